@@ -90,28 +90,29 @@ fn main() {
     println!("--------------------------------------------------");
 
     // Example of a grid with some blocked tiles.
-    #[rustfmt::skip]
-    let grid_with_blocks = GridBuf::<_, _, RowMajor>::from_buffer(&[
-        0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
-        0, 1, 1, 1, 1, 1, 0, 0, 1, 0,
-        0, 0, 0, 1, 0, 0, 0, 1, 1, 0,
-        0, 1, 1, 1, 0, 1, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 1, 0, 1, 1, 0,
-        0, 1, 1, 1, 0, 1, 0, 0, 0, 0,
-        0, 0, 0, 1, 0, 1, 0, 1, 1, 0,
-        0, 1, 1, 1, 0, 1, 0, 0, 1, 0,
-        0, 0, 0, 0, 0, 1, 1, 1, 1, 0,
-        0, 1, 1, 1, 0, 1, 0, 0, 0, 0,
-    ], 10).map(|tile| {
-        if tile == &1 {
-            Tile::Blocked
-        } else {
-            Tile::Walkable
-        }
-    });
+    let grid_with_blocks = GridBuf::from_str_map(
+        "\
+        .....##...\n\
+        .#####..#.\n\
+        ...#...##.\n\
+        .###.#....\n\
+        ..#..#.##.\n\
+        .###.#....\n\
+        ...#.#.##.\n\
+        .###.#..#.\n\
+        .....####.\n\
+        .###.#....\n",
+        |c| {
+            if c == '#' {
+                Tile::Blocked
+            } else {
+                Tile::Walkable
+            }
+        },
+    );
     let start = Pos::new(0, 0);
     let end = Pos::new(9, 9);
-    if let Some(path) = find_path(&grid_with_blocks, start, end) {
+    if let Some(path) = find_path(&grid_with_blocks.copied(), start, end) {
         println!("Path found from {start:?} to {end:?}:");
         for pos in path {
             println!("Path step: {pos:?}");
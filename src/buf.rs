@@ -13,6 +13,9 @@
 //! assert_eq!(grid.get(Pos::new(2, 3)), Some(&42));
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{
     fmt,
     marker::PhantomData,
@@ -21,19 +24,45 @@ use core::{
 
 // IMPLEMENATIONS ----------------------------------------------------------------------------------
 
+pub mod atomic;
 pub mod bits;
 
 // TRAIT IMPLS -------------------------------------------------------------------------------------
 
 use crate::ops::ExactSizeGrid as _;
 pub use crate::ops::unchecked::TrustedSizeGrid as _;
-use crate::{core::Pos, ops::layout};
+use crate::{
+    core::{GridError, GridIndex, Pos},
+    ops::layout,
+};
+pub use impl_split::{GridViewMut, SplitMut};
 
+mod impl_cols;
+mod impl_copy;
+mod impl_debug;
+mod impl_default;
+mod impl_eq;
 mod impl_grid;
+mod impl_hash;
+mod impl_iter;
+mod impl_map;
+mod impl_ndarray;
+mod impl_nested;
 mod impl_new;
+mod impl_raw;
+#[cfg(feature = "rayon")]
+mod impl_rayon;
 mod impl_resize;
+mod impl_rotate;
+mod impl_rows;
+mod impl_scroll;
 mod impl_serde;
+#[cfg(feature = "simd")]
+mod impl_simd;
 mod impl_slice;
+mod impl_sparse;
+mod impl_split;
+mod impl_transpose;
 
 /// A 2-dimensional grid implemented by a linear data buffer.
 ///
@@ -43,7 +72,6 @@ mod impl_slice;
 /// [`Traversal`].
 ///
 /// [`Traversal`]: layout::Traversal
-#[derive(Debug, Clone)]
 pub struct GridBuf<T, B, L> {
     buffer: B,
     width: usize,
@@ -52,6 +80,41 @@ pub struct GridBuf<T, B, L> {
     _element: PhantomData<T>,
 }
 
+impl<T, B, L> Clone for GridBuf<T, B, L>
+where
+    B: Clone,
+{
+    /// Clones the grid by cloning its buffer; the layout is a zero-sized marker.
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            width: self.width,
+            height: self.height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
+/// A growable, row-major grid owned by a `Vec`.
+///
+/// This is the common fully-owned configuration of [`GridBuf`]; unlike array- or slice-backed
+/// grids it supports [`resize`](GridBuf::resize) and [`resize_filled`](GridBuf::resize_filled).
+///
+/// ## Example
+///
+/// ```rust
+/// use grixy::{buf::VecGrid, prelude::*};
+///
+/// let mut grid = VecGrid::new_filled(2, 2, 1u8);
+/// grid.resize_filled(4, 4, 0);
+///
+/// assert_eq!(grid.get(Pos::new(1, 1)), Some(&1)); // preserved
+/// assert_eq!(grid.get(Pos::new(3, 3)), Some(&0)); // new
+/// ```
+#[cfg(feature = "alloc")]
+pub type VecGrid<T> = GridBuf<T, alloc::vec::Vec<T>, layout::RowMajor>;
+
 impl<T, B, L> GridBuf<T, B, L>
 where
     L: layout::Linear,
@@ -62,6 +125,11 @@ where
         (self.buffer, self.width, self.height)
     }
 
+    /// Returns the stride [`layout::Linear::pos_to_index`] expects for this grid's dimensions.
+    fn stride(&self) -> usize {
+        layout::stride::<L>(self.width, self.height)
+    }
+
     /// Returns a mutable reference to the element at `pos`, or `None` if out of bounds.
     #[must_use]
     pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T>
@@ -70,33 +138,78 @@ where
         L: layout::Linear,
     {
         if self.contains(pos) {
-            let idx = L::pos_to_index(pos, self.width);
+            let idx = L::pos_to_index(pos, self.stride());
             self.buffer.as_mut().get_mut(idx)
         } else {
             None
         }
     }
+
+    /// Swaps the elements at two positions.
+    ///
+    /// The swap happens on the underlying buffer, so no intermediate clone of either element is
+    /// made. Swapping a position with itself is a no-op.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if either position is out of bounds; the grid is left unchanged.
+    pub fn swap(&mut self, a: Pos, b: Pos) -> Result<(), GridError>
+    where
+        B: AsMut<[T]>,
+    {
+        if !self.contains(a) {
+            return Err(GridError::OutOfBounds { pos: a });
+        }
+        if !self.contains(b) {
+            return Err(GridError::OutOfBounds { pos: b });
+        }
+        let a = L::pos_to_index(a, self.stride());
+        let b = L::pos_to_index(b, self.stride());
+        self.buffer.as_mut().swap(a, b);
+        Ok(())
+    }
 }
 
-impl<T, B, L> Index<Pos> for GridBuf<T, B, L>
+impl<T, B, L, I> Index<I> for GridBuf<T, B, L>
 where
     L: layout::Linear,
     B: AsRef<[T]>,
+    I: GridIndex,
 {
     type Output = T;
 
-    fn index(&self, index: Pos) -> &Self::Output {
-        &self.buffer.as_ref()[index.y * self.width + index.x]
+    /// Returns a reference to the element at `index`, given as any [`GridIndex`].
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the position is out of bounds; see [`GridRead::get`][] for a checked
+    /// alternative.
+    ///
+    /// [`GridRead::get`]: crate::ops::GridRead::get
+    fn index(&self, index: I) -> &Self::Output {
+        let pos = index.into_pos();
+        assert!(self.contains(pos), "Position out of bounds");
+        &self.buffer.as_ref()[L::pos_to_index(pos, self.stride())]
     }
 }
 
-impl<T, B, L> IndexMut<Pos> for GridBuf<T, B, L>
+impl<T, B, L, I> IndexMut<I> for GridBuf<T, B, L>
 where
     L: layout::Linear,
     B: AsRef<[T]> + AsMut<[T]>,
+    I: GridIndex,
 {
-    fn index_mut(&mut self, index: Pos) -> &mut Self::Output {
-        &mut self.buffer.as_mut()[index.y * self.width + index.x]
+    /// Returns a mutable reference to the element at `index`, given as any [`GridIndex`].
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the position is out of bounds; see [`GridBuf::get_mut`] for a checked
+    /// alternative.
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let pos = index.into_pos();
+        assert!(self.contains(pos), "Position out of bounds");
+        let idx = L::pos_to_index(pos, self.stride());
+        &mut self.buffer.as_mut()[idx]
     }
 }
 
@@ -270,4 +383,49 @@ mod tests {
         assert_eq!(grid.get(Pos::new(1, 1)), Some(&42));
         assert_eq!(grid.get(Pos::new(3, 3)), None); // Out of bounds
     }
+
+    #[test]
+    fn index_ops_col_major() {
+        let grid =
+            GridBuf::<_, _, layout::ColumnMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3);
+        assert_eq!(grid[Pos::new(1, 0)], 4);
+        assert_eq!(grid[Pos::new(0, 1)], 2);
+        assert_eq!(grid[Pos::new(1, 0)], *grid.get(Pos::new(1, 0)).unwrap());
+    }
+
+    #[test]
+    fn index_ops_tuple_and_array() {
+        let mut grid = GridBuf::<u8, _, _>::new(3, 3);
+        grid[(1, 2)] = 42;
+        assert_eq!(grid[[1, 2]], 42);
+        assert_eq!(grid.get_at((1, 2)), Some(&42));
+        assert_eq!(grid.get_at([3, 3]), None); // Out of bounds
+    }
+
+    #[test]
+    #[should_panic(expected = "Position out of bounds")]
+    fn index_out_of_bounds() {
+        let grid = GridBuf::<u8, _, _>::new(3, 3);
+        let _ = grid[Pos::new(3, 0)];
+    }
+
+    #[test]
+    fn swap_cells() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        grid.swap(Pos::new(0, 0), Pos::new(1, 1)).unwrap();
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&4));
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&1));
+    }
+
+    #[test]
+    fn swap_out_of_bounds_leaves_grid_unchanged() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        assert_eq!(
+            grid.swap(Pos::new(0, 0), Pos::new(2, 0)),
+            Err(crate::core::GridError::OutOfBounds {
+                pos: Pos::new(2, 0)
+            })
+        );
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+    }
 }
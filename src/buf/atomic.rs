@@ -0,0 +1,279 @@
+//! Provides a 2D grid of atomic elements for lock-free writes through shared references.
+//!
+//! The main type, [`AtomicGrid`], stores each element in an atomic cell (e.g. [`AtomicU8`][]),
+//! so multiple threads can mark cells concurrently without a mutex.
+//!
+//! [`AtomicU8`]: core::sync::atomic::AtomicU8
+//!
+//! # Examples
+//!
+//! Writing through a shared reference:
+//! ```
+//! use grixy::{core::Pos, buf::atomic::AtomicGrid, ops::{GridRead, layout::RowMajor}};
+//!
+//! let grid = AtomicGrid::<u8, _, RowMajor>::new(8, 8);
+//! grid.set(Pos::new(3, 4), 1).unwrap();
+//! assert_eq!(grid.get(Pos::new(3, 4)), Some(1));
+//! ```
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU8, AtomicU16, AtomicU32, AtomicUsize, Ordering},
+};
+
+use crate::{
+    core::{GridError, Pos, Size},
+    internal,
+    ops::{ExactSizeGrid, GridBase, GridRead, layout},
+};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// A primitive with a corresponding atomic cell type, usable as an [`AtomicGrid`] element.
+///
+/// This trait is sealed, and implemented for `u8`, `u16`, `u32`, and `usize`; `u64` is omitted
+/// because many 32-bit and embedded targets have no 64-bit atomics.
+pub trait AtomicPrimitive: internal::Sealed + Copy {
+    /// The atomic cell type holding this primitive.
+    type Atomic;
+
+    /// Wraps the value in a new atomic cell.
+    fn into_atomic(self) -> Self::Atomic;
+
+    /// Loads the value with [`Ordering::Relaxed`].
+    fn load(atomic: &Self::Atomic) -> Self;
+
+    /// Stores the value with [`Ordering::Relaxed`].
+    fn store(atomic: &Self::Atomic, value: Self);
+}
+
+macro_rules! impl_atomic_primitive {
+    ($t:ty, $atomic:ty) => {
+        impl AtomicPrimitive for $t {
+            type Atomic = $atomic;
+
+            fn into_atomic(self) -> Self::Atomic {
+                <$atomic>::new(self)
+            }
+
+            fn load(atomic: &Self::Atomic) -> Self {
+                atomic.load(Ordering::Relaxed)
+            }
+
+            fn store(atomic: &Self::Atomic, value: Self) {
+                atomic.store(value, Ordering::Relaxed);
+            }
+        }
+    };
+}
+
+impl_atomic_primitive!(u8, AtomicU8);
+impl_atomic_primitive!(u16, AtomicU16);
+impl_atomic_primitive!(u32, AtomicU32);
+impl_atomic_primitive!(usize, AtomicUsize);
+
+/// A 2-dimensional grid whose elements are atomic cells, writable through shared references.
+///
+/// ## Layout
+///
+/// The grid is stored in a linear buffer, with elements accessed in an order defined by
+/// [`Traversal`].
+///
+/// [`Traversal`]: layout::Traversal
+pub struct AtomicGrid<T, B, L>
+where
+    T: AtomicPrimitive,
+    L: layout::Linear,
+{
+    buffer: B,
+    width: usize,
+    height: usize,
+    _layout: PhantomData<L>,
+    _element: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L> AtomicGrid<T, alloc::vec::Vec<T::Atomic>, L>
+where
+    T: AtomicPrimitive + Default,
+    L: layout::Linear,
+{
+    /// Creates a new grid with the specified width and height, filled with the default value.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use grixy::{core::Pos, buf::atomic::AtomicGrid, ops::{GridRead, layout::RowMajor}};
+    ///
+    /// let grid = AtomicGrid::<u8, _, RowMajor>::new(3, 3);
+    /// assert_eq!(grid.get(Pos::new(0, 0)), Some(0));
+    /// ```
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let len = width
+            .checked_mul(height)
+            .expect("Grid dimensions are too large");
+        let buffer = (0..len).map(|_| T::default().into_atomic()).collect();
+        Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T, B, L> AtomicGrid<T, B, L>
+where
+    T: AtomicPrimitive,
+    L: layout::Linear,
+{
+    /// Returns the stride [`layout::Linear::pos_to_index`] expects for this grid's dimensions.
+    fn stride(&self) -> usize {
+        layout::stride::<L>(self.width, self.height)
+    }
+}
+
+impl<T, B, L> AtomicGrid<T, B, L>
+where
+    T: AtomicPrimitive,
+    B: AsRef<[T::Atomic]>,
+    L: layout::Linear,
+{
+    /// Returns a grid from an existing buffer of atomic cells with a given width in columns.
+    ///
+    /// The height is inferred from the buffer length and width.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the buffer length is not a multiple of the width.
+    pub fn from_buffer(buffer: B, width: usize) -> Self {
+        let height = buffer.as_ref().len().checked_div(width).unwrap_or(0);
+        assert!(
+            buffer.as_ref().len() == width * height,
+            "Buffer length must be a multiple of width"
+        );
+        Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+
+    /// Returns the element at the given position, or `None` if it is out of bounds.
+    ///
+    /// The element is read with a relaxed atomic load.
+    pub fn get(&self, pos: Pos) -> Option<T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let atomic = &self.buffer.as_ref()[L::pos_to_index(pos, self.stride())];
+        Some(T::load(atomic))
+    }
+
+    /// Sets the element at the given position through a shared reference.
+    ///
+    /// The element is written with a relaxed atomic store.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the position is out of bounds.
+    pub fn set(&self, pos: Pos, value: T) -> Result<(), GridError> {
+        if !self.contains(pos) {
+            return Err(GridError::OutOfBounds { pos });
+        }
+        let atomic = &self.buffer.as_ref()[L::pos_to_index(pos, self.stride())];
+        T::store(atomic, value);
+        Ok(())
+    }
+}
+
+impl<T, B, L> GridBase for AtomicGrid<T, B, L>
+where
+    T: AtomicPrimitive,
+    L: layout::Linear,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        let size = Size::new(self.width, self.height);
+        (size, Some(size))
+    }
+}
+
+impl<T, B, L> ExactSizeGrid for AtomicGrid<T, B, L>
+where
+    T: AtomicPrimitive,
+    L: layout::Linear,
+{
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+}
+
+impl<T, B, L> GridRead for AtomicGrid<T, B, L>
+where
+    T: AtomicPrimitive,
+    B: AsRef<[T::Atomic]>,
+    L: layout::Linear,
+{
+    type Element<'a>
+        = T
+    where
+        Self: 'a;
+
+    type Layout = L;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        AtomicGrid::get(self, pos)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{core::Rect, ops::layout::RowMajor};
+
+    #[test]
+    fn set_through_shared_reference() {
+        let grid = AtomicGrid::<u8, _, RowMajor>::new(3, 3);
+        let shared = &grid;
+        shared.set(Pos::new(1, 2), 42).unwrap();
+        assert_eq!(shared.get(Pos::new(1, 2)), Some(42));
+        assert_eq!(shared.get(Pos::new(0, 0)), Some(0));
+    }
+
+    #[test]
+    fn set_out_of_bounds_fails() {
+        let grid = AtomicGrid::<u32, _, RowMajor>::new(2, 2);
+        assert_eq!(
+            grid.set(Pos::new(2, 0), 1),
+            Err(GridError::OutOfBounds {
+                pos: Pos::new(2, 0)
+            })
+        );
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+    }
+
+    #[test]
+    fn grid_read_iterates_loaded_values() {
+        let grid = AtomicGrid::<u8, _, RowMajor>::new(2, 2);
+        grid.set(Pos::new(0, 0), 1).unwrap();
+        grid.set(Pos::new(1, 1), 4).unwrap();
+        let values: alloc::vec::Vec<_> = grid.iter_rect(Rect::from_ltwh(0, 0, 2, 2)).collect();
+        assert_eq!(values, alloc::vec![1, 0, 0, 4]);
+    }
+}
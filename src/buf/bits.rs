@@ -15,11 +15,12 @@
 
 use core::{marker::PhantomData, ops::Index};
 
+mod impl_serde;
 mod ops;
 pub use ops::BitOps;
 
 use crate::{
-    core::{Pos, Size},
+    core::{GridError, GridIndex, Pos, Rect, Size},
     internal,
     ops::{
         ExactSizeGrid, GridBase, layout,
@@ -38,7 +39,6 @@ extern crate alloc;
 /// [`Traversal`].
 ///
 /// [`Traversal`]: layout::Traversal
-#[derive(Debug, Clone)]
 pub struct GridBits<T, B, L>
 where
     T: BitOps,
@@ -51,6 +51,35 @@ where
     _element: PhantomData<T>,
 }
 
+impl<T, B, L> Clone for GridBits<T, B, L>
+where
+    T: BitOps,
+    B: Clone,
+    L: layout::Linear,
+{
+    /// Clones the grid by cloning its buffer; the layout is a zero-sized marker.
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            width: self.width,
+            height: self.height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T, B, L> GridBits<T, B, L>
+where
+    T: BitOps,
+    L: layout::Linear,
+{
+    /// Returns the stride [`layout::Linear::pos_to_index`] expects for this grid's dimensions.
+    fn stride(&self) -> usize {
+        layout::stride::<L>(self.width, self.height)
+    }
+}
+
 impl<T, B, L> GridBits<T, B, L>
 where
     T: BitOps,
@@ -81,18 +110,33 @@ where
     /// ```
     #[must_use]
     pub fn from_buffer(buffer: B, width: usize) -> Self {
-        let height = buffer.as_ref().len() * T::MAX_WIDTH / width;
-        assert!(
-            height * width == buffer.as_ref().len() * T::MAX_WIDTH,
-            "Buffer length must be a multiple of width"
-        );
-        Self {
+        Self::try_from_buffer(buffer, width)
+            .unwrap_or_else(|_| panic!("Buffer length must be a multiple of width"))
+    }
+
+    /// Returns a grid from an existing buffer, or an error if the bits do not fit the width.
+    ///
+    /// This is the fallible form of [`from_buffer`](GridBits::from_buffer) for buffer data that
+    /// comes from untrusted sources such as files. A width of `0` is valid only for an empty
+    /// buffer.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError::InvalidBufferLength`] (with the length in bits) if the buffer's bits
+    /// are not a multiple of the width.
+    pub fn try_from_buffer(buffer: B, width: usize) -> Result<Self, GridError> {
+        let len = buffer.as_ref().len() * T::MAX_WIDTH;
+        let height = len.checked_div(width).unwrap_or(0);
+        if height * width != len {
+            return Err(GridError::InvalidBufferLength { len, width });
+        }
+        Ok(Self {
             buffer,
             width,
             height,
             _layout: PhantomData,
             _element: PhantomData,
-        }
+        })
     }
 }
 
@@ -105,6 +149,9 @@ where
     ///
     /// This creates a grid with a row-major layout; see [`new_with_layout`][] to customize.
     ///
+    /// The dimensions do not need to fill the buffer exactly; any trailing bits in the last
+    /// element are unused and remain unset.
+    ///
     /// [`new_with_layout`]: GridBits::new_with_layout
     ///
     /// ## Example
@@ -118,10 +165,23 @@ where
     /// assert_eq!(grid.get(Pos::new(8, 0)), None);
     /// assert_eq!(grid.get(Pos::new(0, 1)), None);
     /// ```
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
     #[must_use]
     pub fn new(width: usize, height: usize) -> Self {
-        let buffer = alloc::vec![T::default(); (width * height).div_ceil(T::MAX_WIDTH)];
-        Self::from_buffer(buffer, width)
+        let bits = width
+            .checked_mul(height)
+            .expect("Grid dimensions are too large");
+        let buffer = alloc::vec![T::default(); bits.div_ceil(T::MAX_WIDTH)];
+        Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
     }
 }
 
@@ -133,6 +193,9 @@ where
 {
     /// Creates a new grid with the specified width, height, and layout, filled with the default value.
     ///
+    /// The dimensions do not need to fill the buffer exactly; any trailing bits in the last
+    /// element are unused and remain unset.
+    ///
     /// # Example
     /// ```
     /// use grixy::{core::{Pos}, buf::bits::GridBits, ops::{GridRead, layout::RowMajor}};
@@ -140,13 +203,57 @@ where
     /// let grid = GridBits::<u8, _, RowMajor>::new_with_layout(8, 1);
     /// assert_eq!(grid.get(Pos::new(0, 0)), Some(false));
     /// ```
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
     #[must_use]
     pub fn new_with_layout(width: usize, height: usize) -> Self
     where
         T: Default,
     {
-        let buffer = alloc::vec![T::default(); (width * height).div_ceil(T::MAX_WIDTH)];
-        Self::from_buffer(buffer, width)
+        let bits = width
+            .checked_mul(height)
+            .expect("Grid dimensions are too large");
+        let buffer = alloc::vec![T::default(); bits.div_ceil(T::MAX_WIDTH)];
+        Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+
+    /// Creates a new grid by evaluating a function at every position.
+    ///
+    /// Positions are visited in the layout's storage order, and only set bits are written.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use grixy::{core::Pos, buf::bits::GridBits, ops::{GridRead, layout::RowMajor}};
+    ///
+    /// let grid = GridBits::<u8, _, RowMajor>::new_generate(4, 4, |pos| pos.x == pos.y);
+    /// assert_eq!(grid.get(Pos::new(2, 2)), Some(true));
+    /// assert_eq!(grid.get(Pos::new(2, 1)), Some(false));
+    /// ```
+    pub fn new_generate(width: usize, height: usize, mut f: impl FnMut(Pos) -> bool) -> Self
+    where
+        T: Default,
+    {
+        let mut grid = Self::new_with_layout(width, height);
+        for pos in L::iter_pos(Rect::from_ltwh(0, 0, width, height)) {
+            if f(pos) {
+                // SAFETY: `iter_pos` only yields positions within the grid's bounds.
+                unsafe { grid.set_unchecked(pos, true) };
+            }
+        }
+        grid
     }
 }
 
@@ -194,6 +301,118 @@ where
     }
 }
 
+impl<T, B> GridBits<T, B, layout::RowMajor>
+where
+    T: BitOps,
+    B: AsRef<[T]>,
+{
+    /// Counts the set bits in a rectangular region.
+    ///
+    /// The region is trimmed to the grid, and each row is counted a word at a time with
+    /// popcounts rather than by testing individual cells.
+    #[must_use]
+    pub fn count_set(&self, bounds: Rect) -> usize {
+        let bounds = self.trim_rect(bounds);
+        let words = self.buffer.as_ref();
+        let origin = bounds.top_left();
+        let mut total = 0;
+        for row in 0..bounds.height() {
+            let start = (origin.y + row) * self.width + origin.x;
+            let mut index = start;
+            let end = start + bounds.width();
+            while index < end {
+                let offset = index % T::MAX_WIDTH;
+                let take = (T::MAX_WIDTH - offset).min(end - index);
+                let mask = if take >= usize::BITS as usize {
+                    usize::MAX
+                } else {
+                    (1 << take) - 1
+                };
+                let word = words[index / T::MAX_WIDTH].to_usize();
+                total += ((word >> offset) & mask).count_ones() as usize;
+                index += take;
+            }
+        }
+        total
+    }
+
+    /// Copies a rectangular region from another `RowMajor` bit grid using word-level moves.
+    ///
+    /// This is the bit-grid counterpart of [`GridBuf::copy_rect_from`][]: each row segment is
+    /// moved up to a word at a time with shifts and masks rather than bit-by-bit sets, which is
+    /// the difference between a glyph blit and a byte copy of the same data. Cells that do not
+    /// fit in either grid are clipped.
+    ///
+    /// [`GridBuf::copy_rect_from`]: crate::buf::GridBuf::copy_rect_from
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::{core::{Pos, Rect}, buf::bits::GridBits, ops::{GridRead, layout::RowMajor}};
+    ///
+    /// let src = GridBits::<u8, _, RowMajor>::from_buffer([0b0000_1111], 8);
+    /// let mut dst = GridBits::<u8, _, RowMajor>::new(8, 2);
+    /// dst.copy_rect_from(&src, Rect::from_ltwh(0, 0, 4, 1), Pos::new(3, 1));
+    ///
+    /// assert_eq!(dst.get(Pos::new(3, 1)), Some(true));
+    /// assert_eq!(dst.get(Pos::new(6, 1)), Some(true));
+    /// assert_eq!(dst.get(Pos::new(7, 1)), Some(false));
+    /// ```
+    pub fn copy_rect_from<B2>(
+        &mut self,
+        src: &GridBits<T, B2, layout::RowMajor>,
+        from: Rect,
+        to: Pos,
+    ) where
+        B: AsMut<[T]>,
+        B2: AsRef<[T]>,
+    {
+        let from = src.trim_rect(from);
+        let dst_rect = self.trim_rect(Rect::from_ltwh(to.x, to.y, from.width(), from.height()));
+        let width = from.width().min(dst_rect.width());
+        let height = from.height().min(dst_rect.height());
+        let src_origin = from.top_left();
+        let src_width = src.width;
+        let dst_width = self.width;
+        for y in 0..height {
+            let src_bit = (src_origin.y + y) * src_width + src_origin.x;
+            let dst_bit = (to.y + y) * dst_width + to.x;
+            copy_bits(
+                src.buffer.as_ref(),
+                self.buffer.as_mut(),
+                src_bit,
+                dst_bit,
+                width,
+            );
+        }
+    }
+}
+
+/// Copies a run of `len` bits between word buffers, moving up to a word per step.
+///
+/// Each step takes the longest run that stays within one source word and one destination word,
+/// extracts it with a shift and mask, and merges it into the destination word.
+fn copy_bits<T: BitOps>(src: &[T], dst: &mut [T], src_bit: usize, dst_bit: usize, len: usize) {
+    let (mut src_bit, mut dst_bit, mut len) = (src_bit, dst_bit, len);
+    while len > 0 {
+        let s_off = src_bit % T::MAX_WIDTH;
+        let d_off = dst_bit % T::MAX_WIDTH;
+        let take = (T::MAX_WIDTH - s_off).min(T::MAX_WIDTH - d_off).min(len);
+        let mask = if take >= usize::BITS as usize {
+            usize::MAX
+        } else {
+            (1 << take) - 1
+        };
+        let bits = (src[src_bit / T::MAX_WIDTH].to_usize() >> s_off) & mask;
+        let word = &mut dst[dst_bit / T::MAX_WIDTH];
+        let merged = (word.to_usize() & !(mask << d_off)) | (bits << d_off);
+        *word = T::from_usize(merged);
+        src_bit += take;
+        dst_bit += take;
+        len -= take;
+    }
+}
+
 impl<T, B, L> GridReadUnchecked for GridBits<T, B, L>
 where
     T: BitOps,
@@ -208,7 +427,7 @@ where
     type Layout = L;
 
     unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
-        let index = L::pos_to_index(pos, self.width);
+        let index = L::pos_to_index(pos, self.stride());
         let (byte_index, bit_index) = (index / T::MAX_WIDTH, index % T::MAX_WIDTH);
         let byte = unsafe { self.buffer.as_ref().get_unchecked(byte_index) };
         (byte.to_usize() >> bit_index) & 1 != 0
@@ -243,7 +462,7 @@ where
     type Layout = L;
 
     unsafe fn set_unchecked(&mut self, pos: Pos, value: bool) {
-        let index = L::pos_to_index(pos, self.width);
+        let index = L::pos_to_index(pos, self.stride());
         let (byte_index, bit_index) = (index / T::MAX_WIDTH, index % T::MAX_WIDTH);
         let byte = unsafe { self.buffer.as_mut().get_unchecked_mut(byte_index) };
         if value {
@@ -289,28 +508,150 @@ where
 {
 }
 
-impl<T, B, L> Index<Pos> for GridBits<T, B, L>
+impl<T, B, L, I> Index<I> for GridBits<T, B, L>
 where
     T: BitOps,
     B: AsRef<[T]>,
     L: layout::Linear,
+    I: GridIndex,
 {
     type Output = bool;
 
-    fn index(&self, index: Pos) -> &Self::Output {
-        let value = unsafe { self.get_unchecked(index) };
+    fn index(&self, index: I) -> &Self::Output {
+        let value = unsafe { self.get_unchecked(index.into_pos()) };
         if value { &true } else { &false }
     }
 }
 
+impl<T, B, B2, L> PartialEq<GridBits<T, B2, L>> for GridBits<T, B, L>
+where
+    T: BitOps,
+    B: AsRef<[T]>,
+    B2: AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Grids are equal when they have the same dimensions and the same bits set.
+    ///
+    /// The backing buffers may be different types; a `Vec`-backed grid compares equal to an
+    /// array-backed one with the same contents. Words are compared directly; any unused trailing
+    /// bits participate, but remain unset for grids created by this crate.
+    fn eq(&self, other: &GridBits<T, B2, L>) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self
+                .buffer
+                .as_ref()
+                .iter()
+                .zip(other.buffer.as_ref())
+                .all(|(a, b)| a.to_usize() == b.to_usize())
+    }
+}
+
+impl<T, B, L> Eq for GridBits<T, B, L>
+where
+    T: BitOps,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+}
+
+impl<T, B, L> core::hash::Hash for GridBits<T, B, L>
+where
+    T: BitOps,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Hashes the grid's dimensions and words, agreeing with its `PartialEq` implementation:
+    /// grids that compare equal hash equal, regardless of the backing buffer type.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        for word in self.buffer.as_ref() {
+            word.to_usize().hash(state);
+        }
+    }
+}
+
+impl<T, B, L> core::fmt::Debug for GridBits<T, B, L>
+where
+    T: BitOps,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Formats the grid for debugging.
+    ///
+    /// The default format is a compact field dump; the alternate format (`{:#?}`) draws the grid
+    /// as rows of `#` (set) and `·` (unset) characters, truncated to the top-left `32x8` corner
+    /// for large grids. Each cell is a single character, so more columns are shown than for
+    /// [`GridBuf`][]'s alternate format.
+    ///
+    /// [`GridBuf`]: crate::buf::GridBuf
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write as _;
+
+        if !f.alternate() {
+            return f
+                .debug_struct("GridBits")
+                .field("width", &self.width)
+                .field("height", &self.height)
+                .field("words", &self.buffer.as_ref().len())
+                .finish();
+        }
+
+        let cols = self.width.min(32);
+        let rows = self.height.min(8);
+        writeln!(f, "GridBits {}x{} [", self.width, self.height)?;
+        for y in 0..rows {
+            f.write_str("    ")?;
+            for x in 0..cols {
+                let bit = unsafe { self.get_unchecked(Pos::new(x, y)) };
+                f.write_char(if bit { '#' } else { '·' })?;
+            }
+            if self.width > cols {
+                f.write_str(" …")?;
+            }
+            f.write_char('\n')?;
+        }
+        if self.height > rows {
+            f.write_str("    …\n")?;
+        }
+        f.write_char(']')
+    }
+}
+
+impl<T, B, L> Default for GridBits<T, B, L>
+where
+    T: BitOps,
+    B: Default + AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Returns an empty `0x0` grid backed by `B::default()`.
+    ///
+    /// This is intended for growable buffers such as `Vec`, whose default is empty; for a
+    /// fixed-size buffer, any bits in the defaulted buffer are unreachable through the grid.
+    fn default() -> Self {
+        Self {
+            buffer: B::default(),
+            width: 0,
+            height: 0,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
 
     use crate::{
         buf::bits::GridBits,
-        core::{GridError, Pos},
-        ops::{GridRead, GridWrite, layout::RowMajor, unchecked::GridReadUnchecked as _},
+        core::{GridError, Pos, Rect},
+        ops::{
+            ExactSizeGrid as _, GridRead, GridWrite, layout::RowMajor,
+            unchecked::GridReadUnchecked as _,
+        },
+        test::fnv1a_hash_of,
     };
 
     #[test]
@@ -335,8 +676,30 @@ mod tests {
 
     #[test]
     #[should_panic(expected = "Buffer length must be a multiple of width")]
-    fn arr_new_panics() {
-        let _ = GridBits::<u8, _, RowMajor>::new(9, 1);
+    fn from_buffer_panics_on_partial_rows() {
+        let _ = GridBits::<u8, _, RowMajor>::from_buffer([0u8], 3);
+    }
+
+    #[test]
+    fn try_from_buffer_reports_bit_length() {
+        let grid = GridBits::<u8, _, RowMajor>::try_from_buffer([0u8], 4).unwrap();
+        assert_eq!(grid.get(Pos::new(3, 1)), Some(false));
+        assert_eq!(
+            GridBits::<u8, _, RowMajor>::try_from_buffer([0u8], 3),
+            Err(GridError::InvalidBufferLength { len: 8, width: 3 })
+        );
+    }
+
+    #[test]
+    fn new_with_partial_last_word() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(3, 3);
+        assert_eq!(grid.get(Pos::new(2, 2)), Some(false));
+        assert_eq!(grid.get(Pos::new(3, 2)), None);
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+
+        grid.set(Pos::new(2, 2), true).unwrap();
+        assert_eq!(grid.get(Pos::new(2, 2)), Some(true));
+        assert_eq!(grid.count_set(Rect::from_ltwh(0, 0, 3, 3)), 1);
     }
 
     #[test]
@@ -462,4 +825,128 @@ mod tests {
         let grid = GridBits::<_, _, RowMajor>::from_buffer(data, 8);
         assert!(grid[Pos::new(0, 0)]);
     }
+
+    #[test]
+    fn equal_bit_grids_compare_equal() {
+        let a = GridBits::<u8, _, RowMajor>::from_buffer([0b1010_0101, 0b0000_1111], 8);
+        let b = GridBits::<u8, _, RowMajor>::from_buffer(alloc::vec![0b1010_0101, 0b0000_1111], 8);
+        assert_eq!(a, b);
+        assert_ne!(
+            a,
+            GridBits::<u8, _, RowMajor>::from_buffer([0b1010_0101, 0b0100_1111], 8)
+        );
+    }
+
+    #[test]
+    fn equal_bit_grids_hash_equal() {
+        let a = GridBits::<u8, _, RowMajor>::from_buffer([0b1010_0101, 0b0000_1111], 8);
+        let b = GridBits::<u8, _, RowMajor>::from_buffer(alloc::vec![0b1010_0101, 0b0000_1111], 8);
+        assert_eq!(fnv1a_hash_of(&a), fnv1a_hash_of(&b));
+
+        let c = GridBits::<u8, _, RowMajor>::from_buffer([0b1010_0101, 0b0100_1111], 8);
+        assert_ne!(fnv1a_hash_of(&a), fnv1a_hash_of(&c));
+    }
+
+    #[test]
+    fn bit_grids_with_differing_dimensions_compare_unequal() {
+        let a = GridBits::<u8, _, RowMajor>::from_buffer([0b1010_0101, 0b0000_1111], 8);
+        let b = GridBits::<u8, _, RowMajor>::from_buffer([0b1010_0101, 0b0000_1111], 16);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn count_set_full_grid() {
+        let data: [u8; 2] = [0b1010_0101, 0b0000_1111];
+        let grid = GridBits::<_, _, RowMajor>::from_buffer(data, 8);
+        assert_eq!(grid.count_set(Rect::from_ltwh(0, 0, 8, 2)), 8);
+    }
+
+    #[test]
+    fn count_set_respects_bounds() {
+        let data: [u8; 2] = [0b1111_1111, 0b1111_1111];
+        let grid = GridBits::<_, _, RowMajor>::from_buffer(data, 8);
+        assert_eq!(grid.count_set(Rect::from_ltwh(2, 0, 4, 1)), 4);
+        assert_eq!(grid.count_set(Rect::from_ltwh(6, 1, 100, 100)), 2);
+    }
+
+    #[test]
+    fn count_set_spans_word_boundaries() {
+        // A 4-wide grid of `u8` packs two rows per word.
+        let data: [u8; 2] = [0b1111_0001, 0b1000_0011];
+        let grid = GridBits::<_, _, RowMajor>::from_buffer(data, 4);
+        assert_eq!(grid.count_set(Rect::from_ltwh(0, 0, 4, 4)), 8);
+        assert_eq!(grid.count_set(Rect::from_ltwh(0, 1, 4, 2)), 6);
+    }
+
+    #[test]
+    fn count_set_matches_count_rect() {
+        let data: [u8; 2] = [0b0110_1001, 0b1001_0110];
+        let grid = GridBits::<_, _, RowMajor>::from_buffer(data, 8);
+        let bounds = Rect::from_ltwh(1, 0, 5, 2);
+        assert_eq!(grid.count_set(bounds), grid.count_rect(bounds, |bit| bit));
+    }
+
+    #[test]
+    fn default_grid_is_empty() {
+        let grid = GridBits::<u8, alloc::vec::Vec<u8>, RowMajor>::default();
+        assert_eq!(grid.width(), 0);
+        assert_eq!(grid.height(), 0);
+        assert_eq!(grid.get(Pos::new(0, 0)), None);
+    }
+
+    #[test]
+    fn new_generate_sets_matching_bits() {
+        let grid = GridBits::<u8, _, RowMajor>::new_generate(4, 4, |pos| pos.x == pos.y);
+        assert_eq!(grid.get(Pos::new(3, 3)), Some(true));
+        assert_eq!(grid.get(Pos::new(3, 0)), Some(false));
+    }
+
+    #[test]
+    fn copy_rect_from_unaligned_offsets() {
+        #[rustfmt::skip]
+        let src = GridBits::<u8, _, RowMajor>::new_generate(8, 2, |pos| {
+            (pos.x + pos.y) % 2 == 0
+        });
+        let mut dst = GridBits::<u8, _, RowMajor>::new(8, 4);
+        dst.copy_rect_from(&src, Rect::from_ltwh(1, 0, 5, 2), Pos::new(2, 1));
+
+        for y in 0..2 {
+            for x in 0..5 {
+                assert_eq!(
+                    dst.get(Pos::new(2 + x, 1 + y)),
+                    src.get(Pos::new(1 + x, y)),
+                    "mismatch at offset ({x}, {y})"
+                );
+            }
+        }
+        assert_eq!(dst.count_set(Rect::from_ltwh(0, 0, 8, 4)), 5);
+    }
+
+    #[test]
+    fn copy_rect_from_spans_word_boundaries() {
+        // A 4-wide grid of `u8` packs two rows per word.
+        let src = GridBits::<u8, _, RowMajor>::from_buffer([0b1111_1111, 0b1111_1111], 4);
+        let mut dst = GridBits::<u8, _, RowMajor>::new(4, 4);
+        dst.copy_rect_from(&src, Rect::from_ltwh(1, 1, 3, 2), Pos::new(0, 1));
+
+        assert_eq!(dst.count_set(Rect::from_ltwh(0, 1, 3, 2)), 6);
+        assert_eq!(dst.count_set(Rect::from_ltwh(0, 0, 4, 4)), 6);
+    }
+
+    #[test]
+    fn copy_rect_from_clips_both_grids() {
+        let src = GridBits::<u8, _, RowMajor>::from_buffer([0b1111_1111], 8);
+        let mut dst = GridBits::<u8, _, RowMajor>::new(4, 1);
+        dst.copy_rect_from(&src, Rect::from_ltwh(0, 0, 8, 1), Pos::new(2, 0));
+
+        assert_eq!(dst.count_set(Rect::from_ltwh(0, 0, 4, 1)), 2);
+    }
+
+    #[test]
+    fn debug_alternate_draws_bits() {
+        let data: [u8; 1] = [0b0000_1001];
+        let grid = GridBits::<_, _, RowMajor>::from_buffer(data, 4);
+        let output = alloc::format!("{grid:#?}");
+        assert_eq!(output, "GridBits 4x2 [\n    #··#\n    ····\n]");
+    }
 }
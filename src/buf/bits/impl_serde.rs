@@ -0,0 +1,189 @@
+#[cfg(feature = "serde")]
+extern crate alloc;
+
+#[cfg(feature = "serde")]
+use crate::buf::bits::{BitOps, GridBits};
+#[cfg(feature = "serde")]
+use crate::ops::layout;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+};
+
+#[cfg(feature = "serde")]
+impl<T, B, L> Serialize for GridBits<T, B, L>
+where
+    T: BitOps + Serialize,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("GridBits", 3)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("height", &self.height)?;
+        state.serialize_field("data", self.buffer.as_ref())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, L> Deserialize<'de> for GridBits<T, alloc::vec::Vec<T>, L>
+where
+    T: BitOps + Deserialize<'de>,
+    L: layout::Linear,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        enum Field {
+            Width,
+            Height,
+            Data,
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FieldVisitor;
+
+                impl Visitor<'_> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        f.write_str("`width`, `height`, or `data`")
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                        match value {
+                            "width" => Ok(Field::Width),
+                            "height" => Ok(Field::Height),
+                            "data" => Ok(Field::Data),
+                            _ => Err(de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        const FIELDS: &[&str] = &["width", "height", "data"];
+
+        struct GridBitsVisitor<T, L>(PhantomData<(T, L)>);
+
+        impl<'de, T, L> Visitor<'de> for GridBitsVisitor<T, L>
+        where
+            T: BitOps + Deserialize<'de>,
+            L: layout::Linear,
+        {
+            type Value = GridBits<T, alloc::vec::Vec<T>, L>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("struct GridBits")
+            }
+
+            fn visit_map<V: de::MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+                let mut width = None;
+                let mut height = None;
+                let mut data = None;
+
+                while let Some(key) = map.next_key::<Field>()? {
+                    match key {
+                        Field::Width => {
+                            if width.is_some() {
+                                return Err(de::Error::duplicate_field("width"));
+                            }
+                            width = Some(map.next_value::<usize>()?);
+                        }
+                        Field::Height => {
+                            if height.is_some() {
+                                return Err(de::Error::duplicate_field("height"));
+                            }
+                            height = Some(map.next_value::<usize>()?);
+                        }
+                        Field::Data => {
+                            if data.is_some() {
+                                return Err(de::Error::duplicate_field("data"));
+                            }
+                            data = Some(map.next_value::<alloc::vec::Vec<T>>()?);
+                        }
+                    }
+                }
+
+                let width = width.ok_or_else(|| de::Error::missing_field("width"))?;
+                let height = height.ok_or_else(|| de::Error::missing_field("height"))?;
+                let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+
+                let expected = (width * height).div_ceil(T::MAX_WIDTH);
+                if data.len() != expected {
+                    return Err(de::Error::custom(alloc::format!(
+                        "data length {} does not match width {} * height {}, which needs {} elements",
+                        data.len(),
+                        width,
+                        height,
+                        expected
+                    )));
+                }
+
+                Ok(GridBits {
+                    buffer: data,
+                    width,
+                    height,
+                    _layout: PhantomData,
+                    _element: PhantomData,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("GridBits", FIELDS, GridBitsVisitor::<T, L>(PhantomData))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::bits::GridBits,
+        core::Pos,
+        ops::{GridRead as _, GridWrite as _, layout::RowMajor},
+    };
+
+    #[test]
+    fn serde_roundtrip() {
+        let grid = GridBits::<u8, _, RowMajor>::from_buffer([0b0000_0010u8, 0b1000_0000], 8);
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let deserialized: GridBits<u8, alloc::vec::Vec<u8>, RowMajor> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(grid, deserialized);
+        assert_eq!(deserialized.get(Pos::new(1, 0)), Some(true));
+        assert_eq!(deserialized.get(Pos::new(7, 1)), Some(true));
+        assert_eq!(deserialized.get(Pos::new(0, 0)), Some(false));
+    }
+
+    #[test]
+    fn serde_roundtrip_partial_last_word() {
+        let mut grid = GridBits::<u8, alloc::vec::Vec<u8>, RowMajor>::new(3, 3);
+        grid.set(Pos::new(2, 2), true).unwrap();
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let deserialized: GridBits<u8, alloc::vec::Vec<u8>, RowMajor> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(grid, deserialized);
+        assert_eq!(deserialized.get(Pos::new(2, 2)), Some(true));
+        assert_eq!(deserialized.get(Pos::new(0, 0)), Some(false));
+    }
+
+    #[test]
+    fn rejects_mismatched_data_length() {
+        let json = r#"{"width":8,"height":3,"data":[1,2]}"#;
+        let result: Result<GridBits<u8, alloc::vec::Vec<u8>, RowMajor>, _> =
+            serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+}
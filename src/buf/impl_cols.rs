@@ -0,0 +1,89 @@
+use crate::{buf::GridBuf, ops::layout};
+
+impl<T, B> GridBuf<T, B, layout::ColumnMajor>
+where
+    B: AsRef<[T]>,
+{
+    /// Returns an iterator over the columns of the grid as slices.
+    ///
+    /// Columns are yielded left to right. Because each column of a `ColumnMajor` buffer is
+    /// contiguous, the slices can be used directly for `memcpy`-style copies or reductions.
+    pub fn cols(&self) -> impl Iterator<Item = &[T]> {
+        self.buffer.as_ref().chunks(self.height.max(1))
+    }
+
+    /// Returns an iterator over the columns of the grid as mutable slices.
+    ///
+    /// Columns are yielded left to right. Because each column of a `ColumnMajor` buffer is
+    /// contiguous, the slices can be filled or copied into directly.
+    pub fn cols_mut(&mut self) -> impl Iterator<Item = &mut [T]>
+    where
+        B: AsMut<[T]>,
+    {
+        self.buffer.as_mut().chunks_mut(self.height.max(1))
+    }
+
+    /// Returns the column at `x` as a contiguous slice, or `None` if `x` is out of bounds.
+    ///
+    /// Whole columns can then be handed to I/O, hashing, or SIMD routines that operate on
+    /// slices, which is impossible through the element-wise API.
+    pub fn col_slice(&self, x: usize) -> Option<&[T]> {
+        (x < self.width).then(|| {
+            let start = x * self.height;
+            &self.buffer.as_ref()[start..start + self.height]
+        })
+    }
+
+    /// Returns the column at `x` as a contiguous mutable slice, or `None` if `x` is out of
+    /// bounds.
+    pub fn col_slice_mut(&mut self, x: usize) -> Option<&mut [T]>
+    where
+        B: AsMut<[T]>,
+    {
+        (x < self.width).then(|| {
+            let start = x * self.height;
+            &mut self.buffer.as_mut()[start..start + self.height]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, ops::layout::ColumnMajor};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn cols_yields_slices() {
+        // Column-major: the buffer stores each column contiguously.
+        let grid = GridBuf::<_, _, ColumnMajor>::from_buffer(vec![1, 3, 2, 4], 2);
+
+        let cols: Vec<&[i32]> = grid.cols().collect();
+        assert_eq!(cols, vec![&[1, 3][..], &[2, 4][..]]);
+    }
+
+    #[test]
+    fn col_slice_returns_contiguous_column() {
+        // Column-major: the buffer stores each column contiguously.
+        let mut grid = GridBuf::<_, _, ColumnMajor>::from_buffer(vec![1, 3, 2, 4], 2);
+        assert_eq!(grid.col_slice(1), Some(&[2, 4][..]));
+        assert_eq!(grid.col_slice(2), None);
+
+        grid.col_slice_mut(0).unwrap().fill(9);
+        assert_eq!(grid.col_slice(0), Some(&[9, 9][..]));
+        assert_eq!(grid.col_slice_mut(2), None);
+    }
+
+    #[test]
+    fn cols_mut_allows_in_place_writes() {
+        let mut grid = GridBuf::<_, _, ColumnMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        for col in grid.cols_mut() {
+            col.fill(9);
+        }
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![9, 9, 9, 9]);
+    }
+}
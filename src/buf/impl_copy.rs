@@ -0,0 +1,178 @@
+use crate::{
+    buf::GridBuf,
+    core::{Pos, Rect},
+    ops::{GridBase as _, layout},
+};
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    T: Copy,
+    B: AsRef<[T]> + AsMut<[T]>,
+{
+    /// Copies a rectangular region from another `RowMajor` grid using whole-row slice copies.
+    ///
+    /// This is a linear-buffer fast path for [`copy_rect`][]: because both grids store rows
+    /// contiguously, each row segment of the region is moved with a single `copy_from_slice`
+    /// instead of a per-element iterator. Cells that do not fit in either grid are clipped, as
+    /// with [`copy_rect`].
+    ///
+    /// [`copy_rect`]: crate::ops::copy_rect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let src = GridBuf::new_filled(3, 3, 1);
+    /// let mut dst = GridBuf::<i32, _, _>::new(5, 5);
+    /// dst.copy_rect_from(&src, Rect::from_ltwh(0, 0, 3, 3), Pos::new(2, 2));
+    ///
+    /// assert_eq!(dst.get(Pos::new(2, 2)), Some(&1));
+    /// assert_eq!(dst.get(Pos::new(4, 4)), Some(&1));
+    /// assert_eq!(dst.get(Pos::new(1, 1)), Some(&0));
+    /// ```
+    pub fn copy_rect_from<B2>(
+        &mut self,
+        src: &GridBuf<T, B2, layout::RowMajor>,
+        from: Rect,
+        to: Pos,
+    ) where
+        B2: AsRef<[T]>,
+    {
+        let from = src.trim_rect(from);
+        let dst_rect = self.trim_rect(Rect::from_ltwh(to.x, to.y, from.width(), from.height()));
+        let width = from.width().min(dst_rect.width());
+        let height = from.height().min(dst_rect.height());
+        let from = Rect::from_ltwh(from.top_left().x, from.top_left().y, width, height);
+
+        // SAFETY: `from` was clipped to `src` and the destination region to `self` above.
+        unsafe { self.copy_rect_from_unchecked(src, from, to) }
+    }
+
+    /// Copies a rectangular region from another `RowMajor` grid without bounds checking.
+    ///
+    /// This is the unchecked counterpart of [`copy_rect_from`](GridBuf::copy_rect_from): each
+    /// row segment is moved with a single [`ptr::copy_nonoverlapping`](core::ptr::copy_nonoverlapping)
+    /// instead of a per-element iterator, and no rectangle is clipped.
+    ///
+    /// ## Safety
+    ///
+    /// The caller must ensure that `from` lies fully within `src`, and that the destination
+    /// region (`to` extended by the size of `from`) lies fully within `self`. Copying memory
+    /// outside either grid's storage is _[undefined behavior][]_.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn copy_rect_from_unchecked<B2>(
+        &mut self,
+        src: &GridBuf<T, B2, layout::RowMajor>,
+        from: Rect,
+        to: Pos,
+    ) where
+        B2: AsRef<[T]>,
+    {
+        let width = from.width();
+        if width == 0 || from.height() == 0 {
+            return;
+        }
+        let src_origin = from.top_left();
+        let src_width = src.width;
+        let dst_width = self.width;
+        let src_ptr = src.buffer.as_ref().as_ptr();
+        let dst_ptr = self.buffer.as_mut().as_mut_ptr();
+        for y in 0..from.height() {
+            let src_start = (src_origin.y + y) * src_width + src_origin.x;
+            let dst_start = (to.y + y) * dst_width + to.x;
+            // SAFETY: Both row segments are in bounds per the caller's contract, and the two
+            // buffers are disjoint (`&mut self` and `&src` cannot alias).
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    src_ptr.add(src_start),
+                    dst_ptr.add(dst_start),
+                    width,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::GridBuf,
+        core::{Pos, Rect},
+        ops::layout::RowMajor,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn copy_rect_from_within_bounds() {
+        #[rustfmt::skip]
+        let src = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+
+        let mut dst = GridBuf::<_, _, RowMajor>::new(3, 3);
+        dst.copy_rect_from(&src, Rect::from_ltwh(0, 0, 2, 2), Pos::new(1, 1));
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0, 0,
+            0, 1, 2,
+            0, 3, 4,
+        ]);
+    }
+
+    #[test]
+    fn copy_rect_from_clips_destination() {
+        let src = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+
+        let mut dst = GridBuf::<_, _, RowMajor>::new(3, 3);
+        dst.copy_rect_from(&src, Rect::from_ltwh(0, 0, 2, 2), Pos::new(2, 2));
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0, 0,
+            0, 0, 0,
+            0, 0, 1,
+        ]);
+    }
+
+    #[test]
+    fn copy_rect_from_unchecked_copies_rows() {
+        let src = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+
+        let mut dst = GridBuf::<_, _, RowMajor>::new(3, 3);
+        unsafe {
+            dst.copy_rect_from_unchecked(&src, Rect::from_ltwh(0, 0, 2, 2), Pos::new(1, 0));
+        }
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 1, 2,
+            0, 3, 4,
+            0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn copy_rect_from_clips_source() {
+        let src = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+
+        let mut dst = GridBuf::<_, _, RowMajor>::new(3, 3);
+        dst.copy_rect_from(&src, Rect::from_ltwh(1, 1, 2, 2), Pos::new(0, 0));
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            4, 0, 0,
+            0, 0, 0,
+            0, 0, 0,
+        ]);
+    }
+}
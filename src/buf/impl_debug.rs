@@ -0,0 +1,108 @@
+use core::fmt::{self, Debug, Formatter, Write as _};
+
+use crate::{buf::GridBuf, core::Pos, ops::layout};
+
+/// Maximum rows or columns shown by the alternate `Debug` format before truncating.
+const MAX_PREVIEW: usize = 8;
+
+/// Counts the characters written to it, for measuring cell widths before printing.
+struct WidthCounter(usize);
+
+impl fmt::Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+impl<T, B, L> Debug for GridBuf<T, B, L>
+where
+    T: Debug,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Formats the grid for debugging.
+    ///
+    /// The default format is a compact field dump; the alternate format (`{:#?}`) draws the grid
+    /// as aligned rows, truncated to the top-left `8x8` corner for large grids.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return f
+                .debug_struct("GridBuf")
+                .field("width", &self.width)
+                .field("height", &self.height)
+                .field("buffer", &self.buffer.as_ref())
+                .finish();
+        }
+
+        let cols = self.width.min(MAX_PREVIEW);
+        let rows = self.height.min(MAX_PREVIEW);
+        let buffer = self.buffer.as_ref();
+        let stride = layout::stride::<L>(self.width, self.height);
+        let cell = |x: usize, y: usize| &buffer[L::pos_to_index(Pos::new(x, y), stride)];
+
+        let mut cell_width = 0;
+        for y in 0..rows {
+            for x in 0..cols {
+                let mut counter = WidthCounter(0);
+                write!(counter, "{:?}", cell(x, y))?;
+                cell_width = cell_width.max(counter.0);
+            }
+        }
+
+        writeln!(f, "GridBuf {}x{} [", self.width, self.height)?;
+        for y in 0..rows {
+            f.write_str("   ")?;
+            for x in 0..cols {
+                let mut counter = WidthCounter(0);
+                write!(counter, "{:?}", cell(x, y))?;
+                for _ in counter.0..=cell_width {
+                    f.write_char(' ')?;
+                }
+                write!(f, "{:?}", cell(x, y))?;
+            }
+            if self.width > cols {
+                f.write_str(" …")?;
+            }
+            f.write_char('\n')?;
+        }
+        if self.height > rows {
+            f.write_str("    …\n")?;
+        }
+        f.write_char(']')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, ops::layout::RowMajor};
+    use alloc::format;
+
+    #[test]
+    fn debug_is_a_field_dump() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer([1, 2, 3, 4], 2);
+        let output = format!("{grid:?}");
+        assert_eq!(
+            output,
+            "GridBuf { width: 2, height: 2, buffer: [1, 2, 3, 4] }"
+        );
+    }
+
+    #[test]
+    fn debug_alternate_draws_aligned_rows() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer([1, 2, 30, 4], 2);
+        let output = format!("{grid:#?}");
+        assert_eq!(output, "GridBuf 2x2 [\n     1  2\n    30  4\n]");
+    }
+
+    #[test]
+    fn debug_alternate_truncates_large_grids() {
+        let grid = GridBuf::<u8, _, RowMajor>::new(10, 10);
+        let output = format!("{grid:#?}");
+        assert!(output.starts_with("GridBuf 10x10 ["));
+        assert!(output.contains(" …\n"));
+        assert!(output.ends_with("    …\n]"));
+    }
+}
@@ -0,0 +1,51 @@
+use core::marker::PhantomData;
+
+use crate::{buf::GridBuf, ops::layout};
+
+impl<T, B, L> Default for GridBuf<T, B, L>
+where
+    B: Default + AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Returns an empty `0x0` grid backed by `B::default()`.
+    ///
+    /// This is intended for growable buffers such as `Vec`, whose default is empty; for a
+    /// fixed-size buffer, any elements in the defaulted buffer are unreachable through the grid.
+    fn default() -> Self {
+        Self {
+            buffer: B::default(),
+            width: 0,
+            height: 0,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::GridBuf,
+        core::Pos,
+        ops::{ExactSizeGrid as _, GridRead as _, layout::RowMajor},
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn default_grid_is_empty() {
+        let grid = GridBuf::<u8, Vec<u8>, RowMajor>::default();
+        assert_eq!(grid.width(), 0);
+        assert_eq!(grid.height(), 0);
+        assert_eq!(grid.get(Pos::new(0, 0)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn default_grid_can_be_resized() {
+        let mut grid = GridBuf::<u8, Vec<u8>, RowMajor>::default();
+        grid.resize_filled(2, 2, 7);
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&7));
+    }
+}
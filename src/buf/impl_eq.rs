@@ -0,0 +1,65 @@
+use crate::{buf::GridBuf, ops::layout};
+
+impl<T, B, B2, L> PartialEq<GridBuf<T, B2, L>> for GridBuf<T, B, L>
+where
+    T: PartialEq,
+    B: AsRef<[T]>,
+    B2: AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Grids are equal when they have the same dimensions and elementwise-equal contents.
+    ///
+    /// The backing buffers may be different types; a `Vec`-backed grid compares equal to an
+    /// array-backed one with the same contents. For comparing grids of different layouts or
+    /// element types, see [`grids_equal`](crate::ops::grids_equal).
+    fn eq(&self, other: &GridBuf<T, B2, L>) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.buffer.as_ref() == other.buffer.as_ref()
+    }
+}
+
+impl<T, B, L> Eq for GridBuf<T, B, L>
+where
+    T: Eq,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, core::Pos, ops::layout::RowMajor};
+    use alloc::vec;
+
+    #[test]
+    fn equal_grids_compare_equal() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let b = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn differing_contents_compare_unequal() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let mut b = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        b[Pos::new(0, 1)] = 9;
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn differing_dimensions_compare_unequal() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let b = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 4);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn buffer_types_may_differ() {
+        let owned = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let borrowed = GridBuf::<i32, _, RowMajor>::from_buffer([1, 2, 3, 4], 2);
+        assert_eq!(owned, borrowed);
+    }
+}
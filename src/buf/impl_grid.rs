@@ -1,9 +1,9 @@
 use crate::{
     buf::GridBuf,
-    core::{Pos, Size},
+    core::{Pos, Rect, Size},
     internal,
     ops::{
-        ExactSizeGrid, GridBase, layout,
+        ExactSizeGrid, GridBase, GridReadMut, layout,
         unchecked::{GridReadUnchecked, GridWriteUnchecked, TrustedSizeGrid},
     },
 };
@@ -33,8 +33,8 @@ where
 
 // SAFETY: `GridBuf` always reports its exact dimensions from `size_hint()` (see `GridBase` impl),
 // and those dimensions match `ExactSizeGrid::width()`/`height()`. The buffer length is always
-// `width * height` (enforced by `from_buffer` and constructors), so unchecked indexing into
-// the buffer at `L::pos_to_index(pos, width)` for any pos within `(0..width, 0..height)` is safe.
+// `width * height` (enforced by `from_buffer` and constructors), so unchecked indexing into the
+// buffer at `L::pos_to_index(pos, stride)` for any pos within `(0..width, 0..height)` is safe.
 unsafe impl<T, B, L> TrustedSizeGrid for GridBuf<T, B, L> where L: layout::Linear {}
 
 impl<T, B, L> GridReadUnchecked for GridBuf<T, B, L>
@@ -50,7 +50,7 @@ where
     type Layout = L;
 
     unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
-        let index = L::pos_to_index(pos, self.width);
+        let index = L::pos_to_index(pos, self.stride());
         // SAFETY: The caller guarantees `pos` is in bounds, and `TrustedSizeGrid` guarantees
         // `index < self.buffer.len()`. The buffer is at least `width * height` elements long.
         unsafe { self.buffer.as_ref().get_unchecked(index) }
@@ -65,17 +65,36 @@ where
             // the layout's storage order. When it returns `Some`, the returned slice covers
             // exactly the positions in `bounds`. The caller guarantees every position is valid,
             // so the slice is within the allocated buffer.
-            internal::IterRect::Aligned(aligned.iter())
-        } else {
-            // SAFETY: For non-contiguous rects, iterate position-by-position. Each
-            // `self.get_unchecked(pos)` call is sound because the caller guarantees all
-            // positions in `bounds` are valid.
-            let iter = {
-                let pos = L::iter_pos(bounds);
-                pos.map(move |pos| unsafe { self.get_unchecked(pos) })
-            };
-            internal::IterRect::Unaligned(iter)
+            return internal::IterRect::Aligned(aligned.iter());
         }
+
+        // For other rects, iterate row segments as slices when each row of the rect maps to a
+        // contiguous index range (linear layouts use the same x-stride on every row), avoiding
+        // the per-element index computation of position-by-position access.
+        let left = bounds.top_left().x;
+        let top = bounds.top_left().y;
+        let stride = self.stride();
+        let row_chunked = bounds.width() > 0 && bounds.height() > 0 && {
+            let start = L::pos_to_index(Pos::new(left, top), stride);
+            let end = L::pos_to_index(Pos::new(bounds.right() - 1, top), stride);
+            end >= start && end - start + 1 == bounds.width()
+        };
+        let iter = if row_chunked {
+            let buffer = self.buffer.as_ref();
+            internal::IterRect::Aligned((top..bounds.bottom()).flat_map(move |y| {
+                let start = L::pos_to_index(Pos::new(left, y), stride);
+                // SAFETY: The caller guarantees every position in `bounds` is valid, and the
+                // row segment was measured to be contiguous above, so `start..start + width`
+                // covers exactly the positions of this row within the allocated buffer.
+                unsafe { buffer.get_unchecked(start..start + bounds.width()) }.iter()
+            }))
+        } else {
+            // SAFETY: Each `self.get_unchecked(pos)` call is sound because the caller
+            // guarantees all positions in `bounds` are valid.
+            let pos = L::iter_pos(bounds);
+            internal::IterRect::Unaligned(pos.map(move |pos| unsafe { self.get_unchecked(pos) }))
+        };
+        internal::IterRect::Unaligned(iter)
     }
 }
 
@@ -88,7 +107,7 @@ where
     type Layout = L;
 
     unsafe fn set_unchecked(&mut self, pos: Pos, value: Self::Element) {
-        let index = L::pos_to_index(pos, self.width);
+        let index = L::pos_to_index(pos, self.stride());
         // SAFETY: The caller guarantees `pos` is in bounds, and `TrustedSizeGrid` guarantees
         // `index < self.buffer.len()`. The buffer is at least `width * height` elements long.
         unsafe { *self.buffer.as_mut().get_unchecked_mut(index) = value }
@@ -126,11 +145,34 @@ where
         Self::Element: Copy,
     {
         let size = self.size();
+        if bounds.width() == 0 || bounds.height() == 0 {
+            return;
+        }
         if let Some(aligned) = L::slice_rect_aligned_mut(self.as_mut(), size, bounds) {
             // SAFETY: `slice_rect_aligned_mut` returns `None` when the bounds are not contiguous.
             // When `Some`, the mutable slice covers exactly the positions in `bounds`.
             // `slice.fill(value)` is safe because the slice is within the allocated buffer.
             aligned.fill(value);
+            return;
+        }
+
+        // Even when the whole rect is not one contiguous range, each row segment usually is
+        // (e.g. a partial-width rect in a `RowMajor` buffer). Linear layouts use the same
+        // x-stride on every row, so measuring the first row's index span detects the case.
+        let left = bounds.top_left().x;
+        let top = bounds.top_left().y;
+        let stride = self.stride();
+        let start = L::pos_to_index(Pos::new(left, top), stride);
+        let end = L::pos_to_index(Pos::new(bounds.right() - 1, top), stride);
+        if end >= start && end - start + 1 == bounds.width() {
+            let buffer = self.buffer.as_mut();
+            for y in top..bounds.bottom() {
+                let start = L::pos_to_index(Pos::new(left, y), stride);
+                // SAFETY: The caller guarantees every position in `bounds` is valid, and the
+                // row segment was measured to be contiguous above, so `start..start + width`
+                // covers exactly the positions of this row within the allocated buffer.
+                unsafe { buffer.get_unchecked_mut(start..start + bounds.width()) }.fill(value);
+            }
         } else {
             for pos in L::iter_pos(bounds) {
                 // SAFETY: The caller guarantees every position in `bounds` is valid.
@@ -139,3 +181,73 @@ where
         }
     }
 }
+
+impl<T, B> GridReadMut for GridBuf<T, B, layout::RowMajor>
+where
+    B: AsMut<[T]>,
+{
+    type Element = T;
+    type Layout = layout::RowMajor;
+
+    fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        GridBuf::get_mut(self, pos)
+    }
+
+    fn iter_rect_mut(&mut self, bounds: Rect) -> impl Iterator<Item = &mut T> {
+        let bounds = self.trim_rect(bounds);
+        let origin = bounds.top_left();
+        let cols = origin.x..bounds.right();
+        self.buffer
+            .as_mut()
+            .chunks_exact_mut(self.width.max(1))
+            .skip(origin.y)
+            .take(bounds.height())
+            .flat_map(move |row| row[cols.clone()].iter_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::ops::{GridRead as _, layout::RowMajor};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn fill_rect_solid_partial_width_fills_row_segments() {
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(4, 3);
+        grid.fill_rect_solid(Rect::from_ltwh(1, 1, 2, 2), 7);
+
+        let (buffer, _, _) = grid.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0, 0, 0,
+            0, 7, 7, 0,
+            0, 7, 7, 0,
+        ]);
+    }
+
+    #[test]
+    fn iter_rect_partial_width_yields_row_segments() {
+        #[rustfmt::skip]
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ], 3);
+
+        let cells: Vec<_> = grid.iter_rect(Rect::from_ltwh(1, 0, 2, 3)).collect();
+        assert_eq!(cells, [&2, &3, &5, &6, &8, &9]);
+    }
+
+    #[test]
+    fn fill_rect_solid_empty_rect_is_a_no_op() {
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(2, 2);
+        grid.fill_rect_solid(Rect::from_ltwh(1, 1, 0, 5), 7);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,48 @@
+use core::hash::{Hash, Hasher};
+
+use crate::{buf::GridBuf, ops::layout};
+
+impl<T, B, L> Hash for GridBuf<T, B, L>
+where
+    T: Hash,
+    B: AsRef<[T]>,
+    L: layout::Linear,
+{
+    /// Hashes the grid's dimensions and contents, agreeing with its `PartialEq` implementation:
+    /// grids that compare equal hash equal, regardless of the backing buffer type.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.buffer.as_ref().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, core::Pos, ops::layout::RowMajor, test::fnv1a_hash_of as hash_of};
+    use alloc::vec;
+
+    #[test]
+    fn equal_grids_hash_equal() {
+        let owned = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let borrowed = GridBuf::<i32, _, RowMajor>::from_buffer([1, 2, 3, 4], 2);
+        assert_eq!(hash_of(&owned), hash_of(&borrowed));
+    }
+
+    #[test]
+    fn differing_contents_hash_differently() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let mut b = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        b[Pos::new(0, 0)] = 9;
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn dimensions_participate_in_the_hash() {
+        let wide = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 4);
+        let tall = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 1);
+        assert_ne!(hash_of(&wide), hash_of(&tall));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{
+    buf::GridBuf,
+    core::Rect,
+    ops::{GridBase as _, layout},
+};
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsRef<[T]> + AsMut<[T]>,
+{
+    /// Returns an iterator over mutable references to elements in a rectangular region.
+    ///
+    /// Elements are yielded in row-major order. The bounding rectangle is trimmed to the grid,
+    /// as with [`GridRead::iter_rect`][]. Each row of the region is borrowed as one contiguous
+    /// slice, so iteration does not bounds-check per cell.
+    ///
+    /// [`GridRead::iter_rect`]: crate::ops::GridRead::iter_rect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let mut grid = GridBuf::new_filled(3, 3, 1);
+    /// for cell in grid.iter_rect_mut(Rect::from_ltwh(1, 1, 2, 2)) {
+    ///     *cell = 9;
+    /// }
+    ///
+    /// assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+    /// assert_eq!(grid.get(Pos::new(1, 1)), Some(&9));
+    /// ```
+    pub fn iter_rect_mut(&mut self, bounds: Rect) -> impl Iterator<Item = &mut T> {
+        let bounds = self.trim_rect(bounds);
+        let left = bounds.top_left().x;
+        let width = bounds.width();
+        self.buffer
+            .as_mut()
+            .chunks_mut(self.width.max(1))
+            .skip(bounds.top_left().y)
+            .take(bounds.height())
+            .flat_map(move |row| row[left..left + width].iter_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, core::Rect, ops::layout::RowMajor};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iter_rect_mut_partial_region() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ], 3);
+
+        for cell in grid.iter_rect_mut(Rect::from_ltwh(1, 1, 2, 2)) {
+            *cell = 0;
+        }
+
+        let (buffer, _, _) = grid.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            1, 2, 3,
+            4, 0, 0,
+            7, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn iter_rect_mut_in_layout_order() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        let values: Vec<i32> = grid
+            .iter_rect_mut(Rect::from_ltwh(0, 0, 3, 2))
+            .map(|cell| *cell)
+            .collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn iter_rect_mut_trims_out_of_bounds() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        assert_eq!(grid.iter_rect_mut(Rect::from_ltwh(1, 1, 5, 5)).count(), 1);
+    }
+}
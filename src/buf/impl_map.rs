@@ -0,0 +1,96 @@
+use crate::{
+    buf::GridBuf,
+    core::Rect,
+    ops::{GridBase as _, layout},
+};
+
+impl<T, B, L> GridBuf<T, B, L>
+where
+    B: AsRef<[T]> + AsMut<[T]>,
+    L: layout::Linear,
+{
+    /// Reads and rewrites each cell in a rectangular region in place.
+    ///
+    /// This is a linear-buffer fast path for [`map_rect`][]: when the rectangle maps to one
+    /// contiguous range in the layout's storage order, the region is rewritten through a single
+    /// mutable slice without per-cell bounds checks.
+    ///
+    /// [`map_rect`]: crate::ops::map_rect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let mut grid = GridBuf::new_filled(3, 3, 1);
+    /// grid.map_rect(Rect::from_ltwh(0, 0, 3, 1), |&x| x * 2);
+    ///
+    /// assert_eq!(grid.get(Pos::new(0, 0)), Some(&2));
+    /// assert_eq!(grid.get(Pos::new(0, 1)), Some(&1));
+    /// ```
+    pub fn map_rect(&mut self, bounds: Rect, mut f: impl FnMut(&T) -> T) {
+        let bounds = self.trim_rect(bounds);
+        let size = crate::core::Size::new(self.width, self.height);
+        let stride = layout::stride::<L>(self.width, self.height);
+        if let Some(aligned) = L::slice_rect_aligned_mut(self.buffer.as_mut(), size, bounds) {
+            for cell in aligned {
+                let value = f(cell);
+                *cell = value;
+            }
+        } else {
+            for pos in L::iter_pos(bounds) {
+                let index = L::pos_to_index(pos, stride);
+                if let Some(cell) = self.buffer.as_mut().get_mut(index) {
+                    let value = f(cell);
+                    *cell = value;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, core::Rect, ops::layout::RowMajor};
+    use alloc::vec;
+
+    #[test]
+    fn map_rect_aligned_full_width() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+
+        grid.map_rect(Rect::from_ltwh(0, 0, 2, 1), |&x| x * 10);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![10, 20, 3, 4]);
+    }
+
+    #[test]
+    fn map_rect_unaligned_partial_width() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+
+        grid.map_rect(Rect::from_ltwh(1, 0, 1, 2), |&x| x * 10);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![1, 20, 3, 40]);
+    }
+
+    #[test]
+    fn map_rect_trims_out_of_bounds() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+
+        grid.map_rect(Rect::from_ltwh(0, 0, 5, 5), |&x| x + 1);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(feature = "ndarray")]
+extern crate alloc;
+
+#[cfg(feature = "ndarray")]
+use crate::buf::GridBuf;
+#[cfg(feature = "ndarray")]
+use crate::ops::layout;
+#[cfg(feature = "ndarray")]
+use alloc::vec::Vec;
+#[cfg(feature = "ndarray")]
+use ndarray::{Array2, ArrayView2};
+
+#[cfg(feature = "ndarray")]
+impl<T> From<Array2<T>> for GridBuf<T, Vec<T>, layout::RowMajor> {
+    /// Converts an `Array2` into a grid, with array rows becoming grid rows.
+    ///
+    /// Elements are collected in the array's logical (row-major) order, so non-standard storage
+    /// layouts convert correctly.
+    fn from(array: Array2<T>) -> Self {
+        let (_, width) = array.dim();
+        let data: Vec<T> = array.into_iter().collect();
+        GridBuf::from_buffer(data, width)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> From<GridBuf<T, Vec<T>, layout::RowMajor>> for Array2<T> {
+    /// Converts a grid into an `Array2` of shape `(height, width)` without copying.
+    fn from(grid: GridBuf<T, Vec<T>, layout::RowMajor>) -> Self {
+        let (buffer, width, height) = grid.into_inner();
+        Array2::from_shape_vec((height, width), buffer)
+            .expect("Grid buffer length matches its dimensions")
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a, T> GridBuf<T, &'a [T], layout::RowMajor> {
+    /// Returns a grid borrowing an `ArrayView2`'s data, or `None` if the view is not contiguous
+    /// in standard (row-major) layout.
+    ///
+    /// Views into sliced or transposed arrays are not contiguous; call
+    /// `as_standard_layout()` on the array first to get an owned copy in the right order.
+    #[must_use]
+    pub fn from_array_view(view: ArrayView2<'a, T>) -> Option<Self> {
+        let (_, width) = view.dim();
+        let slice = view.to_slice()?;
+        Some(GridBuf::from_buffer(slice, width))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ndarray")]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use ndarray::{Array2, array};
+
+    use crate::{buf::GridBuf, core::Pos, ops::GridRead as _, ops::layout::RowMajor};
+
+    #[test]
+    fn array_to_grid() {
+        let array = array![[1, 2, 3], [4, 5, 6]];
+        let grid: GridBuf<i32, Vec<i32>, RowMajor> = array.into();
+        assert_eq!(grid.get(Pos::new(2, 0)), Some(&3));
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&4));
+    }
+
+    #[test]
+    fn grid_to_array() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        let array: Array2<i32> = grid.into();
+        assert_eq!(array.dim(), (2, 3));
+        assert_eq!(array[[0, 2]], 3);
+        assert_eq!(array[[1, 0]], 4);
+    }
+
+    #[test]
+    fn transposed_array_converts_in_logical_order() {
+        let array = array![[1, 2], [3, 4]].reversed_axes();
+        let grid: GridBuf<i32, Vec<i32>, RowMajor> = array.into();
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(&3));
+    }
+
+    #[test]
+    fn view_borrows_contiguous_data() {
+        let array = array![[1, 2], [3, 4]];
+        let grid = GridBuf::from_array_view(array.view()).unwrap();
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&4));
+
+        let transposed = array.view().reversed_axes();
+        assert!(GridBuf::from_array_view(transposed).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    buf::VecGrid,
+    core::{GridError, Size},
+};
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<Vec<Vec<T>>> for VecGrid<T> {
+    type Error = GridError;
+
+    /// Builds a grid from nested row vectors, validating that every row has the same length.
+    ///
+    /// The outer vector is rows top to bottom; the width is taken from the first row. JSON data
+    /// models and existing code that use `Vec<Vec<T>>` convert without reshaping by hand.
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut buffer = Vec::with_capacity(width * height);
+        for row in rows {
+            if row.len() != width {
+                return Err(GridError::SizeMismatch {
+                    expected: Size::new(width, height),
+                    actual: Size::new(row.len(), height),
+                });
+            }
+            buffer.extend(row);
+        }
+        Ok(Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VecGrid<T> {
+    /// Returns the grid's rows as nested vectors, top to bottom.
+    ///
+    /// This is the inverse of the `TryFrom<Vec<Vec<T>>>` conversion.
+    #[must_use]
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{core::Pos, ops::GridRead as _};
+    use alloc::vec;
+
+    #[test]
+    fn try_from_nested_rows() {
+        let grid = VecGrid::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Pos::new(0, 2)), None); // Out of bounds
+    }
+
+    #[test]
+    fn try_from_rejects_ragged_rows() {
+        assert_eq!(
+            VecGrid::try_from(vec![vec![1, 2], vec![3]]),
+            Err(GridError::SizeMismatch {
+                expected: Size::new(2, 2),
+                actual: Size::new(1, 2),
+            })
+        );
+    }
+
+    #[test]
+    fn nested_vec_roundtrip() {
+        let rows = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+        let grid = VecGrid::try_from(rows.clone()).unwrap();
+        assert_eq!(grid.to_nested_vec(), rows);
+    }
+}
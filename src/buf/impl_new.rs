@@ -1,7 +1,9 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-use crate::{buf::GridBuf, ops::layout};
+#[cfg(feature = "alloc")]
+use crate::core::{Pos, Rect};
+use crate::{buf::GridBuf, core::GridError, ops::layout};
 use core::marker::PhantomData;
 
 impl<T, B, L> GridBuf<T, B, L>
@@ -18,7 +20,8 @@ where
     ///
     /// ## Panics
     ///
-    /// This panics if the buffer length is not a multiple of the width.
+    /// This panics if the buffer length is not a multiple of the width; see
+    /// [`try_from_buffer`](GridBuf::try_from_buffer) to validate untrusted data instead.
     ///
     /// ## Example
     ///
@@ -35,18 +38,33 @@ where
     /// ```
     #[must_use]
     pub fn from_buffer(buffer: B, width: usize) -> Self {
-        let height = buffer.as_ref().len() / width;
-        assert!(
-            height * width == buffer.as_ref().len(),
-            "Buffer length must be a multiple of width"
-        );
-        Self {
+        Self::try_from_buffer(buffer, width)
+            .unwrap_or_else(|_| panic!("Buffer length must be a multiple of width"))
+    }
+
+    /// Returns a grid from an existing buffer, or an error if the length does not fit the width.
+    ///
+    /// This is the fallible form of [`from_buffer`](GridBuf::from_buffer) for buffer data that
+    /// comes from untrusted sources such as files. A width of `0` is valid only for an empty
+    /// buffer.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError::InvalidBufferLength`] if the buffer length is not a multiple of the
+    /// width.
+    pub fn try_from_buffer(buffer: B, width: usize) -> Result<Self, GridError> {
+        let len = buffer.as_ref().len();
+        let height = len.checked_div(width).unwrap_or(0);
+        if height * width != len {
+            return Err(GridError::InvalidBufferLength { len, width });
+        }
+        Ok(Self {
             buffer,
             width,
             height,
             _layout: PhantomData,
             _element: PhantomData,
-        }
+        })
     }
 }
 
@@ -81,6 +99,11 @@ impl<T> GridBuf<T, alloc::vec::Vec<T>, layout::RowMajor> {
     ///
     /// [`new_filled_with_layout`]: GridBuf::new_filled_with_layout
     ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`; see
+    /// [`try_new_filled`](GridBuf::try_new_filled) for a checked alternative.
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -97,7 +120,101 @@ impl<T> GridBuf<T, alloc::vec::Vec<T>, layout::RowMajor> {
     where
         T: Copy,
     {
-        let buffer = alloc::vec![value; width * height];
+        Self::try_new_filled(width, height, value)
+            .unwrap_or_else(|_| panic!("Grid dimensions are too large"))
+    }
+
+    /// Creates a new grid with the specified width and height, filled with a default value.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError::TooLarge`] if `width * height` overflows `usize`; see
+    /// [`new`](GridBuf::new) for a panicking alternative.
+    pub fn try_new(width: usize, height: usize) -> Result<Self, GridError>
+    where
+        T: Copy + Default,
+    {
+        Self::try_new_filled(width, height, T::default())
+    }
+
+    /// Creates a new grid with the specified width and height, filled with a specified value.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError::TooLarge`] if `width * height` overflows `usize`.
+    pub fn try_new_filled(width: usize, height: usize, value: T) -> Result<Self, GridError>
+    where
+        T: Copy,
+    {
+        let len = width
+            .checked_mul(height)
+            .ok_or(GridError::TooLarge { width, height })?;
+        let buffer = alloc::vec![value; len];
+        Ok(Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        })
+    }
+
+    /// Creates a new grid by evaluating a function at every position.
+    ///
+    /// This creates a grid with a row-major layout; see
+    /// [`new_generate_with_layout`](GridBuf::new_generate_with_layout) to customize.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::new_generate(3, 3, |pos| pos.x + pos.y);
+    /// assert_eq!(grid.get(Pos::new(2, 2)), Some(&4));
+    /// ```
+    pub fn new_generate(width: usize, height: usize, f: impl FnMut(Pos) -> T) -> Self {
+        Self::new_generate_with_layout(width, height, f)
+    }
+
+    /// Creates a grid from a newline-separated string, mapping each character to an element.
+    ///
+    /// The width is inferred from the first line, and the height from the number of lines; a
+    /// trailing newline is ignored. This keeps fixture maps in tests and examples readable.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if lines have differing lengths.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::from_str_map(".#.\n...\n", |c| c == '#');
+    /// assert_eq!(grid.get(Pos::new(1, 0)), Some(&true));
+    /// assert_eq!(grid.get(Pos::new(1, 1)), Some(&false));
+    /// ```
+    #[must_use]
+    pub fn from_str_map(s: &str, map: impl Fn(char) -> T) -> Self {
+        let mut buffer = alloc::vec::Vec::new();
+        let mut width = None;
+        for line in s.lines() {
+            let start = buffer.len();
+            buffer.extend(line.chars().map(&map));
+            let row_len = buffer.len() - start;
+            match width {
+                None => width = Some(row_len),
+                Some(expected) => {
+                    assert!(expected == row_len, "Lines must have equal lengths");
+                }
+            }
+        }
+        let width = width.unwrap_or(0);
+        let height = buffer.len().checked_div(width).unwrap_or(0);
         Self {
             buffer,
             width,
@@ -117,6 +234,10 @@ where
     ///
     /// The layout is specified by the type parameter `L`, allowing for different memory layouts.
     ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
+    ///
     /// ## Example
     #[must_use]
     pub fn new_filled_with_layout(width: usize, height: usize, value: T) -> Self
@@ -124,7 +245,10 @@ where
         T: Copy,
         L: layout::Linear,
     {
-        let buffer = alloc::vec![value; width * height];
+        let len = width
+            .checked_mul(height)
+            .expect("Grid dimensions are too large");
+        let buffer = alloc::vec![value; len];
         Self {
             buffer,
             width,
@@ -133,6 +257,55 @@ where
             _element: PhantomData,
         }
     }
+
+    /// Creates a new grid by evaluating a function at every position.
+    ///
+    /// Positions are visited in the layout's storage order, so the buffer fills sequentially
+    /// for cache efficiency.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `width * height` overflows `usize`.
+    pub fn new_generate_with_layout(width: usize, height: usize, f: impl FnMut(Pos) -> T) -> Self {
+        let len = width
+            .checked_mul(height)
+            .expect("Grid dimensions are too large");
+        let mut buffer = alloc::vec::Vec::with_capacity(len);
+        buffer.extend(L::iter_pos(Rect::from_ltwh(0, 0, width, height)).map(f));
+        Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+
+    /// Collects an iterator into a grid with a given width in columns.
+    ///
+    /// Elements fill the buffer in the grid's layout order, and the height is inferred from the
+    /// number of elements. This avoids the intermediate `Vec` plus
+    /// [`from_buffer`](GridBuf::from_buffer) pair when building a grid from a parsed stream.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError::InvalidBufferLength`] if the iterator's length is not a multiple of
+    /// the width.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::<_, _, RowMajor>::from_iter_with_width(3, 1..=6).unwrap();
+    /// assert_eq!(grid.get(Pos::new(2, 1)), Some(&6));
+    /// ```
+    pub fn from_iter_with_width(
+        width: usize,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<Self, GridError> {
+        Self::try_from_buffer(iter.into_iter().collect::<alloc::vec::Vec<T>>(), width)
+    }
 }
 
 #[cfg(test)]
@@ -150,6 +323,62 @@ mod tests {
         let _grid = GridBuf::<_, _, RowMajor>::from_buffer(buffer, 2);
     }
 
+    #[test]
+    fn try_from_buffer_accepts_whole_rows() {
+        let grid = GridBuf::<_, _, RowMajor>::try_from_buffer(vec![1, 2, 3, 4], 2).unwrap();
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&4));
+    }
+
+    #[test]
+    fn try_from_buffer_rejects_partial_rows() {
+        assert_eq!(
+            GridBuf::<_, _, RowMajor>::try_from_buffer(vec![1, 2, 3], 2),
+            Err(crate::core::GridError::InvalidBufferLength { len: 3, width: 2 })
+        );
+    }
+
+    #[test]
+    fn try_from_buffer_zero_width_requires_empty() {
+        let grid = GridBuf::<u8, _, RowMajor>::try_from_buffer(vec![], 0).unwrap();
+        assert_eq!(grid.get(Pos::new(0, 0)), None);
+        assert!(GridBuf::<u8, _, RowMajor>::try_from_buffer(vec![1], 0).is_err());
+    }
+
+    #[test]
+    fn new_generate_evaluates_positions() {
+        let grid = GridBuf::new_generate(3, 2, |pos| pos.y * 3 + pos.x);
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&5));
+    }
+
+    #[test]
+    fn new_generate_with_layout_col_major() {
+        let grid =
+            crate::buf::GridBuf::<_, _, crate::ops::layout::ColumnMajor>::new_generate_with_layout(
+                2,
+                3,
+                |pos| (pos.x, pos.y),
+            );
+        assert_eq!(grid.get(Pos::new(1, 2)), Some(&(1, 2)));
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&(0, 0)));
+    }
+
+    #[test]
+    fn try_new_builds_default_grid() {
+        let grid = GridBuf::<u8, _, RowMajor>::try_new(2, 2).unwrap();
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&0));
+    }
+
+    #[test]
+    fn try_new_rejects_overflowing_dimensions() {
+        assert_eq!(
+            GridBuf::<u8, _, RowMajor>::try_new(usize::MAX, 2),
+            Err(crate::core::GridError::TooLarge {
+                width: usize::MAX,
+                height: 2
+            })
+        );
+    }
+
     #[test]
     fn new_filled_with_layout() {
         let grid = GridBuf::<_, _, RowMajor>::new_filled_with_layout(3, 2, 42);
@@ -157,4 +386,37 @@ mod tests {
         assert_eq!(grid.get(Pos::new(2, 1)), Some(&42));
         assert_eq!(grid.get(Pos::new(3, 1)), None); // Out of bounds
     }
+
+    #[test]
+    fn from_iter_with_width_infers_height() {
+        let grid = GridBuf::<_, _, RowMajor>::from_iter_with_width(2, 1..=6).unwrap();
+        assert_eq!(grid.get(Pos::new(1, 2)), Some(&6));
+        assert_eq!(grid.get(Pos::new(0, 3)), None); // Out of bounds
+    }
+
+    #[test]
+    fn from_iter_with_width_rejects_partial_rows() {
+        assert!(GridBuf::<_, _, RowMajor>::from_iter_with_width(4, 1..=6).is_err());
+    }
+
+    #[test]
+    fn from_str_map_builds_rows() {
+        let grid = GridBuf::from_str_map(".#.\n#.#\n", |c| u8::from(c == '#'));
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(&1));
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&1));
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&0));
+        assert_eq!(grid.get(Pos::new(3, 0)), None); // Out of bounds
+    }
+
+    #[test]
+    fn from_str_map_empty_string_is_empty_grid() {
+        let grid = GridBuf::from_str_map("", |_| 0u8);
+        assert_eq!(grid.get(Pos::new(0, 0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lines must have equal lengths")]
+    fn from_str_map_panics_on_ragged_lines() {
+        let _grid = GridBuf::from_str_map("..\n...\n", |_| 0u8);
+    }
 }
@@ -0,0 +1,130 @@
+use crate::{buf::GridBuf, ops::layout};
+use core::marker::PhantomData;
+
+impl<T, L> GridBuf<T, &[T], L>
+where
+    L: layout::Linear,
+{
+    /// Returns a grid borrowing `len` elements starting at `ptr`, with a given width in columns.
+    ///
+    /// The height is inferred from `len` and `width`. This is the zero-copy entry point for
+    /// buffers owned elsewhere, such as C libraries or GPU staging memory; for Rust slice types
+    /// prefer [`from_buffer`](GridBuf::from_buffer).
+    ///
+    /// ## Safety
+    ///
+    /// `ptr` must be valid for reads of `len` initialized elements for the returned grid's
+    /// lifetime, and the memory must not be mutated for that lifetime. See
+    /// [`core::slice::from_raw_parts`].
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `len` is not a multiple of the width.
+    #[must_use]
+    pub unsafe fn from_raw_parts(ptr: *const T, len: usize, width: usize) -> Self {
+        let buffer = unsafe { core::slice::from_raw_parts(ptr, len) };
+        Self::from_buffer(buffer, width)
+    }
+
+    /// Consumes the grid, returning the data pointer, element count, and width.
+    ///
+    /// The returned pointer borrows the original buffer for the grid's lifetime; it can be
+    /// reassembled with
+    /// [`from_raw_parts`](GridBuf::from_raw_parts).
+    #[must_use]
+    pub fn into_raw_parts(self) -> (*const T, usize, usize) {
+        (self.buffer.as_ptr(), self.buffer.len(), self.width)
+    }
+}
+
+impl<T, L> GridBuf<T, &mut [T], L>
+where
+    L: layout::Linear,
+{
+    /// Returns a mutable grid borrowing `len` elements starting at `ptr`.
+    ///
+    /// The height is inferred from `len` and `width`.
+    ///
+    /// ## Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `len` initialized elements for the returned
+    /// grid's lifetime, and must not be aliased for that lifetime. See
+    /// [`core::slice::from_raw_parts_mut`].
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `len` is not a multiple of the width.
+    #[must_use]
+    pub unsafe fn from_raw_parts_mut(ptr: *mut T, len: usize, width: usize) -> Self {
+        let buffer = unsafe { core::slice::from_raw_parts_mut(ptr, len) };
+        let height = len / width;
+        assert!(
+            height * width == len,
+            "Buffer length must be a multiple of width"
+        );
+        Self {
+            buffer,
+            width,
+            height,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+
+    /// Consumes the grid, returning the data pointer, element count, and width.
+    ///
+    /// The returned pointer borrows the original buffer mutably for the grid's lifetime; it can
+    /// be reassembled with [`from_raw_parts_mut`](GridBuf::from_raw_parts_mut).
+    #[must_use]
+    pub fn into_raw_parts_mut(self) -> (*mut T, usize, usize) {
+        let width = self.width;
+        let len = self.buffer.len();
+        (self.buffer.as_mut_ptr(), len, width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, core::Pos, ops::GridRead as _, ops::GridWrite as _};
+    use alloc::vec;
+
+    #[test]
+    fn from_raw_parts_reads_foreign_buffer() {
+        let data = [1u8, 2, 3, 4, 5, 6];
+        let grid = unsafe {
+            GridBuf::<u8, _, crate::ops::layout::RowMajor>::from_raw_parts(data.as_ptr(), 6, 3)
+        };
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Pos::new(0, 2)), None); // Out of bounds
+    }
+
+    #[test]
+    fn from_raw_parts_mut_writes_through() {
+        let mut data = vec![0u8; 4];
+        let mut grid = unsafe {
+            GridBuf::<u8, _, crate::ops::layout::RowMajor>::from_raw_parts_mut(
+                data.as_mut_ptr(),
+                4,
+                2,
+            )
+        };
+        grid.set(Pos::new(1, 1), 42).unwrap();
+        assert_eq!(data, vec![0, 0, 0, 42]);
+    }
+
+    #[test]
+    fn raw_parts_roundtrip() {
+        let data = [7u8; 6];
+        let grid = unsafe {
+            GridBuf::<u8, _, crate::ops::layout::RowMajor>::from_raw_parts(data.as_ptr(), 6, 2)
+        };
+        let (ptr, len, width) = grid.into_raw_parts();
+        assert_eq!((len, width), (6, 2));
+        let grid = unsafe {
+            GridBuf::<u8, _, crate::ops::layout::RowMajor>::from_raw_parts(ptr, len, width)
+        };
+        assert_eq!(grid.get(Pos::new(1, 2)), Some(&7));
+    }
+}
@@ -0,0 +1,208 @@
+use rayon::prelude::*;
+
+use crate::{
+    buf::GridBuf,
+    core::{Pos, Rect},
+    ops::{GridBase as _, layout},
+};
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsRef<[T]> + AsMut<[T]>,
+{
+    /// Returns a parallel iterator over the rows of the grid as slices.
+    ///
+    /// Rows are yielded in order when collected, but are processed across the rayon thread pool.
+    pub fn par_iter_rows(&self) -> impl IndexedParallelIterator<Item = &[T]>
+    where
+        T: Sync,
+    {
+        self.buffer.as_ref().par_chunks(self.width.max(1))
+    }
+
+    /// Sets elements within a rectangular region of the grid in parallel.
+    ///
+    /// Work is split by row bands across the rayon thread pool. Out-of-bounds elements are
+    /// skipped, and the bounding rectangle is treated as _exclusive_ of the right and bottom
+    /// edges, as with [`GridWrite::fill_rect`][].
+    ///
+    /// [`GridWrite::fill_rect`]: crate::ops::GridWrite::fill_rect
+    pub fn par_fill_rect(&mut self, bounds: Rect, f: impl Fn(Pos) -> T + Sync)
+    where
+        T: Send,
+    {
+        let bounds = self.trim_rect(bounds);
+        let origin = bounds.top_left();
+        let width = self.width;
+        self.buffer
+            .as_mut()
+            .par_chunks_mut(width.max(1))
+            .enumerate()
+            .skip(origin.y)
+            .take(bounds.height())
+            .for_each(|(y, row)| {
+                for (x, cell) in row
+                    .iter_mut()
+                    .enumerate()
+                    .skip(origin.x)
+                    .take(bounds.width())
+                {
+                    *cell = f(Pos::new(x, y));
+                }
+            });
+    }
+
+    /// Copies a rectangular region from another `RowMajor` grid, in parallel.
+    ///
+    /// This is the parallel counterpart of [`GridBuf::copy_rect_from`]: the destination is
+    /// split into row bands across the rayon thread pool, and each row segment is moved with a
+    /// single slice copy. Cells that do not fit in either grid are clipped, as with
+    /// [`copy_rect`][].
+    ///
+    /// [`copy_rect`]: crate::ops::copy_rect
+    pub fn par_copy_rect_from<B2>(
+        &mut self,
+        src: &GridBuf<T, B2, layout::RowMajor>,
+        from: Rect,
+        to: Pos,
+    ) where
+        T: Copy + Send + Sync,
+        B2: AsRef<[T]>,
+    {
+        let from = src.trim_rect(from);
+        let dst_rect = self.trim_rect(Rect::from_ltwh(to.x, to.y, from.width(), from.height()));
+        let width = from.width().min(dst_rect.width());
+        let height = from.height().min(dst_rect.height());
+        let src_origin = from.top_left();
+        let src_width = src.width;
+        let src_buf = src.buffer.as_ref();
+        let dst_width = self.width;
+        self.buffer
+            .as_mut()
+            .par_chunks_mut(dst_width.max(1))
+            .enumerate()
+            .skip(to.y)
+            .take(height)
+            .for_each(|(y, row)| {
+                let src_start = (src_origin.y + (y - to.y)) * src_width + src_origin.x;
+                row[to.x..to.x + width].copy_from_slice(&src_buf[src_start..src_start + width]);
+            });
+    }
+
+    /// Reads and rewrites each cell in a rectangular region in place, in parallel.
+    ///
+    /// This is the parallel counterpart of [`GridBuf::map_rect`], splitting work by row bands
+    /// across the rayon thread pool.
+    pub fn par_map_rect(&mut self, bounds: Rect, f: impl Fn(&T) -> T + Sync)
+    where
+        T: Send,
+    {
+        let bounds = self.trim_rect(bounds);
+        let origin = bounds.top_left();
+        let width = self.width;
+        self.buffer
+            .as_mut()
+            .par_chunks_mut(width.max(1))
+            .skip(origin.y)
+            .take(bounds.height())
+            .for_each(|row| {
+                for cell in row.iter_mut().skip(origin.x).take(bounds.width()) {
+                    let value = f(cell);
+                    *cell = value;
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::GridBuf,
+        core::{Pos, Rect},
+        ops::layout::RowMajor,
+    };
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_iter_rows_in_order() {
+        #[rustfmt::skip]
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+
+        let rows: Vec<&[i32]> = grid.par_iter_rows().collect();
+        assert_eq!(rows, vec![&[1, 2][..], &[3, 4][..]]);
+    }
+
+    #[test]
+    fn par_fill_rect_partial() {
+        let mut grid = GridBuf::<_, _, RowMajor>::new(3, 3);
+        grid.par_fill_rect(Rect::from_ltwh(1, 1, 2, 2), |pos| pos.x + pos.y);
+
+        let (buffer, _, _) = grid.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0, 0,
+            0, 2, 3,
+            0, 3, 4,
+        ]);
+    }
+
+    #[test]
+    fn par_copy_rect_from_within_bounds() {
+        #[rustfmt::skip]
+        let src = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+
+        let mut dst = GridBuf::<_, _, RowMajor>::new(3, 3);
+        dst.par_copy_rect_from(&src, Rect::from_ltwh(0, 0, 2, 2), Pos::new(1, 1));
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0, 0,
+            0, 1, 2,
+            0, 3, 4,
+        ]);
+    }
+
+    #[test]
+    fn par_copy_rect_from_clips_both_grids() {
+        let src = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+
+        let mut dst = GridBuf::<_, _, RowMajor>::new(2, 2);
+        dst.par_copy_rect_from(&src, Rect::from_ltwh(1, 0, 2, 2), Pos::new(1, 1));
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0,
+            0, 2,
+        ]);
+    }
+
+    #[test]
+    fn par_map_rect_full() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        grid.par_map_rect(Rect::from_ltwh(0, 0, 2, 2), |&x| x * 10);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn par_fill_rect_trims_out_of_bounds() {
+        let mut grid = GridBuf::<_, _, RowMajor>::new(2, 2);
+        grid.par_fill_rect(Rect::from_ltwh(0, 0, 5, 5), |_| 7);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![7, 7, 7, 7]);
+    }
+}
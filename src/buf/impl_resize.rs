@@ -1,6 +1,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
 use crate::{buf::GridBuf, core::Pos, ops::layout};
 
 #[cfg(feature = "alloc")]
@@ -35,13 +36,15 @@ where
 
         let copy_w = self.width.min(new_width);
         let copy_h = self.height.min(new_height);
+        let dst_stride = layout::stride::<L>(new_width, new_height);
+        let src_stride = layout::stride::<L>(self.width, self.height);
         let mut new_buf = alloc::vec![T::default(); new_width * new_height];
 
         for row in 0..copy_h {
             for col in 0..copy_w {
                 let src_pos = Pos::new(col, row);
-                let dst_idx = L::pos_to_index(src_pos, new_width);
-                let src_idx = L::pos_to_index(src_pos, self.width);
+                let dst_idx = L::pos_to_index(src_pos, dst_stride);
+                let src_idx = L::pos_to_index(src_pos, src_stride);
                 new_buf[dst_idx] = self.buffer[src_idx].clone();
             }
         }
@@ -71,13 +74,15 @@ where
 
         let copy_w = self.width.min(new_width);
         let copy_h = self.height.min(new_height);
+        let dst_stride = layout::stride::<L>(new_width, new_height);
+        let src_stride = layout::stride::<L>(self.width, self.height);
         let mut new_buf = alloc::vec![value; new_width * new_height];
 
         for row in 0..copy_h {
             for col in 0..copy_w {
                 let src_pos = Pos::new(col, row);
-                let dst_idx = L::pos_to_index(src_pos, new_width);
-                let src_idx = L::pos_to_index(src_pos, self.width);
+                let dst_idx = L::pos_to_index(src_pos, dst_stride);
+                let src_idx = L::pos_to_index(src_pos, src_stride);
                 new_buf[dst_idx] = self.buffer[src_idx].clone();
             }
         }
@@ -0,0 +1,97 @@
+use crate::{buf::GridBuf, ops::layout};
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsMut<[T]>,
+{
+    /// Rotates a square grid 90 degrees clockwise in place.
+    ///
+    /// The rotation is performed with element swaps (four-way cycles per ring), so no second
+    /// buffer is allocated.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the grid is not square.
+    pub fn rotate90_in_place(&mut self) {
+        assert!(self.width == self.height, "Grid must be square");
+        let n = self.width;
+        let buffer = self.buffer.as_mut();
+        for layer in 0..n / 2 {
+            for i in layer..n - 1 - layer {
+                let top = layer * n + i;
+                let left = (n - 1 - i) * n + layer;
+                let bottom = (n - 1 - layer) * n + (n - 1 - i);
+                let right = i * n + (n - 1 - layer);
+                buffer.swap(top, left);
+                buffer.swap(left, bottom);
+                buffer.swap(bottom, right);
+            }
+        }
+    }
+
+    /// Rotates the grid 180 degrees in place.
+    ///
+    /// This works for any dimensions: reversing the row-major buffer reverses both axes at once,
+    /// so no second buffer is allocated.
+    pub fn rotate180_in_place(&mut self) {
+        self.buffer.as_mut().reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{core::Pos, ops::GridRead as _};
+    use alloc::vec;
+
+    #[test]
+    fn rotate90_square() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ], 3);
+        grid.rotate90_in_place();
+        #[rustfmt::skip]
+        let expected = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            7, 4, 1,
+            8, 5, 2,
+            9, 6, 3,
+        ], 3);
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn rotate90_four_times_is_identity() {
+        let mut grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let original = grid.clone();
+        for _ in 0..4 {
+            grid.rotate90_in_place();
+        }
+        assert_eq!(grid, original);
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid must be square")]
+    fn rotate90_rejects_rectangles() {
+        let mut grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        grid.rotate90_in_place();
+    }
+
+    #[test]
+    fn rotate180_rectangular() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+        ], 3);
+        grid.rotate180_in_place();
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&6));
+        assert_eq!(grid.get(Pos::new(2, 0)), Some(&4));
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&3));
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&1));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{buf::GridBuf, core::Pos, ops::layout};
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsRef<[T]>,
+{
+    /// Returns an iterator over the rows of the grid as slices.
+    ///
+    /// Rows are yielded top to bottom. Because each row of a `RowMajor` buffer is contiguous,
+    /// the slices can be used directly for `memcpy`-style copies or writes.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.buffer.as_ref().chunks(self.width.max(1))
+    }
+
+    /// Returns an iterator over the rows of the grid as mutable slices.
+    ///
+    /// Rows are yielded top to bottom. Because each row of a `RowMajor` buffer is contiguous,
+    /// the slices can be filled or copied into directly.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]>
+    where
+        B: AsMut<[T]>,
+    {
+        self.buffer.as_mut().chunks_mut(self.width.max(1))
+    }
+
+    /// Returns the row at `y` as a contiguous slice, or `None` if `y` is out of bounds.
+    ///
+    /// Whole rows can then be handed to I/O, hashing, or SIMD routines that operate on slices,
+    /// which is impossible through the element-wise API.
+    pub fn row_slice(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| {
+            let start = y * self.width;
+            &self.buffer.as_ref()[start..start + self.width]
+        })
+    }
+
+    /// Returns the row at `y` as a contiguous mutable slice, or `None` if `y` is out of bounds.
+    pub fn row_slice_mut(&mut self, y: usize) -> Option<&mut [T]>
+    where
+        B: AsMut<[T]>,
+    {
+        (y < self.height).then(|| {
+            let start = y * self.width;
+            &mut self.buffer.as_mut()[start..start + self.width]
+        })
+    }
+
+    /// Returns an iterator over positions where this grid differs from another, yielding both
+    /// elements.
+    ///
+    /// Rows are compared as slices first, so a run of unchanged rows costs one slice comparison
+    /// each; only differing rows are scanned cell by cell. For unrelated grid types, see
+    /// [`diff`](crate::ops::diff).
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the grids have different dimensions.
+    pub fn diff_rows<'a, B2>(
+        &'a self,
+        other: &'a GridBuf<T, B2, layout::RowMajor>,
+    ) -> impl Iterator<Item = (Pos, &'a T, &'a T)>
+    where
+        T: PartialEq,
+        B2: AsRef<[T]>,
+    {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "Grids must have the same dimensions"
+        );
+        self.rows()
+            .zip(other.rows())
+            .enumerate()
+            .filter(|(_, (ours, theirs))| ours != theirs)
+            .flat_map(|(y, (ours, theirs))| {
+                ours.iter()
+                    .zip(theirs)
+                    .enumerate()
+                    .filter(|(_, (a, b))| a != b)
+                    .map(move |(x, (a, b))| (Pos::new(x, y), a, b))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, core::Pos, ops::layout::RowMajor};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn rows_yields_slices() {
+        #[rustfmt::skip]
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+
+        let rows: Vec<&[i32]> = grid.rows().collect();
+        assert_eq!(rows, vec![&[1, 2][..], &[3, 4][..]]);
+    }
+
+    #[test]
+    fn rows_mut_allows_in_place_writes() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        for row in grid.rows_mut() {
+            row.fill(9);
+        }
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![9, 9, 9, 9]);
+    }
+
+    #[test]
+    fn row_slice_returns_contiguous_row() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        assert_eq!(grid.row_slice(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row_slice(2), None);
+
+        grid.row_slice_mut(0).unwrap().fill(9);
+        assert_eq!(grid.row_slice(0), Some(&[9, 9, 9][..]));
+        assert_eq!(grid.row_slice_mut(2), None);
+    }
+
+    #[test]
+    fn diff_rows_yields_changed_cells() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 2);
+        let b = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 9, 4, 5, 8], 2);
+
+        let changed: Vec<_> = a.diff_rows(&b).collect();
+        assert_eq!(
+            changed,
+            [(Pos::new(0, 1), &3, &9), (Pos::new(1, 2), &6, &8)]
+        );
+    }
+
+    #[test]
+    fn diff_rows_identical_grids_yield_nothing() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let b = GridBuf::<_, _, RowMajor>::from_buffer([1, 2, 3, 4], 2);
+        assert_eq!(a.diff_rows(&b).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Grids must have the same dimensions")]
+    fn diff_rows_panics_on_size_mismatch() {
+        let a = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let b = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 4);
+        let _ = a.diff_rows(&b).count();
+    }
+}
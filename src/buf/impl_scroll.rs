@@ -0,0 +1,119 @@
+use crate::{buf::GridBuf, ops::layout};
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    T: Copy,
+    B: AsRef<[T]> + AsMut<[T]>,
+{
+    /// Shifts the entire contents of the grid by a signed delta, filling exposed cells.
+    ///
+    /// This is a `RowMajor` fast path for [`scroll`][]: vertical shifts move whole row blocks
+    /// with a single `copy_within`, and horizontal shifts move one row segment per row.
+    ///
+    /// [`scroll`]: crate::ops::scroll
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3], 3);
+    /// grid.scroll(1, 0, 0);
+    ///
+    /// assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+    /// assert_eq!(grid.get(Pos::new(1, 0)), Some(&1));
+    /// ```
+    pub fn scroll(&mut self, dx: isize, dy: isize, fill: T) {
+        let width = self.width;
+        let height = self.height;
+        let sx = dx.unsigned_abs();
+        let sy = dy.unsigned_abs();
+        if sx >= width || sy >= height {
+            self.buffer.as_mut().fill(fill);
+            return;
+        }
+
+        let buffer = self.buffer.as_mut();
+        if dy > 0 {
+            buffer.copy_within(0..(height - sy) * width, sy * width);
+            buffer[..sy * width].fill(fill);
+        } else if dy < 0 {
+            buffer.copy_within(sy * width.., 0);
+            buffer[(height - sy) * width..].fill(fill);
+        }
+
+        if dx != 0 {
+            for row in buffer.chunks_exact_mut(width) {
+                if dx > 0 {
+                    row.copy_within(0..width - sx, sx);
+                    row[..sx].fill(fill);
+                } else {
+                    row.copy_within(sx.., 0);
+                    row[width - sx..].fill(fill);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, ops::layout::RowMajor};
+    use alloc::vec;
+
+    #[test]
+    fn scroll_right() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3], 3);
+        grid.scroll(1, 0, 0);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn scroll_down() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2,
+            3, 4,
+        ], 2);
+        grid.scroll(0, 1, 0);
+
+        let (buffer, _, _) = grid.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 0,
+            1, 2,
+        ]);
+    }
+
+    #[test]
+    fn scroll_up_left() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ], 3);
+        grid.scroll(-1, -1, 0);
+
+        let (buffer, _, _) = grid.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            5, 6, 0,
+            8, 9, 0,
+            0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn scroll_entire_grid_fills() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        grid.scroll(0, 2, 9);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![9, 9, 9, 9]);
+    }
+}
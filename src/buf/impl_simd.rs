@@ -0,0 +1,166 @@
+use crate::{
+    buf::GridBuf,
+    core::{Pos, Rect},
+    ops::{GridBase as _, layout},
+};
+
+macro_rules! impl_fill_rect_solid {
+    ($t:ty) => {
+        impl<B> GridBuf<$t, B, layout::RowMajor>
+        where
+            B: AsRef<[$t]> + AsMut<[$t]>,
+        {
+            /// Fills a rectangular region with a single value using chunked row writes.
+            ///
+            /// Each row segment of the region is filled with a single `slice::fill`, which the
+            /// compiler lowers to vectorized (`memset`-style) writes, including the unaligned
+            /// partial-row case that the generic trait path handles element-by-element. The
+            /// bounding rectangle is trimmed to the grid, as with
+            /// [`GridWrite::fill_rect_solid`][].
+            ///
+            /// This inherent method shadows the trait method of the same name when the `simd`
+            /// feature is enabled.
+            ///
+            /// [`GridWrite::fill_rect_solid`]: crate::ops::GridWrite::fill_rect_solid
+            pub fn fill_rect_solid(&mut self, dst: Rect, value: $t) {
+                let bounds = self.trim_rect(dst);
+                let width = self.width;
+                let origin = bounds.top_left();
+                for y in 0..bounds.height() {
+                    let start = (origin.y + y) * width + origin.x;
+                    self.buffer.as_mut()[start..start + bounds.width()].fill(value);
+                }
+            }
+        }
+    };
+}
+
+impl_fill_rect_solid!(u8);
+impl_fill_rect_solid!(u32);
+
+impl<B> GridBuf<u32, B, layout::RowMajor>
+where
+    B: AsRef<[u32]> + AsMut<[u32]>,
+{
+    /// Blends a rectangular region from another `RowMajor` grid using a row-slice kernel.
+    ///
+    /// This is the compositing counterpart of [`copy_rect_from`][]: each row segment is handed
+    /// to `kernel` as a pair of contiguous slices, so the SIMD-friendly kernels in
+    /// [`ops::blend`][] ([`source_over_slice`][], [`additive_slice`][]) composite whole rows
+    /// instead of going through [`blit_rect`][]'s per-cell accesses. Cells that do not fit in
+    /// either grid are clipped.
+    ///
+    /// [`copy_rect_from`]: GridBuf::copy_rect_from
+    /// [`ops::blend`]: crate::ops::blend
+    /// [`source_over_slice`]: crate::ops::blend::source_over_slice
+    /// [`additive_slice`]: crate::ops::blend::additive_slice
+    /// [`blit_rect`]: crate::ops::blit_rect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::{core::{Pos, Rect}, buf::GridBuf, ops::{blend, GridRead}};
+    ///
+    /// let src = GridBuf::new_filled(2, 2, 0xFF00_00FFu32);
+    /// let mut dst = GridBuf::new_filled(4, 4, 0x0000_0000u32);
+    /// dst.blit_rect_from(&src, Rect::from_ltwh(0, 0, 2, 2), Pos::new(1, 1), blend::source_over_slice);
+    ///
+    /// assert_eq!(dst.get(Pos::new(1, 1)), Some(&0xFF00_00FF));
+    /// assert_eq!(dst.get(Pos::new(0, 0)), Some(&0x0000_0000));
+    /// ```
+    pub fn blit_rect_from<B2>(
+        &mut self,
+        src: &GridBuf<u32, B2, layout::RowMajor>,
+        from: Rect,
+        to: Pos,
+        kernel: impl Fn(&mut [u32], &[u32]),
+    ) where
+        B2: AsRef<[u32]>,
+    {
+        let from = src.trim_rect(from);
+        let dst_rect = self.trim_rect(Rect::from_ltwh(to.x, to.y, from.width(), from.height()));
+        let width = from.width().min(dst_rect.width());
+        let height = from.height().min(dst_rect.height());
+        let src_origin = from.top_left();
+        let src_width = src.width;
+        let dst_width = self.width;
+        for y in 0..height {
+            let src_start = (src_origin.y + y) * src_width + src_origin.x;
+            let dst_start = (to.y + y) * dst_width + to.x;
+            kernel(
+                &mut self.buffer.as_mut()[dst_start..dst_start + width],
+                &src.buffer.as_ref()[src_start..src_start + width],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::GridBuf,
+        core::{Pos, Rect},
+        ops::{blend, layout::RowMajor},
+    };
+    use alloc::vec;
+
+    #[test]
+    fn fill_rect_solid_u8_partial_row() {
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(3, 3);
+        grid.fill_rect_solid(Rect::from_ltwh(1, 0, 2, 2), 42);
+
+        let (buffer, _, _) = grid.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0, 42, 42,
+            0, 42, 42,
+            0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn fill_rect_solid_u32_trims_out_of_bounds() {
+        let mut grid = GridBuf::<u32, _, RowMajor>::new(2, 2);
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, 5, 5), 7);
+
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![7, 7, 7, 7]);
+    }
+
+    #[test]
+    fn blit_rect_from_source_over_composites_rows() {
+        let src = GridBuf::<u32, _, RowMajor>::new_filled(2, 2, 0xFF00_00FF);
+        let mut dst = GridBuf::<u32, _, RowMajor>::new_filled(3, 3, 0xFFFF_FFFF);
+        dst.blit_rect_from(
+            &src,
+            Rect::from_ltwh(0, 0, 2, 2),
+            Pos::new(1, 1),
+            blend::source_over_slice,
+        );
+
+        let (buffer, _, _) = dst.into_inner();
+        #[rustfmt::skip]
+        assert_eq!(buffer, vec![
+            0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF,
+            0xFFFF_FFFF, 0xFF00_00FF, 0xFF00_00FF,
+            0xFFFF_FFFF, 0xFF00_00FF, 0xFF00_00FF,
+        ]);
+    }
+
+    #[test]
+    fn blit_rect_from_additive_clips() {
+        let src = GridBuf::<u32, _, RowMajor>::new_filled(2, 1, 0x0000_0001);
+        let mut dst = GridBuf::<u32, _, RowMajor>::new_filled(2, 1, 0x0000_00FF);
+        dst.blit_rect_from(
+            &src,
+            Rect::from_ltwh(0, 0, 2, 1),
+            Pos::new(1, 0),
+            blend::additive_slice,
+        );
+
+        let (buffer, _, _) = dst.into_inner();
+        assert_eq!(buffer, vec![0x0000_00FF, 0x0000_00FF]);
+    }
+}
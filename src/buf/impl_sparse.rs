@@ -0,0 +1,98 @@
+use crate::{
+    buf::{
+        GridBuf,
+        bits::{BitOps, GridBits},
+    },
+    core::Pos,
+    ops::{ExactSizeGrid as _, GridSparse, layout},
+};
+
+impl<T, B> GridSparse for GridBuf<Option<T>, B, layout::RowMajor>
+where
+    B: AsRef<[Option<T>]>,
+{
+    type Value<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    fn iter_set(&self) -> impl Iterator<Item = (Pos, Self::Value<'_>)> {
+        let width = self.width;
+        self.buffer
+            .as_ref()
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, cell)| {
+                cell.as_ref()
+                    .map(|value| (Pos::new(index % width, index / width), value))
+            })
+    }
+}
+
+impl<T, B> GridSparse for GridBits<T, B, layout::RowMajor>
+where
+    T: BitOps,
+    B: AsRef<[T]>,
+{
+    type Value<'a>
+        = bool
+    where
+        Self: 'a;
+
+    fn iter_set(&self) -> impl Iterator<Item = (Pos, Self::Value<'_>)> {
+        let width = self.width();
+        let slice: &[T] = self.as_ref();
+        slice
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| word.to_usize() != 0)
+            .flat_map(move |(word_index, word)| {
+                let bits = word.to_usize();
+                (0..T::MAX_WIDTH)
+                    .filter(move |bit| (bits >> bit) & 1 != 0)
+                    .map(move |bit| {
+                        let index = word_index * T::MAX_WIDTH + bit;
+                        (Pos::new(index % width, index / width), true)
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::{GridBuf, bits::GridBits},
+        core::Pos,
+        ops::{GridSparse as _, layout::RowMajor},
+    };
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn option_grid_yields_some_cells_only() {
+        #[rustfmt::skip]
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            None, Some(1), None,
+            None, None, Some(2),
+        ], 3);
+
+        let set: Vec<_> = grid.iter_set().collect();
+        assert_eq!(set, [(Pos::new(1, 0), &1), (Pos::new(2, 1), &2)]);
+    }
+
+    #[test]
+    fn option_grid_all_empty() {
+        let grid = GridBuf::<Option<i32>, _, RowMajor>::from_buffer(vec![None; 4], 2);
+        assert_eq!(grid.iter_set().count(), 0);
+    }
+
+    #[test]
+    fn bit_grid_skips_zero_words() {
+        let grid = GridBits::<u8, _, RowMajor>::from_buffer(vec![0b0000_0000, 0b0000_0101], 8);
+
+        let set: Vec<_> = grid.iter_set().collect();
+        assert_eq!(set, [(Pos::new(0, 1), true), (Pos::new(2, 1), true)]);
+    }
+}
@@ -0,0 +1,336 @@
+use core::marker::PhantomData;
+
+use crate::{
+    buf::GridBuf,
+    core::{Pos, Rect, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridReadMut, GridWrite, layout},
+};
+
+/// A pair of grids borrowing disjoint halves of a split grid's buffer.
+///
+/// Returned by [`GridBuf::split_at_row_mut`][] and [`GridBuf::split_at_col_mut`][].
+pub type SplitMut<'a, T, L> = (GridBuf<T, &'a mut [T], L>, GridBuf<T, &'a mut [T], L>);
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsMut<[T]>,
+{
+    /// Splits the grid into two independent mutable views above and below row `y`.
+    ///
+    /// The first grid contains rows `0..y` and the second rows `y..height`. The views borrow
+    /// disjoint regions of the buffer, so both halves can be mutated simultaneously, e.g. for
+    /// ping-pong simulation steps or handing to separate threads.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `y` is greater than the grid's height.
+    pub fn split_at_row_mut(&mut self, y: usize) -> SplitMut<'_, T, layout::RowMajor> {
+        assert!(y <= self.height, "Row index out of bounds");
+        let width = self.width;
+        let (top_height, bottom_height) = (y, self.height - y);
+        let (top, bottom) = self.buffer.as_mut().split_at_mut(y * width);
+        (
+            GridBuf {
+                buffer: top,
+                width,
+                height: top_height,
+                _layout: PhantomData,
+                _element: PhantomData,
+            },
+            GridBuf {
+                buffer: bottom,
+                width,
+                height: bottom_height,
+                _layout: PhantomData,
+                _element: PhantomData,
+            },
+        )
+    }
+}
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsMut<[T]>,
+{
+    /// Splits the grid into four non-overlapping mutable quadrant views around `at`.
+    ///
+    /// The views are returned in `(top-left, top-right, bottom-left, bottom-right)` order; the
+    /// top-left view spans `(0, 0)..(at.x, at.y)`. Each view borrows a disjoint region of the
+    /// buffer, so all four quadrants can be mutated simultaneously for divide-and-conquer
+    /// algorithms such as quadtree builds or multi-threaded blits.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `at` is outside the grid's dimensions.
+    pub fn split_quad_mut(
+        &mut self,
+        at: Pos,
+    ) -> (
+        GridViewMut<'_, T>,
+        GridViewMut<'_, T>,
+        GridViewMut<'_, T>,
+        GridViewMut<'_, T>,
+    ) {
+        assert!(
+            at.x <= self.width && at.y <= self.height,
+            "Position out of bounds"
+        );
+        let (width, height) = (self.width, self.height);
+        let base = self.buffer.as_mut().as_mut_ptr();
+        let view = |x0: usize, y0: usize, w: usize, h: usize| {
+            let ptr = if w == 0 || h == 0 {
+                base
+            } else {
+                // SAFETY: A non-empty view's origin lies within the buffer, whose length is
+                // `width * height`.
+                unsafe { base.add(y0 * width + x0) }
+            };
+            GridViewMut {
+                ptr,
+                stride: width,
+                width: w,
+                height: h,
+                _marker: PhantomData,
+            }
+        };
+        (
+            view(0, 0, at.x, at.y),
+            view(at.x, 0, width - at.x, at.y),
+            view(0, at.y, at.x, height - at.y),
+            view(at.x, at.y, width - at.x, height - at.y),
+        )
+    }
+}
+
+/// A mutable view of a rectangular region of a row-major grid.
+///
+/// Returned by [`GridBuf::split_quad_mut`][]; each view has exclusive access to its region of
+/// the underlying buffer, so sibling views can be mutated simultaneously. Positions are local
+/// to the view, starting at `(0, 0)` in its top-left corner.
+pub struct GridViewMut<'a, T> {
+    ptr: *mut T,
+    stride: usize,
+    width: usize,
+    height: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: A view has exclusive access to its region of the buffer, so moving it to another
+// thread moves that access with it, as for `&mut [T]`.
+unsafe impl<T: Send> Send for GridViewMut<'_, T> {}
+
+// SAFETY: A shared reference to a view only permits reads of its region, as for `&mut [T]`.
+unsafe impl<T: Sync> Sync for GridViewMut<'_, T> {}
+
+impl<T> GridViewMut<'_, T> {
+    fn index(&self, pos: Pos) -> Option<usize> {
+        (pos.x < self.width && pos.y < self.height).then_some(pos.y * self.stride + pos.x)
+    }
+
+    /// Returns a reference to the element at `pos`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        // SAFETY: `index` only yields offsets within the view's region of the buffer.
+        self.index(pos)
+            .map(|index| unsafe { &*self.ptr.add(index) })
+    }
+
+    /// Returns a mutable reference to the element at `pos`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        // SAFETY: `index` only yields offsets within the view's region of the buffer, and the
+        // view has exclusive access to that region.
+        self.index(pos)
+            .map(|index| unsafe { &mut *self.ptr.add(index) })
+    }
+}
+
+impl<T> GridBase for GridViewMut<'_, T> {
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        let size = Size::new(self.width, self.height);
+        (size, Some(size))
+    }
+}
+
+impl<T> ExactSizeGrid for GridViewMut<'_, T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+}
+
+impl<T> GridRead for GridViewMut<'_, T> {
+    type Element<'b>
+        = &'b T
+    where
+        Self: 'b;
+
+    type Layout = layout::RowMajor;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        GridViewMut::get(self, pos)
+    }
+}
+
+impl<T> GridWrite for GridViewMut<'_, T> {
+    type Element = T;
+    type Layout = layout::RowMajor;
+
+    fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), crate::core::GridError> {
+        match self.get_mut(pos) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(crate::core::GridError::OutOfBounds { pos }),
+        }
+    }
+}
+
+impl<T> GridReadMut for GridViewMut<'_, T> {
+    type Element = T;
+    type Layout = layout::RowMajor;
+
+    fn get_mut(&mut self, pos: Pos) -> Option<&mut Self::Element> {
+        GridViewMut::get_mut(self, pos)
+    }
+
+    fn iter_rect_mut(&mut self, bounds: Rect) -> impl Iterator<Item = &mut Self::Element> {
+        let bounds = self.trim_rect(bounds);
+        let (x0, cols) = (bounds.top_left().x, bounds.width());
+        let (stride, ptr) = (self.stride, self.ptr);
+        (bounds.top_left().y..bounds.bottom()).flat_map(move |y| {
+            // SAFETY: Each row segment lies within the view's region, and segments for
+            // distinct `y` are disjoint, so handing out mutable references to each is sound
+            // while the view is mutably borrowed.
+            let row = unsafe { core::slice::from_raw_parts_mut(ptr.add(y * stride + x0), cols) };
+            row.iter_mut()
+        })
+    }
+}
+
+impl<T, B> GridBuf<T, B, layout::ColumnMajor>
+where
+    B: AsMut<[T]>,
+{
+    /// Splits the grid into two independent mutable views left and right of column `x`.
+    ///
+    /// The first grid contains columns `0..x` and the second columns `x..width`. Columns are
+    /// contiguous in a column-major buffer, so the views borrow disjoint regions and both can
+    /// be mutated simultaneously; for row splits, see
+    /// [`split_at_row_mut`](GridBuf::split_at_row_mut) on row-major grids.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if `x` is greater than the grid's width.
+    pub fn split_at_col_mut(&mut self, x: usize) -> SplitMut<'_, T, layout::ColumnMajor> {
+        assert!(x <= self.width, "Column index out of bounds");
+        let height = self.height;
+        let (left_width, right_width) = (x, self.width - x);
+        let (left, right) = self.buffer.as_mut().split_at_mut(x * height);
+        (
+            GridBuf {
+                buffer: left,
+                width: left_width,
+                height,
+                _layout: PhantomData,
+                _element: PhantomData,
+            },
+            GridBuf {
+                buffer: right,
+                width: right_width,
+                height,
+                _layout: PhantomData,
+                _element: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::GridBuf,
+        core::Pos,
+        ops::{
+            GridRead as _, GridWrite as _,
+            layout::{ColumnMajor, RowMajor},
+        },
+    };
+    use alloc::vec;
+
+    #[test]
+    fn split_at_row_yields_independent_halves() {
+        let mut grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 2);
+        let (mut top, mut bottom) = grid.split_at_row_mut(1);
+        top.set(Pos::new(1, 0), 20).unwrap();
+        bottom.set(Pos::new(0, 1), 50).unwrap();
+        assert_eq!(top.get(Pos::new(0, 1)), None); // Out of bounds
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(&20));
+        assert_eq!(grid.get(Pos::new(0, 2)), Some(&50));
+    }
+
+    #[test]
+    fn split_quad_mutates_all_quadrants() {
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(4, 4);
+        {
+            let (mut tl, mut tr, mut bl, mut br) = grid.split_quad_mut(Pos::new(2, 2));
+            tl.set(Pos::new(0, 0), 1).unwrap();
+            tr.set(Pos::new(1, 0), 2).unwrap();
+            bl.set(Pos::new(0, 1), 3).unwrap();
+            br.set(Pos::new(1, 1), 4).unwrap();
+            assert_eq!(tl.get(Pos::new(2, 0)), None); // Out of bounds
+        }
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+        assert_eq!(grid.get(Pos::new(3, 0)), Some(&2));
+        assert_eq!(grid.get(Pos::new(0, 3)), Some(&3));
+        assert_eq!(grid.get(Pos::new(3, 3)), Some(&4));
+    }
+
+    #[test]
+    fn split_quad_at_edge_yields_empty_views() {
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(2, 2);
+        let (tl, tr, bl, br) = grid.split_quad_mut(Pos::new(2, 2));
+        assert_eq!(tl.get(Pos::new(1, 1)), Some(&0));
+        assert_eq!(tr.get(Pos::new(0, 0)), None);
+        assert_eq!(bl.get(Pos::new(0, 0)), None);
+        assert_eq!(br.get(Pos::new(0, 0)), None);
+    }
+
+    #[test]
+    fn split_quad_region_iteration() {
+        use crate::ops::GridReadMut as _;
+
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(4, 4);
+        let (_, _, _, mut br) = grid.split_quad_mut(Pos::new(1, 1));
+        for cell in br.iter_rect_mut(crate::core::Rect::from_ltwh(0, 0, 3, 3)) {
+            *cell = 7;
+        }
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&7));
+        assert_eq!(grid.get(Pos::new(3, 3)), Some(&7));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index out of bounds")]
+    fn split_at_row_past_height_panics() {
+        let mut grid = GridBuf::<u8, _, RowMajor>::new(2, 2);
+        let _ = grid.split_at_row_mut(3);
+    }
+
+    #[test]
+    fn split_at_col_yields_independent_halves() {
+        let mut grid =
+            GridBuf::<_, _, ColumnMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3);
+        let (mut left, mut right) = grid.split_at_col_mut(1);
+        left.set(Pos::new(0, 1), 20).unwrap();
+        right.set(Pos::new(0, 0), 50).unwrap();
+        assert_eq!(left.get(Pos::new(1, 0)), None); // Out of bounds
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&20));
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(&50));
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use crate::{buf::GridBuf, ops::layout};
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+
+impl<T, B> GridBuf<T, B, layout::RowMajor>
+where
+    B: AsMut<[T]>,
+{
+    /// Transposes a square grid in place, mirroring elements across the main diagonal.
+    ///
+    /// The transpose is performed with element swaps, so no second buffer is allocated.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the grid is not square; see [`transpose`](GridBuf::transpose) for
+    /// rectangular grids.
+    pub fn transpose_in_place(&mut self) {
+        assert!(self.width == self.height, "Grid must be square");
+        let n = self.width;
+        let buffer = self.buffer.as_mut();
+        for y in 0..n {
+            for x in y + 1..n {
+                buffer.swap(y * n + x, x * n + y);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GridBuf<T, alloc::vec::Vec<T>, layout::RowMajor>
+where
+    T: Clone,
+{
+    /// Returns the transpose of the grid, with the width and height swapped.
+    ///
+    /// This allocates a new buffer, so it works for rectangular grids; square grids can use
+    /// [`transpose_in_place`](GridBuf::transpose_in_place) instead. Row-major sensor data read
+    /// column-by-column transposes once up front rather than striding on every access.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let (width, height) = (self.width, self.height);
+        let buffer = self.buffer.as_slice();
+        let mut out = alloc::vec::Vec::with_capacity(buffer.len());
+        for x in 0..width {
+            for y in 0..height {
+                out.push(buffer[y * width + x].clone());
+            }
+        }
+        Self {
+            buffer: out,
+            width: height,
+            height: width,
+            _layout: PhantomData,
+            _element: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn transpose_in_place_square() {
+        #[rustfmt::skip]
+        let mut grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ], 3);
+        grid.transpose_in_place();
+        #[rustfmt::skip]
+        let expected = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            1, 4, 7,
+            2, 5, 8,
+            3, 6, 9,
+        ], 3);
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid must be square")]
+    fn transpose_in_place_rejects_rectangles() {
+        let mut grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        grid.transpose_in_place();
+    }
+
+    #[test]
+    fn transpose_rectangular_swaps_dimensions() {
+        #[rustfmt::skip]
+        let grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            1, 2, 3,
+            4, 5, 6,
+        ], 3);
+        #[rustfmt::skip]
+        let expected = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![
+            1, 4,
+            2, 5,
+            3, 6,
+        ], 2);
+        assert_eq!(grid.transpose(), expected);
+    }
+
+    #[test]
+    fn transpose_twice_is_identity() {
+        let grid = GridBuf::<_, _, layout::RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 2);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+}
@@ -0,0 +1,362 @@
+//! Terminal cell grids and an incremental ANSI renderer.
+//!
+//! A [`Cell`] pairs a character with foreground and background [`Color`]s and text [`Attrs`],
+//! and a [`ConsoleGrid`] is a row-major `GridBuf` of cells. [`ConsoleRenderer::render_ansi`]
+//! writes the ANSI escape sequences needed to bring a terminal from the previously rendered
+//! frame to the current one, so an unchanged frame writes nothing and a single changed cell
+//! writes a single cursor move.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{console::{Cell, ConsoleGrid, ConsoleRenderer}, core::Pos, ops::GridWrite as _};
+//!
+//! let mut grid = ConsoleGrid::new(4, 2);
+//! let _ = grid.set(Pos::new(1, 0), Cell::new('@'));
+//!
+//! let mut renderer = ConsoleRenderer::new();
+//! let mut out = String::new();
+//! renderer.render_ansi(&grid, &mut out).unwrap();
+//! assert!(out.contains('@'));
+//!
+//! // An unchanged frame writes nothing.
+//! out.clear();
+//! renderer.render_ansi(&grid, &mut out).unwrap();
+//! assert!(out.is_empty());
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    buf::GridBuf,
+    ops::{ExactSizeGrid as _, layout::RowMajor},
+};
+
+/// A foreground or background color for a [`Cell`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// The terminal's default color.
+    #[default]
+    Default,
+
+    /// An indexed color from the 256-color palette (SGR `38;5;n` / `48;5;n`).
+    Indexed(u8),
+
+    /// A 24-bit color (SGR `38;2;r;g;b` / `48;2;r;g;b`).
+    Rgb(u8, u8, u8),
+}
+
+/// Text attributes for a [`Cell`], combined with the `|` operator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Attrs(u8);
+
+impl Attrs {
+    /// No attributes.
+    pub const NONE: Self = Self(0);
+
+    /// Bold (SGR `1`).
+    pub const BOLD: Self = Self(1 << 0);
+
+    /// Dim (SGR `2`).
+    pub const DIM: Self = Self(1 << 1);
+
+    /// Italic (SGR `3`).
+    pub const ITALIC: Self = Self(1 << 2);
+
+    /// Underline (SGR `4`).
+    pub const UNDERLINE: Self = Self(1 << 3);
+
+    /// Reverse video (SGR `7`).
+    pub const REVERSE: Self = Self(1 << 4);
+
+    /// Returns `true` if every attribute in `other` is also set in `self`.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for Attrs {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for Attrs {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A single terminal cell: a character with colors and attributes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+    /// The character displayed in the cell.
+    pub ch: char,
+
+    /// The foreground color.
+    pub fg: Color,
+
+    /// The background color.
+    pub bg: Color,
+
+    /// The text attributes.
+    pub attrs: Attrs,
+}
+
+impl Cell {
+    /// Creates a cell displaying `ch` with default colors and no attributes.
+    #[must_use]
+    pub const fn new(ch: char) -> Self {
+        Self {
+            ch,
+            fg: Color::Default,
+            bg: Color::Default,
+            attrs: Attrs::NONE,
+        }
+    }
+
+    /// Returns the cell with the given foreground color.
+    #[must_use]
+    pub const fn with_fg(mut self, fg: Color) -> Self {
+        self.fg = fg;
+        self
+    }
+
+    /// Returns the cell with the given background color.
+    #[must_use]
+    pub const fn with_bg(mut self, bg: Color) -> Self {
+        self.bg = bg;
+        self
+    }
+
+    /// Returns the cell with the given text attributes.
+    #[must_use]
+    pub const fn with_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs = attrs;
+        self
+    }
+}
+
+impl Default for Cell {
+    /// A blank cell: a space with default colors and no attributes.
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
+
+/// A row-major, `Vec`-backed grid of terminal cells.
+pub type ConsoleGrid = GridBuf<Cell, Vec<Cell>, RowMajor>;
+
+/// Renders console grids as ANSI escape sequences, diffing against the previous frame.
+///
+/// The renderer remembers the last frame it wrote. Each call to [`render_ansi`][] emits cursor
+/// moves, style changes, and characters only for cells that differ from that frame; when the
+/// grid's dimensions change, the screen is cleared and redrawn in full.
+///
+/// [`render_ansi`]: ConsoleRenderer::render_ansi
+pub struct ConsoleRenderer {
+    prev: ConsoleGrid,
+}
+
+impl ConsoleRenderer {
+    /// Creates a renderer with no previous frame; the first render draws every non-blank cell.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            prev: ConsoleGrid::new(0, 0),
+        }
+    }
+
+    /// Writes the escape sequences that update the terminal from the previous frame to `grid`.
+    ///
+    /// Rows and columns are 1-based in the emitted cursor moves, with the grid's origin at the
+    /// terminal's top-left. Consecutive changed cells with the same style are written without
+    /// intermediate escapes, and a trailing SGR reset is emitted whenever the last written cell
+    /// left a non-default style active.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the underlying writer does; the remembered frame is left untouched,
+    /// so a later call re-emits the same diff.
+    pub fn render_ansi<B>(
+        &mut self,
+        grid: &GridBuf<Cell, B, RowMajor>,
+        out: &mut impl fmt::Write,
+    ) -> fmt::Result
+    where
+        B: AsRef<[Cell]>,
+    {
+        let (width, height) = (grid.width(), grid.height());
+        if width != self.prev.width() || height != self.prev.height() {
+            // A cleared terminal shows blank cells, which is exactly what a fresh previous
+            // frame records, so the diff below redraws every non-blank cell.
+            out.write_str("\x1b[2J")?;
+            self.prev = ConsoleGrid::new(width, height);
+        }
+        let mut cursor = None;
+        let mut style = None;
+        for (y, (row, prev_row)) in grid.rows().zip(self.prev.rows()).enumerate() {
+            for (x, (cell, prev)) in row.iter().zip(prev_row).enumerate() {
+                if cell == prev {
+                    continue;
+                }
+                if cursor != Some((x, y)) {
+                    write!(out, "\x1b[{};{}H", y + 1, x + 1)?;
+                }
+                let next_style = (cell.fg, cell.bg, cell.attrs);
+                if style != Some(next_style) {
+                    write_sgr(out, cell)?;
+                    style = Some(next_style);
+                }
+                out.write_char(cell.ch)?;
+                cursor = Some((x + 1, y));
+            }
+        }
+        if style.is_some_and(|s| s != (Color::Default, Color::Default, Attrs::NONE)) {
+            out.write_str("\x1b[0m")?;
+        }
+        self.prev = ConsoleGrid::from_buffer(
+            grid.rows().flat_map(|row| row.iter().copied()).collect(),
+            width,
+        );
+        Ok(())
+    }
+}
+
+impl Default for ConsoleRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes the SGR sequence selecting the cell's attributes and colors, starting from a reset.
+fn write_sgr(out: &mut impl fmt::Write, cell: &Cell) -> fmt::Result {
+    out.write_str("\x1b[0")?;
+    if cell.attrs.contains(Attrs::BOLD) {
+        out.write_str(";1")?;
+    }
+    if cell.attrs.contains(Attrs::DIM) {
+        out.write_str(";2")?;
+    }
+    if cell.attrs.contains(Attrs::ITALIC) {
+        out.write_str(";3")?;
+    }
+    if cell.attrs.contains(Attrs::UNDERLINE) {
+        out.write_str(";4")?;
+    }
+    if cell.attrs.contains(Attrs::REVERSE) {
+        out.write_str(";7")?;
+    }
+    match cell.fg {
+        Color::Default => {}
+        Color::Indexed(n) => write!(out, ";38;5;{n}")?,
+        Color::Rgb(r, g, b) => write!(out, ";38;2;{r};{g};{b}")?,
+    }
+    match cell.bg {
+        Color::Default => {}
+        Color::Indexed(n) => write!(out, ";48;5;{n}")?,
+        Color::Rgb(r, g, b) => write!(out, ";48;2;{r};{g};{b}")?,
+    }
+    out.write_str("m")
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use crate::{core::Pos, ops::GridWrite as _};
+    use alloc::string::String;
+
+    #[test]
+    fn first_frame_clears_and_draws_non_blank_cells() {
+        let mut grid = ConsoleGrid::new(3, 2);
+        let _ = grid.set(Pos::new(2, 1), Cell::new('#'));
+
+        let mut renderer = ConsoleRenderer::new();
+        let mut out = String::new();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        assert_eq!(out, "\x1b[2J\x1b[2;3H\x1b[0m#");
+    }
+
+    #[test]
+    fn unchanged_frame_writes_nothing() {
+        let mut grid = ConsoleGrid::new(3, 2);
+        let _ = grid.set(Pos::new(0, 0), Cell::new('a'));
+
+        let mut renderer = ConsoleRenderer::new();
+        let mut out = String::new();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        out.clear();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn changed_cell_emits_a_single_cursor_move() {
+        let mut grid = ConsoleGrid::new(3, 2);
+        let mut renderer = ConsoleRenderer::new();
+        let mut out = String::new();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        let _ = grid.set(Pos::new(1, 0), Cell::new('@'));
+        out.clear();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+        assert_eq!(out, "\x1b[1;2H\x1b[0m@");
+    }
+
+    #[test]
+    fn adjacent_cells_share_cursor_and_style() {
+        let mut grid = ConsoleGrid::new(3, 1);
+        let _ = grid.set(Pos::new(0, 0), Cell::new('a'));
+        let _ = grid.set(Pos::new(1, 0), Cell::new('b'));
+
+        let mut renderer = ConsoleRenderer::new();
+        let mut out = String::new();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        assert_eq!(out, "\x1b[2J\x1b[1;1H\x1b[0mab");
+    }
+
+    #[test]
+    fn styled_cell_emits_sgr_codes_and_a_trailing_reset() {
+        let mut grid = ConsoleGrid::new(1, 1);
+        let cell = Cell::new('x')
+            .with_fg(Color::Indexed(1))
+            .with_bg(Color::Rgb(10, 20, 30))
+            .with_attrs(Attrs::BOLD | Attrs::UNDERLINE);
+        let _ = grid.set(Pos::new(0, 0), cell);
+
+        let mut renderer = ConsoleRenderer::new();
+        let mut out = String::new();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        assert_eq!(
+            out,
+            "\x1b[2J\x1b[1;1H\x1b[0;1;4;38;5;1;48;2;10;20;30mx\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn resized_grid_clears_and_redraws() {
+        let mut renderer = ConsoleRenderer::new();
+        let mut out = String::new();
+        let mut grid = ConsoleGrid::new(2, 1);
+        let _ = grid.set(Pos::new(0, 0), Cell::new('a'));
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        let mut grid = ConsoleGrid::new(3, 1);
+        let _ = grid.set(Pos::new(0, 0), Cell::new('a'));
+        out.clear();
+        renderer.render_ansi(&grid, &mut out).unwrap();
+
+        assert_eq!(out, "\x1b[2J\x1b[1;1H\x1b[0ma");
+    }
+}
@@ -14,11 +14,49 @@ pub type Pos = ixy::Pos<usize>;
 /// This is a wrapper around [`ixy::Rect`] that uses `usize` for coordinates.
 pub type Rect = ixy::Rect<usize>;
 
+/// A 2-dimensional position with signed coordinates.
+///
+/// This is a wrapper around [`ixy::Pos`] that uses `i32`, for world coordinates that can go
+/// negative; see [`GridRead::get_signed`][] and [`GridWrite::set_signed`][] for addressing grids
+/// with it.
+///
+/// [`GridRead::get_signed`]: crate::ops::GridRead::get_signed
+/// [`GridWrite::set_signed`]: crate::ops::GridWrite::set_signed
+pub type SignedPos = ixy::Pos<i32>;
+
 /// A 2-dimensional size type.
 ///
 /// This is a wrapper around [`ixy::Size`] that uses `usize` for dimensions.
 pub type Size = ixy::Size;
 
+/// Types that can be used as a grid position.
+///
+/// This is implemented for [`Pos`] itself, `(x, y)` tuples, and `[x, y]` arrays, so call sites
+/// can write `grid.get_at((3, 4))` or index with `grid[(3, 4)]` instead of spelling out
+/// [`Pos::new`] everywhere.
+pub trait GridIndex {
+    /// Converts the index into a position.
+    fn into_pos(self) -> Pos;
+}
+
+impl GridIndex for Pos {
+    fn into_pos(self) -> Pos {
+        self
+    }
+}
+
+impl GridIndex for (usize, usize) {
+    fn into_pos(self) -> Pos {
+        Pos::new(self.0, self.1)
+    }
+}
+
+impl GridIndex for [usize; 2] {
+    fn into_pos(self) -> Pos {
+        Pos::new(self[0], self[1])
+    }
+}
+
 /// An error type for operations on or creating a `Grid`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,12 +67,51 @@ pub enum GridError {
         /// The position that was out of bounds.
         pos: Pos,
     },
+
+    /// A buffer's length does not divide evenly into rows of the requested width.
+    InvalidBufferLength {
+        /// The buffer length, in elements (or bits, for bit-packed grids).
+        len: usize,
+
+        /// The requested width in columns.
+        width: usize,
+    },
+
+    /// A grid's requested dimensions overflow `usize` when multiplied.
+    TooLarge {
+        /// The requested width in columns.
+        width: usize,
+
+        /// The requested height in rows.
+        height: usize,
+    },
+
+    /// An operation received a grid whose dimensions differ from what was required.
+    SizeMismatch {
+        /// The dimensions the operation required.
+        expected: Size,
+
+        /// The dimensions it received.
+        actual: Size,
+    },
 }
 
 impl Display for GridError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             GridError::OutOfBounds { pos } => write!(f, "Position out of bounds: {pos}"),
+            GridError::InvalidBufferLength { len, width } => write!(
+                f,
+                "Buffer length {len} is not a multiple of the width {width}"
+            ),
+            GridError::TooLarge { width, height } => {
+                write!(f, "Grid dimensions {width}x{height} are too large")
+            }
+            GridError::SizeMismatch { expected, actual } => write!(
+                f,
+                "Size mismatch: expected {}x{}, got {}x{}",
+                expected.width, expected.height, actual.width, actual.height
+            ),
         }
     }
 }
@@ -0,0 +1,110 @@
+//! Adapters for logging grid types with the [`defmt`] framework.
+//!
+//! Embedded targets logging over RTT avoid `core::fmt`, so this module provides [`defmt::Format`]
+//! implementations for [`GridError`] and lightweight wrappers for the coordinate types. [`Pos`]
+//! and [`Rect`] are aliases of `ixy` types, so they cannot implement the trait directly; wrap
+//! them in [`FmtPos`] and [`FmtRect`] at the log site instead.
+//!
+//! [`defmt`]: https://docs.rs/defmt
+//!
+//! ## Examples
+//!
+//! ```rust,ignore
+//! use grixy::{core::Pos, defmt::FmtPos};
+//!
+//! defmt::info!("cursor at {}", FmtPos(Pos::new(3, 4)));
+//! ```
+
+use defmt::Format;
+
+use crate::{
+    core::{GridError, Pos, Rect},
+    ops::{ExactSizeGrid, GridRead},
+};
+
+impl Format for GridError {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        match self {
+            GridError::OutOfBounds { pos } => {
+                defmt::write!(fmt, "Position out of bounds: {}", FmtPos(*pos));
+            }
+            GridError::InvalidBufferLength { len, width } => {
+                defmt::write!(
+                    fmt,
+                    "Buffer length {} is not a multiple of the width {}",
+                    len,
+                    width
+                );
+            }
+            GridError::TooLarge { width, height } => {
+                defmt::write!(fmt, "Grid dimensions {}x{} are too large", width, height);
+            }
+            GridError::SizeMismatch { expected, actual } => {
+                defmt::write!(
+                    fmt,
+                    "Size mismatch: expected {}x{}, got {}x{}",
+                    expected.width,
+                    expected.height,
+                    actual.width,
+                    actual.height
+                );
+            }
+        }
+    }
+}
+
+/// Formats a [`Pos`] as `(x, y)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FmtPos(pub Pos);
+
+impl Format for FmtPos {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "({}, {})", self.0.x, self.0.y);
+    }
+}
+
+/// Formats a [`Rect`] as `(left, top)..(right, bottom)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FmtRect(pub Rect);
+
+impl Format for FmtRect {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        let origin = self.0.top_left();
+        defmt::write!(
+            fmt,
+            "({}, {})..({}, {})",
+            origin.x,
+            origin.y,
+            self.0.right(),
+            self.0.bottom()
+        );
+    }
+}
+
+/// The maximum number of columns and rows emitted by [`GridPreview`].
+pub const MAX_PREVIEW: usize = 8;
+
+/// Formats a size-limited preview of a grid, one bracketed row per line.
+///
+/// At most [`MAX_PREVIEW`] columns and rows are emitted, keeping log frames small; the full
+/// dimensions are always included so truncation is visible.
+pub struct GridPreview<'a, G>(pub &'a G);
+
+impl<G> Format for GridPreview<'_, G>
+where
+    G: GridRead + ExactSizeGrid,
+    for<'x> G::Element<'x>: Format,
+{
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "Grid {}x{}", self.0.width(), self.0.height());
+        for y in 0..self.0.height().min(MAX_PREVIEW) {
+            defmt::write!(fmt, " [");
+            for x in 0..self.0.width().min(MAX_PREVIEW) {
+                if let Some(elem) = self.0.get(Pos::new(x, y)) {
+                    defmt::write!(fmt, " {}", elem);
+                }
+            }
+            defmt::write!(fmt, " ]");
+        }
+    }
+}
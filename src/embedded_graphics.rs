@@ -0,0 +1,217 @@
+//! Adapters for using grids with the [`embedded-graphics`] crate.
+//!
+//! [`GridTarget`] lets any [`GridWrite`] of a [`PixelColor`] element act as a [`DrawTarget`]
+//! (a framebuffer for embedded displays), and [`GridImage`] lets any [`GridRead`] source be
+//! drawn onto a target as an [`ImageDrawable`]. Out-of-bounds draws are clipped, matching both
+//! the `DrawTarget` contract and this crate's write semantics.
+//!
+//! [`embedded-graphics`]: https://docs.rs/embedded-graphics
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+//! use grixy::{buf::GridBuf, core::Pos, embedded_graphics::GridTarget, ops::GridRead as _};
+//!
+//! let grid = GridBuf::new_filled(8, 8, BinaryColor::Off);
+//! let mut target = GridTarget::new(grid);
+//! Pixel(Point::new(2, 3), BinaryColor::On).draw(&mut target).unwrap();
+//!
+//! let grid = target.into_inner();
+//! assert_eq!(grid.get(Pos::new(2, 3)), Some(&BinaryColor::On));
+//! ```
+
+use core::{convert::Infallible, marker::PhantomData};
+
+use embedded_graphics::{
+    Pixel,
+    prelude::{Dimensions, DrawTarget, ImageDrawable, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+use crate::{
+    core::{Pos, Rect},
+    ops::{ExactSizeGrid, GridRead, GridWrite},
+};
+
+/// Wraps a grid so it can be drawn to as an embedded-graphics [`DrawTarget`].
+///
+/// Pixels outside the grid are clipped. Drawing is infallible.
+pub struct GridTarget<G> {
+    grid: G,
+}
+
+impl<G> GridTarget<G> {
+    /// Creates a new draw target over a grid.
+    pub const fn new(grid: G) -> Self {
+        Self { grid }
+    }
+
+    /// Returns a reference to the wrapped grid.
+    pub const fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    /// Consumes the target, returning the wrapped grid.
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> OriginDimensions for GridTarget<G>
+where
+    G: ExactSizeGrid,
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn size(&self) -> Size {
+        Size::new(self.grid.width() as u32, self.grid.height() as u32)
+    }
+}
+
+impl<G> DrawTarget for GridTarget<G>
+where
+    G: GridWrite + ExactSizeGrid,
+    G::Element: PixelColor,
+{
+    type Color = G::Element;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let (Ok(x), Ok(y)) = (usize::try_from(point.x), usize::try_from(point.y)) else {
+                continue;
+            };
+            let _ = self.grid.set(Pos::new(x, y), color);
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a readable grid so it can be drawn onto a target as an embedded-graphics
+/// [`ImageDrawable`].
+///
+/// The grid's elements are converted into the color type `C`; for grids of references, use
+/// [`copied`](crate::transform::GridConvertExt::copied) first.
+pub struct GridImage<G, C> {
+    grid: G,
+    _color: PhantomData<C>,
+}
+
+impl<G, C> GridImage<G, C> {
+    /// Creates a new image over a grid.
+    pub const fn new(grid: G) -> Self {
+        Self {
+            grid,
+            _color: PhantomData,
+        }
+    }
+
+    /// Consumes the image, returning the wrapped grid.
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G, C> OriginDimensions for GridImage<G, C>
+where
+    G: ExactSizeGrid,
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn size(&self) -> Size {
+        Size::new(self.grid.width() as u32, self.grid.height() as u32)
+    }
+}
+
+impl<G, C> ImageDrawable for GridImage<G, C>
+where
+    G: GridRead + ExactSizeGrid,
+    C: PixelColor,
+    for<'a> G::Element<'a>: Into<C>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_sub_image(target, &self.bounding_box())
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let (Ok(left), Ok(top)) = (
+            usize::try_from(area.top_left.x),
+            usize::try_from(area.top_left.y),
+        ) else {
+            return Ok(());
+        };
+        let bounds = Rect::from_ltwh(
+            left,
+            top,
+            area.size.width as usize,
+            area.size.height as usize,
+        );
+        let origin = bounds.top_left();
+        let rows = (origin.y..bounds.bottom()).map(move |y| {
+            self.grid
+                .iter_rect(Rect::from_ltwh(origin.x, y, bounds.width(), 1))
+        });
+        target.fill_contiguous(
+            &Rectangle::new(Point::zero(), area.size),
+            rows.flatten().map(Into::into),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "alloc", feature = "buffer"))]
+mod tests {
+    use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+
+    use crate::{buf::GridBuf, core::Pos, transform::GridConvertExt as _};
+
+    use super::*;
+
+    #[test]
+    fn draw_iter_writes_pixels_and_clips() {
+        let grid = GridBuf::new_filled(4, 4, BinaryColor::Off);
+        let mut target = GridTarget::new(grid);
+
+        target
+            .draw_iter([
+                Pixel(Point::new(1, 2), BinaryColor::On),
+                Pixel(Point::new(-1, 0), BinaryColor::On),
+                Pixel(Point::new(9, 9), BinaryColor::On),
+            ])
+            .unwrap();
+
+        let grid = target.into_inner();
+        assert_eq!(grid.get(Pos::new(1, 2)), Some(&BinaryColor::On));
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&BinaryColor::Off));
+    }
+
+    #[test]
+    fn target_reports_grid_dimensions() {
+        let target = GridTarget::new(GridBuf::new_filled(5, 3, BinaryColor::Off));
+        assert_eq!(target.size(), Size::new(5, 3));
+        assert_eq!(target.grid().width(), 5);
+    }
+
+    #[test]
+    fn image_draws_onto_target() {
+        let mut source = GridBuf::new_filled(2, 2, BinaryColor::Off);
+        source[Pos::new(1, 0)] = BinaryColor::On;
+        let image = GridImage::<_, BinaryColor>::new(source.copied());
+
+        let mut target = GridTarget::new(GridBuf::new_filled(4, 4, BinaryColor::Off));
+        image.draw(&mut target).unwrap();
+
+        let grid = target.into_inner();
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(&BinaryColor::On));
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&BinaryColor::Off));
+    }
+}
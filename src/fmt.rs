@@ -0,0 +1,13 @@
+//! Self-contained raster formats for loading and saving grids.
+//!
+//! These formats are intentionally tiny and dependency-free so that examples and tests can ship
+//! image data without pulling in an image crate. Everything operates on byte slices and
+//! `Vec<u8>`; no I/O or `std` is required.
+
+#[cfg(not(all(feature = "alloc", feature = "buffer")))]
+compile_error!("The `alloc` and `buffer` features must be enabled to use this module.");
+
+pub mod bmp;
+pub mod gxy;
+pub mod netpbm;
+pub mod tiled;
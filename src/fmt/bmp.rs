@@ -0,0 +1,210 @@
+//! BMP (Windows bitmap) image loading.
+//!
+//! Reads uncompressed (`BI_RGB`) 24-bit and 32-bit BMP pixel data into a `GridBuf<[u8; 3]>` of
+//! `[r, g, b]` elements. BMP stores rows bottom-up (or top-down when the header's height is
+//! negative) and pads each row to a 4-byte boundary; the decoder resolves both while copying
+//! pixels, so the resulting grid always reads top-down in row-major order.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{core::Pos, fmt::bmp, ops::GridRead as _};
+//!
+//! // A 1x1 24-bit BMP with a single red pixel.
+//! let mut bytes = vec![
+//!     0x42, 0x4D, 0x3A, 0, 0, 0, 0, 0, 0, 0, 0x36, 0, 0, 0, // File header.
+//!     40, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 24, 0, // DIB header...
+//! ];
+//! bytes.resize(0x36, 0);
+//! bytes.extend_from_slice(&[0, 0, 255, 0]); // One BGR pixel, padded to 4 bytes.
+//!
+//! let image = bmp::read_bmp(&bytes).unwrap();
+//! assert_eq!(image.get(Pos::new(0, 0)), Some(&[255, 0, 0]));
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{error::Error, fmt::Display};
+
+use crate::{buf::GridBuf, ops::layout::RowMajor};
+
+/// An error type for decoding BMP images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BmpError {
+    /// The magic number or a header field was malformed, or a dimension was zero.
+    InvalidHeader,
+
+    /// The image uses compression or a bit depth other than uncompressed 24/32-bit.
+    Unsupported,
+
+    /// The pixel data ended before the declared dimensions were read.
+    TruncatedData,
+}
+
+impl Display for BmpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BmpError::InvalidHeader => write!(f, "Invalid BMP header"),
+            BmpError::Unsupported => write!(f, "Unsupported BMP variant"),
+            BmpError::TruncatedData => write!(f, "Truncated BMP pixel data"),
+        }
+    }
+}
+
+impl Error for BmpError {}
+
+fn read_u16(bytes: &[u8], at: usize) -> Result<u16, BmpError> {
+    let raw = bytes
+        .get(at..at + 2)
+        .ok_or(BmpError::InvalidHeader)?
+        .try_into()
+        .map_err(|_| BmpError::InvalidHeader)?;
+    Ok(u16::from_le_bytes(raw))
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> Result<u32, BmpError> {
+    let raw = bytes
+        .get(at..at + 4)
+        .ok_or(BmpError::InvalidHeader)?
+        .try_into()
+        .map_err(|_| BmpError::InvalidHeader)?;
+    Ok(u32::from_le_bytes(raw))
+}
+
+/// Reads an uncompressed 24-bit or 32-bit BMP into a `GridBuf<[u8; 3]>` of `[r, g, b]` elements.
+///
+/// Both bottom-up (positive height) and top-down (negative height) pixel data are supported; the
+/// returned grid always reads top-down. The alpha channel of 32-bit images is discarded.
+///
+/// ## Errors
+///
+/// Returns an error if the header is malformed, the image is compressed or not 24/32-bit, or the
+/// pixel data is truncated.
+#[allow(clippy::type_complexity)]
+pub fn read_bmp(bytes: &[u8]) -> Result<GridBuf<[u8; 3], Vec<[u8; 3]>, RowMajor>, BmpError> {
+    if bytes.get(..2) != Some(b"BM") {
+        return Err(BmpError::InvalidHeader);
+    }
+    let data_offset = read_u32(bytes, 0x0A)? as usize;
+    let header_size = read_u32(bytes, 0x0E)?;
+    if header_size < 40 {
+        return Err(BmpError::Unsupported);
+    }
+
+    let width = read_u32(bytes, 0x12)? as usize;
+    #[allow(clippy::cast_possible_wrap)]
+    let raw_height = read_u32(bytes, 0x16)? as i32;
+    let bottom_up = raw_height >= 0;
+    let height = usize::try_from(raw_height.unsigned_abs()).map_err(|_| BmpError::InvalidHeader)?;
+    if width == 0 || height == 0 {
+        return Err(BmpError::InvalidHeader);
+    }
+
+    let bpp = read_u16(bytes, 0x1C)?;
+    let compression = read_u32(bytes, 0x1E)?;
+    if compression != 0 || !matches!(bpp, 24 | 32) {
+        return Err(BmpError::Unsupported);
+    }
+    let bytes_per_pixel = usize::from(bpp / 8);
+    let stride = (width * bytes_per_pixel).div_ceil(4) * 4;
+
+    let data = bytes.get(data_offset..).ok_or(BmpError::TruncatedData)?;
+    if data.len() < stride * height {
+        return Err(BmpError::TruncatedData);
+    }
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let source_row = if bottom_up { height - 1 - y } else { y };
+        let row = &data[source_row * stride..];
+        for x in 0..width {
+            let bgr = &row[x * bytes_per_pixel..];
+            pixels.push([bgr[2], bgr[1], bgr[0]]);
+        }
+    }
+    Ok(GridBuf::from_buffer(pixels, width))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::{core::Pos, ops::GridRead as _};
+
+    use super::*;
+
+    /// Builds a minimal BMP from `rows` of `[r, g, b]` pixels, as stored (not flipped).
+    fn bmp_bytes(width: u32, height: i32, bpp: u16, rows: &[Vec<[u8; 3]>]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 0x36];
+        bytes[..2].copy_from_slice(b"BM");
+        bytes[0x0A..0x0E].copy_from_slice(&0x36u32.to_le_bytes());
+        bytes[0x0E..0x12].copy_from_slice(&40u32.to_le_bytes());
+        bytes[0x12..0x16].copy_from_slice(&width.to_le_bytes());
+        bytes[0x16..0x1A].copy_from_slice(&height.to_le_bytes());
+        bytes[0x1A..0x1C].copy_from_slice(&1u16.to_le_bytes());
+        bytes[0x1C..0x1E].copy_from_slice(&bpp.to_le_bytes());
+        for row in rows {
+            let start = bytes.len();
+            for &[r, g, b] in row {
+                bytes.push(b);
+                bytes.push(g);
+                bytes.push(r);
+                if bpp == 32 {
+                    bytes.push(0xFF);
+                }
+            }
+            while !(bytes.len() - start).is_multiple_of(4) {
+                bytes.push(0);
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn bottom_up_rows_are_flipped() {
+        let red = [255, 0, 0];
+        let green = [0, 255, 0];
+        // Stored bottom-up: the last stored row is the top of the image.
+        let bytes = bmp_bytes(2, 2, 24, &[vec![red, red], vec![green, green]]);
+
+        let image = read_bmp(&bytes).unwrap();
+        assert_eq!(image.get(Pos::new(0, 0)), Some(&green));
+        assert_eq!(image.get(Pos::new(1, 1)), Some(&red));
+    }
+
+    #[test]
+    fn negative_height_reads_top_down() {
+        let red = [255, 0, 0];
+        let green = [0, 255, 0];
+        let bytes = bmp_bytes(2, -2, 24, &[vec![red, red], vec![green, green]]);
+
+        let image = read_bmp(&bytes).unwrap();
+        assert_eq!(image.get(Pos::new(0, 0)), Some(&red));
+        assert_eq!(image.get(Pos::new(1, 1)), Some(&green));
+    }
+
+    #[test]
+    fn thirty_two_bit_discards_alpha() {
+        let blue = [0, 0, 255];
+        let bytes = bmp_bytes(1, 1, 32, &[vec![blue]]);
+        let image = read_bmp(&bytes).unwrap();
+        assert_eq!(image.get(Pos::new(0, 0)), Some(&blue));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(read_bmp(b"PNG"), Err(BmpError::InvalidHeader));
+
+        let mut compressed = bmp_bytes(1, 1, 24, &[vec![[0, 0, 0]]]);
+        compressed[0x1E] = 1; // BI_RLE8
+        assert_eq!(read_bmp(&compressed), Err(BmpError::Unsupported));
+
+        let truncated = bmp_bytes(4, 4, 24, &[vec![[0, 0, 0]; 4]; 1]);
+        assert_eq!(read_bmp(&truncated), Err(BmpError::TruncatedData));
+    }
+}
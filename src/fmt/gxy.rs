@@ -0,0 +1,369 @@
+//! A small framed binary format for grid chunks.
+//!
+//! A `gxy` frame is a 16-byte header followed by the element payload:
+//!
+//! | Field        | Size | Encoding                                  |
+//! |--------------|------|-------------------------------------------|
+//! | magic        | 4    | `"GXY\0"`                                 |
+//! | version      | 1    | currently [`VERSION`]                     |
+//! | layout id    | 1    | [`GxyLayout::ID`]                         |
+//! | element size | 2    | little-endian, bytes per element          |
+//! | width        | 4    | little-endian                             |
+//! | height       | 4    | little-endian                             |
+//! | payload      | —    | elements in layout order, little-endian   |
+//!
+//! The format is self-describing and stable across platforms: elements are always stored
+//! little-endian, and decoding verifies the layout and element size against the requested grid
+//! type. Unsigned integer elements up to `u64` are supported.
+//!
+//! With the `embedded-io` feature, [`write_into`] and [`read_from`] stream frames over any
+//! [`embedded_io`] writer or reader (which `std` types can wrap).
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{fmt::gxy, ops::layout::RowMajor, prelude::*};
+//!
+//! let grid = GridBuf::<u16, _, RowMajor>::new_filled(4, 4, 7);
+//! let bytes = gxy::to_bytes(&grid);
+//! let decoded: GridBuf<u16, Vec<u16>, RowMajor> = gxy::from_bytes(&bytes).unwrap();
+//! assert_eq!(decoded, grid);
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{error::Error, fmt::Display};
+
+use crate::{
+    buf::GridBuf,
+    internal::Sealed,
+    ops::{
+        ExactSizeGrid as _,
+        layout::{ColumnMajor, Linear, RowMajor},
+    },
+};
+
+/// The magic bytes opening every frame.
+pub const MAGIC: [u8; 4] = *b"GXY\0";
+
+/// The format version written by this crate.
+pub const VERSION: u8 = 1;
+
+/// An element type that can be stored in a `gxy` frame.
+///
+/// Implemented for the unsigned integers up to `u64`; elements are encoded little-endian.
+pub trait GxyElement: Sealed + Copy + Default {
+    /// The encoded size of the element, in bytes.
+    const SIZE: usize;
+
+    /// Appends the element's little-endian encoding to `out`.
+    fn encode(self, out: &mut Vec<u8>);
+
+    /// Decodes an element from its little-endian encoding (exactly `SIZE` bytes).
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_gxy_element {
+    ($($ty:ty),*) => {$(
+        impl GxyElement for $ty {
+            const SIZE: usize = size_of::<$ty>();
+
+            fn encode(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode(bytes: &[u8]) -> Self {
+                let mut raw = [0; size_of::<$ty>()];
+                raw.copy_from_slice(bytes);
+                Self::from_le_bytes(raw)
+            }
+        }
+    )*};
+}
+
+impl_gxy_element!(u8, u16, u32, u64);
+
+/// A linear layout with a stable identifier in the frame header.
+pub trait GxyLayout: Linear {
+    /// The layout identifier stored in the header.
+    const ID: u8;
+}
+
+impl GxyLayout for RowMajor {
+    const ID: u8 = 0;
+}
+
+impl GxyLayout for ColumnMajor {
+    const ID: u8 = 1;
+}
+
+/// An error type for decoding `gxy` frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GxyError {
+    /// The frame does not start with [`MAGIC`].
+    InvalidMagic,
+
+    /// The frame was written by an unknown (newer) format version.
+    UnsupportedVersion,
+
+    /// The frame's layout id does not match the requested grid's layout.
+    LayoutMismatch,
+
+    /// The frame's element size does not match the requested element type.
+    ElementMismatch,
+
+    /// The frame ended before the declared payload was read.
+    TruncatedData,
+}
+
+impl Display for GxyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GxyError::InvalidMagic => write!(f, "Invalid gxy magic bytes"),
+            GxyError::UnsupportedVersion => write!(f, "Unsupported gxy format version"),
+            GxyError::LayoutMismatch => write!(f, "Frame layout does not match the grid layout"),
+            GxyError::ElementMismatch => write!(f, "Frame element size does not match"),
+            GxyError::TruncatedData => write!(f, "Truncated gxy payload"),
+        }
+    }
+}
+
+impl Error for GxyError {}
+
+const HEADER_LEN: usize = 16;
+
+/// Encodes a grid as a single `gxy` frame.
+///
+/// ## Panics
+///
+/// This panics if a grid dimension does not fit in `u32`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn to_bytes<T, B, L>(grid: &GridBuf<T, B, L>) -> Vec<u8>
+where
+    T: GxyElement,
+    B: AsRef<[T]>,
+    L: GxyLayout,
+{
+    let width = u32::try_from(grid.width()).expect("Grid width must fit in u32");
+    let height = u32::try_from(grid.height()).expect("Grid height must fit in u32");
+
+    let elements = grid.as_ref();
+    let mut out = Vec::with_capacity(HEADER_LEN + elements.len() * T::SIZE);
+    out.extend_from_slice(&MAGIC);
+    out.push(VERSION);
+    out.push(L::ID);
+    out.extend_from_slice(&(T::SIZE as u16).to_le_bytes());
+    out.extend_from_slice(&width.to_le_bytes());
+    out.extend_from_slice(&height.to_le_bytes());
+    for &elem in elements {
+        elem.encode(&mut out);
+    }
+    out
+}
+
+/// Parses a frame header, returning `(layout_id, element_size, width, height)`.
+fn parse_header(header: &[u8]) -> Result<(u8, usize, usize, usize), GxyError> {
+    if header.len() < HEADER_LEN {
+        return Err(GxyError::TruncatedData);
+    }
+    if header[..4] != MAGIC {
+        return Err(GxyError::InvalidMagic);
+    }
+    if header[4] != VERSION {
+        return Err(GxyError::UnsupportedVersion);
+    }
+    let element_size = usize::from(u16::from_le_bytes([header[6], header[7]]));
+    let width = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+    let height = u32::from_le_bytes([header[12], header[13], header[14], header[15]]) as usize;
+    Ok((header[5], element_size, width, height))
+}
+
+/// Decodes a payload of `count` elements.
+fn decode_payload<T: GxyElement>(payload: &[u8], count: usize) -> Result<Vec<T>, GxyError> {
+    if payload.len() < count * T::SIZE {
+        return Err(GxyError::TruncatedData);
+    }
+    Ok(payload[..count * T::SIZE]
+        .chunks_exact(T::SIZE)
+        .map(T::decode)
+        .collect())
+}
+
+/// Decodes a single `gxy` frame into a grid.
+///
+/// Trailing bytes after the payload are ignored, so frames can be concatenated and split by the
+/// caller.
+///
+/// ## Errors
+///
+/// Returns an error if the header is malformed, the layout or element size does not match the
+/// requested grid type, or the payload is truncated.
+pub fn from_bytes<T, L>(bytes: &[u8]) -> Result<GridBuf<T, Vec<T>, L>, GxyError>
+where
+    T: GxyElement,
+    L: GxyLayout,
+{
+    let (layout_id, element_size, width, height) = parse_header(bytes)?;
+    if layout_id != L::ID {
+        return Err(GxyError::LayoutMismatch);
+    }
+    if element_size != T::SIZE {
+        return Err(GxyError::ElementMismatch);
+    }
+    let data = decode_payload(&bytes[HEADER_LEN..], width * height)?;
+    if width == 0 || height == 0 {
+        return Ok(GridBuf::new_filled_with_layout(0, 0, T::default()));
+    }
+    Ok(GridBuf::from_buffer(data, width))
+}
+
+/// Writes a grid as a single `gxy` frame into an [`embedded_io::Write`].
+///
+/// ## Errors
+///
+/// Returns the writer's error if the frame cannot be written in full.
+#[cfg(feature = "embedded-io")]
+pub fn write_into<T, B, L, W>(grid: &GridBuf<T, B, L>, writer: &mut W) -> Result<(), W::Error>
+where
+    T: GxyElement,
+    B: AsRef<[T]>,
+    L: GxyLayout,
+    W: embedded_io::Write,
+{
+    writer.write_all(&to_bytes(grid))
+}
+
+/// Reads a single `gxy` frame from an [`embedded_io::Read`] into a grid.
+///
+/// ## Errors
+///
+/// Returns [`GxyIoError::Io`] if the reader fails, or [`GxyIoError::Format`] if the frame is
+/// malformed or does not match the requested grid type.
+#[cfg(feature = "embedded-io")]
+pub fn read_from<T, L, R>(reader: &mut R) -> Result<GridBuf<T, Vec<T>, L>, GxyIoError<R::Error>>
+where
+    T: GxyElement,
+    L: GxyLayout,
+    R: embedded_io::Read,
+{
+    let mut header = [0u8; HEADER_LEN];
+    read_exact(reader, &mut header)?;
+    let (layout_id, element_size, width, height) = parse_header(&header)?;
+    if layout_id != L::ID {
+        return Err(GxyError::LayoutMismatch.into());
+    }
+    if element_size != T::SIZE {
+        return Err(GxyError::ElementMismatch.into());
+    }
+    let mut payload = alloc::vec![0u8; width * height * T::SIZE];
+    read_exact(reader, &mut payload)?;
+    let data = decode_payload(&payload, width * height)?;
+    if width == 0 || height == 0 {
+        return Ok(GridBuf::new_filled_with_layout(0, 0, T::default()));
+    }
+    Ok(GridBuf::from_buffer(data, width))
+}
+
+/// An error type for streaming `gxy` frames: either an I/O error or a malformed frame.
+#[cfg(feature = "embedded-io")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GxyIoError<E> {
+    /// The underlying reader failed or ended early.
+    Io(E),
+
+    /// The frame was malformed or does not match the requested grid type.
+    Format(GxyError),
+}
+
+#[cfg(feature = "embedded-io")]
+impl<E> From<GxyError> for GxyIoError<E> {
+    fn from(err: GxyError) -> Self {
+        GxyIoError::Format(err)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+fn read_exact<R: embedded_io::Read>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<(), GxyIoError<R::Error>> {
+    reader.read_exact(buf).map_err(|err| match err {
+        embedded_io::ReadExactError::UnexpectedEof => GxyIoError::Format(GxyError::TruncatedData),
+        embedded_io::ReadExactError::Other(err) => GxyIoError::Io(err),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core::Pos, ops::GridRead as _};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip_u16_row_major() {
+        let mut grid = GridBuf::<u16, _, RowMajor>::new_filled(3, 2, 0);
+        grid[Pos::new(1, 0)] = 0x1234;
+
+        let bytes = to_bytes(&grid);
+        assert_eq!(&bytes[..4], b"GXY\0");
+        assert_eq!(bytes.len(), 16 + 6 * 2);
+        // The payload is little-endian regardless of host byte order.
+        assert_eq!(&bytes[18..20], &[0x34, 0x12]);
+
+        let decoded: GridBuf<u16, Vec<u16>, RowMajor> = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, grid);
+    }
+
+    #[test]
+    fn roundtrip_column_major() {
+        let grid = GridBuf::<u8, _, ColumnMajor>::from_buffer(alloc::vec![1, 2, 3, 4, 5, 6], 3);
+        let decoded: GridBuf<u8, Vec<u8>, ColumnMajor> = from_bytes(&to_bytes(&grid)).unwrap();
+        assert_eq!(decoded.get(Pos::new(2, 1)), grid.get(Pos::new(2, 1)));
+    }
+
+    #[test]
+    fn mismatches_are_rejected() {
+        let grid = GridBuf::<u16, _, RowMajor>::new_filled(2, 2, 0);
+        let bytes = to_bytes(&grid);
+
+        let wrong_elem: Result<GridBuf<u8, Vec<u8>, RowMajor>, _> = from_bytes(&bytes);
+        assert_eq!(wrong_elem, Err(GxyError::ElementMismatch));
+
+        let wrong_layout: Result<GridBuf<u16, Vec<u16>, ColumnMajor>, _> = from_bytes(&bytes);
+        assert_eq!(wrong_layout, Err(GxyError::LayoutMismatch));
+    }
+
+    #[test]
+    fn bad_frames_are_rejected() {
+        let grid = GridBuf::<u8, _, RowMajor>::new_filled(2, 2, 9);
+        let mut bytes = to_bytes(&grid);
+
+        let truncated: Result<GridBuf<u8, Vec<u8>, RowMajor>, _> = from_bytes(&bytes[..17]);
+        assert_eq!(truncated, Err(GxyError::TruncatedData));
+
+        bytes[4] = 99;
+        let version: Result<GridBuf<u8, Vec<u8>, RowMajor>, _> = from_bytes(&bytes);
+        assert_eq!(version, Err(GxyError::UnsupportedVersion));
+
+        bytes[0] = b'X';
+        let magic: Result<GridBuf<u8, Vec<u8>, RowMajor>, _> = from_bytes(&bytes);
+        assert_eq!(magic, Err(GxyError::InvalidMagic));
+    }
+
+    #[cfg(feature = "embedded-io")]
+    #[test]
+    fn streaming_roundtrip() {
+        let grid = GridBuf::<u32, _, RowMajor>::new_filled(2, 2, 0xDEAD_BEEF);
+
+        let mut buffer = [0u8; 64];
+        let mut writer = &mut buffer[..];
+        write_into(&grid, &mut writer).unwrap();
+
+        let mut reader = &buffer[..];
+        let decoded: GridBuf<u32, Vec<u32>, RowMajor> = read_from(&mut reader).unwrap();
+        assert_eq!(decoded, grid);
+    }
+}
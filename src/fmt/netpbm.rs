@@ -0,0 +1,362 @@
+//! Netpbm (PBM/PGM/PPM) image loading and saving.
+//!
+//! Readers accept both the plain (ASCII, `P1`/`P2`/`P3`) and raw (binary, `P4`/`P5`/`P6`)
+//! variants; writers emit the raw variants. Only 8-bit sample depths are supported (a `maxval`
+//! of at most 255).
+//!
+//! - PBM (bitmap) maps to [`GridBits`], with `1` (black) read as `true`
+//! - PGM (graymap) maps to `GridBuf<u8>`
+//! - PPM (pixmap) maps to `GridBuf<[u8; 3]>` with `[r, g, b]` elements
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{core::Pos, fmt::netpbm, ops::GridRead as _};
+//!
+//! let image = netpbm::read_pgm(b"P2\n# A tiny ramp.\n3 1\n255\n0 128 255\n").unwrap();
+//! assert_eq!(image.get(Pos::new(1, 0)), Some(&128));
+//!
+//! let bytes = netpbm::write_pgm(&image);
+//! assert_eq!(netpbm::read_pgm(&bytes).unwrap(), image);
+//! ```
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+use core::{error::Error, fmt::Display};
+
+use crate::{
+    buf::{GridBuf, bits::GridBits},
+    core::Pos,
+    ops::{ExactSizeGrid as _, GridRead as _, GridWrite as _, layout::RowMajor},
+};
+
+/// An error type for decoding netpbm images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NetpbmError {
+    /// The magic number or header was malformed, or a dimension was zero.
+    InvalidHeader,
+
+    /// The image declares a sample depth above 8 bits (`maxval` over 255).
+    UnsupportedMaxVal,
+
+    /// The pixel data ended before `width * height` samples were read.
+    TruncatedData,
+}
+
+impl Display for NetpbmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NetpbmError::InvalidHeader => write!(f, "Invalid netpbm header"),
+            NetpbmError::UnsupportedMaxVal => write!(f, "Unsupported maxval (over 255)"),
+            NetpbmError::TruncatedData => write!(f, "Truncated netpbm pixel data"),
+        }
+    }
+}
+
+impl Error for NetpbmError {}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Skips whitespace and `#` comments (which run to the end of the line).
+    fn skip_space(&mut self) {
+        while let Some(byte) = self.peek() {
+            if byte.is_ascii_whitespace() {
+                self.pos += 1;
+            } else if byte == b'#' {
+                while self.peek().is_some_and(|b| b != b'\n') {
+                    self.pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Reads an unsigned ASCII integer, skipping leading whitespace and comments.
+    fn next_int(&mut self) -> Result<usize, NetpbmError> {
+        self.skip_space();
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(NetpbmError::InvalidHeader);
+        }
+        let mut value = 0usize;
+        for &digit in &self.bytes[start..self.pos] {
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(usize::from(digit - b'0')))
+                .ok_or(NetpbmError::InvalidHeader)?;
+        }
+        Ok(value)
+    }
+
+    /// Reads the magic number and dimensions, returning `(raw, width, height)`.
+    fn header(&mut self, plain: u8, raw: u8) -> Result<(bool, usize, usize), NetpbmError> {
+        if self.peek() != Some(b'P') {
+            return Err(NetpbmError::InvalidHeader);
+        }
+        self.pos += 1;
+        let is_raw = match self.peek() {
+            Some(kind) if kind == plain => false,
+            Some(kind) if kind == raw => true,
+            _ => return Err(NetpbmError::InvalidHeader),
+        };
+        self.pos += 1;
+        let width = self.next_int()?;
+        let height = self.next_int()?;
+        if width == 0 || height == 0 {
+            return Err(NetpbmError::InvalidHeader);
+        }
+        Ok((is_raw, width, height))
+    }
+
+    /// Reads an 8-bit `maxval`, rejecting deeper sample depths.
+    fn maxval(&mut self) -> Result<(), NetpbmError> {
+        match self.next_int()? {
+            0 => Err(NetpbmError::InvalidHeader),
+            1..=255 => Ok(()),
+            _ => Err(NetpbmError::UnsupportedMaxVal),
+        }
+    }
+
+    /// Consumes the single whitespace byte that separates the header from raw pixel data.
+    fn raw_data(&mut self, len: usize) -> Result<&'a [u8], NetpbmError> {
+        if !self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            return Err(NetpbmError::InvalidHeader);
+        }
+        self.pos += 1;
+        let data = &self.bytes[self.pos..];
+        if data.len() < len {
+            return Err(NetpbmError::TruncatedData);
+        }
+        Ok(&data[..len])
+    }
+
+    /// Reads `len` ASCII samples, each at most 255.
+    fn plain_data(&mut self, len: usize) -> Result<Vec<u8>, NetpbmError> {
+        let mut data = Vec::with_capacity(len);
+        for _ in 0..len {
+            let value = self.next_int().map_err(|_| NetpbmError::TruncatedData)?;
+            let value = u8::try_from(value).map_err(|_| NetpbmError::TruncatedData)?;
+            data.push(value);
+        }
+        Ok(data)
+    }
+}
+
+/// Reads a PBM (`P1` or `P4`) bitmap into a [`GridBits`], with `1` (black) read as `true`.
+///
+/// ## Errors
+///
+/// Returns an error if the header is malformed or the pixel data is truncated.
+pub fn read_pbm(bytes: &[u8]) -> Result<GridBits<u8, Vec<u8>, RowMajor>, NetpbmError> {
+    let mut reader = Reader::new(bytes);
+    let (is_raw, width, height) = reader.header(b'1', b'4')?;
+    let mut grid = GridBits::new(width, height);
+    if is_raw {
+        let row_len = width.div_ceil(8);
+        let data = reader.raw_data(row_len * height)?;
+        for y in 0..height {
+            let row = &data[y * row_len..];
+            for x in 0..width {
+                if row[x / 8] & (0x80 >> (x % 8)) != 0 {
+                    let _ = grid.set(Pos::new(x, y), true);
+                }
+            }
+        }
+    } else {
+        for y in 0..height {
+            for x in 0..width {
+                reader.skip_space();
+                match reader.peek() {
+                    Some(b'0') => {}
+                    Some(b'1') => {
+                        let _ = grid.set(Pos::new(x, y), true);
+                    }
+                    _ => return Err(NetpbmError::TruncatedData),
+                }
+                reader.pos += 1;
+            }
+        }
+    }
+    Ok(grid)
+}
+
+/// Reads a PGM (`P2` or `P5`) graymap into a `GridBuf<u8>`.
+///
+/// ## Errors
+///
+/// Returns an error if the header is malformed, the `maxval` exceeds 255, or the pixel data is
+/// truncated.
+pub fn read_pgm(bytes: &[u8]) -> Result<GridBuf<u8, Vec<u8>, RowMajor>, NetpbmError> {
+    let mut reader = Reader::new(bytes);
+    let (is_raw, width, height) = reader.header(b'2', b'5')?;
+    reader.maxval()?;
+    let data = if is_raw {
+        reader.raw_data(width * height)?.to_vec()
+    } else {
+        reader.plain_data(width * height)?
+    };
+    Ok(GridBuf::from_buffer(data, width))
+}
+
+/// Reads a PPM (`P3` or `P6`) pixmap into a `GridBuf<[u8; 3]>` of `[r, g, b]` elements.
+///
+/// ## Errors
+///
+/// Returns an error if the header is malformed, the `maxval` exceeds 255, or the pixel data is
+/// truncated.
+#[allow(clippy::type_complexity)]
+pub fn read_ppm(bytes: &[u8]) -> Result<GridBuf<[u8; 3], Vec<[u8; 3]>, RowMajor>, NetpbmError> {
+    let mut reader = Reader::new(bytes);
+    let (is_raw, width, height) = reader.header(b'3', b'6')?;
+    reader.maxval()?;
+    let samples = if is_raw {
+        reader.raw_data(width * height * 3)?.to_vec()
+    } else {
+        reader.plain_data(width * height * 3)?
+    };
+    let data = samples
+        .chunks_exact(3)
+        .map(|rgb| [rgb[0], rgb[1], rgb[2]])
+        .collect::<Vec<_>>();
+    Ok(GridBuf::from_buffer(data, width))
+}
+
+/// Writes a bit grid as a raw PBM (`P4`), with `true` written as `1` (black).
+#[must_use]
+pub fn write_pbm<B: AsRef<[u8]>>(grid: &GridBits<u8, B, RowMajor>) -> Vec<u8> {
+    let (width, height) = (grid.width(), grid.height());
+    let mut out = alloc::format!("P4\n{width} {height}\n").into_bytes();
+    for y in 0..height {
+        let mut row = vec![0u8; width.div_ceil(8)];
+        for x in 0..width {
+            if grid.get(Pos::new(x, y)) == Some(true) {
+                row[x / 8] |= 0x80 >> (x % 8);
+            }
+        }
+        out.extend_from_slice(&row);
+    }
+    out
+}
+
+/// Writes a grayscale grid as a raw PGM (`P5`).
+#[must_use]
+pub fn write_pgm<B: AsRef<[u8]>>(grid: &GridBuf<u8, B, RowMajor>) -> Vec<u8> {
+    let (width, height) = (grid.width(), grid.height());
+    let mut out = alloc::format!("P5\n{width} {height}\n255\n").into_bytes();
+    for row in grid.rows() {
+        out.extend_from_slice(row);
+    }
+    out
+}
+
+/// Writes an RGB grid as a raw PPM (`P6`).
+#[must_use]
+pub fn write_ppm<B: AsRef<[[u8; 3]]>>(grid: &GridBuf<[u8; 3], B, RowMajor>) -> Vec<u8> {
+    let (width, height) = (grid.width(), grid.height());
+    let mut out = alloc::format!("P6\n{width} {height}\n255\n").into_bytes();
+    for row in grid.rows() {
+        for rgb in row {
+            out.extend_from_slice(rgb);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn pbm_raw_roundtrip_pads_rows() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(5, 3);
+        for pos in [Pos::new(0, 0), Pos::new(4, 1), Pos::new(2, 2)] {
+            grid.set(pos, true).unwrap();
+        }
+
+        let bytes = write_pbm(&grid);
+        assert!(bytes.starts_with(b"P4\n5 3\n"));
+        assert_eq!(read_pbm(&bytes).unwrap(), grid);
+    }
+
+    #[test]
+    fn pbm_plain_parses_unseparated_digits() {
+        let grid = read_pbm(b"P1\n# comment\n3 2\n101\n010\n").unwrap();
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(true));
+        assert_eq!(grid.get(Pos::new(1, 0)), Some(false));
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(true));
+        assert_eq!(grid.count_set(crate::core::Rect::from_ltwh(0, 0, 3, 2)), 3);
+    }
+
+    #[test]
+    fn pgm_raw_roundtrip() {
+        let grid = GridBuf::<u8, _, RowMajor>::from_buffer(vec![0, 64, 128, 255, 1, 2], 3);
+        let bytes = write_pgm(&grid);
+        assert!(bytes.starts_with(b"P5\n3 2\n255\n"));
+        assert_eq!(read_pgm(&bytes).unwrap(), grid);
+    }
+
+    #[test]
+    fn pgm_plain_with_comments() {
+        let grid = read_pgm(b"P2\n# ramp\n4 1\n255\n0 85 170 255\n").unwrap();
+        let (buffer, _, _) = grid.into_inner();
+        assert_eq!(buffer, vec![0, 85, 170, 255]);
+    }
+
+    #[test]
+    fn ppm_raw_roundtrip() {
+        let grid = GridBuf::<[u8; 3], _, RowMajor>::from_buffer(
+            vec![[255, 0, 0], [0, 255, 0], [0, 0, 255], [9, 9, 9]],
+            2,
+        );
+        let bytes = write_ppm(&grid);
+        assert!(bytes.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(read_ppm(&bytes).unwrap(), grid);
+    }
+
+    #[test]
+    fn ppm_plain_groups_samples() {
+        let grid = read_ppm(b"P3\n1 2\n255\n1 2 3\n4 5 6\n").unwrap();
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&[1, 2, 3]));
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&[4, 5, 6]));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(
+            read_pgm(b"P7\n1 1\n255\n\0"),
+            Err(NetpbmError::InvalidHeader)
+        );
+        assert_eq!(read_pgm(b"P2\n0 1\n255\n"), Err(NetpbmError::InvalidHeader));
+        assert_eq!(
+            read_pgm(b"P2\n2 1\n65535\n0 0\n"),
+            Err(NetpbmError::UnsupportedMaxVal)
+        );
+        assert_eq!(
+            read_pgm(b"P5\n2 2\n255\n\0\0"),
+            Err(NetpbmError::TruncatedData)
+        );
+        assert_eq!(read_pbm(b"P1\n2 2\n10"), Err(NetpbmError::TruncatedData));
+    }
+}
@@ -0,0 +1,135 @@
+//! Tiled map editor CSV tile-layer loading.
+//!
+//! Parses the CSV encoding used by [Tiled](https://www.mapeditor.org/) tile layers (the text
+//! inside `<data encoding="csv">` in a TMX file, or the `"data"` array joined with commas in a
+//! JSON export) into a `GridBuf<u32>` of global tile IDs (GIDs).
+//!
+//! Raw GIDs carry the layer's flip flags in their upper bits; use [`gid`] to strip them, or the
+//! `FLIPPED_*` constants to inspect them. A GID of `0` means an empty cell.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{core::Pos, fmt::tiled, ops::GridRead as _};
+//!
+//! let layer = tiled::read_layer_csv("1,2,3,\n4,5,6\n").unwrap();
+//! assert_eq!(layer.get(Pos::new(2, 1)), Some(&6));
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{error::Error, fmt::Display};
+
+use crate::{buf::GridBuf, ops::layout::RowMajor};
+
+/// The GID bit flagging a tile as flipped horizontally.
+pub const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+
+/// The GID bit flagging a tile as flipped vertically.
+pub const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+
+/// The GID bit flagging a tile as flipped anti-diagonally (rotated).
+pub const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+
+/// Returns a raw GID with its flip flags stripped.
+#[must_use]
+pub const fn gid(raw: u32) -> u32 {
+    raw & !(FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY)
+}
+
+/// An error type for decoding Tiled CSV tile layers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TiledError {
+    /// The layer contained no rows, or a cell was not an unsigned integer.
+    InvalidLayer,
+
+    /// A row had a different number of tiles than the first row.
+    RaggedRows,
+}
+
+impl Display for TiledError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TiledError::InvalidLayer => write!(f, "Invalid Tiled CSV layer"),
+            TiledError::RaggedRows => write!(f, "Tiled CSV rows have differing widths"),
+        }
+    }
+}
+
+impl Error for TiledError {}
+
+/// Reads a Tiled CSV tile layer into a `GridBuf<u32>` of raw GIDs.
+///
+/// The width is inferred from the first row. Blank lines and trailing commas (which Tiled emits
+/// at the end of every row) are ignored. GIDs keep their flip flags; see [`gid`].
+///
+/// ## Errors
+///
+/// Returns an error if the layer is empty, a cell is not an unsigned integer, or rows have
+/// differing widths.
+pub fn read_layer_csv(csv: &str) -> Result<GridBuf<u32, Vec<u32>, RowMajor>, TiledError> {
+    let mut data = Vec::new();
+    let mut width = None;
+    for line in csv.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let start = data.len();
+        for cell in line.split(',') {
+            let cell = cell.trim();
+            if cell.is_empty() {
+                continue;
+            }
+            let value = cell.parse::<u32>().map_err(|_| TiledError::InvalidLayer)?;
+            data.push(value);
+        }
+        let row_len = data.len() - start;
+        match width {
+            None => width = Some(row_len),
+            Some(expected) if expected != row_len => return Err(TiledError::RaggedRows),
+            Some(_) => {}
+        }
+    }
+    let width = width.filter(|&w| w > 0).ok_or(TiledError::InvalidLayer)?;
+    Ok(GridBuf::from_buffer(data, width))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core::Pos, ops::GridRead as _};
+
+    use super::*;
+
+    #[test]
+    fn reads_a_layer_with_trailing_commas() {
+        let layer = read_layer_csv("1,2,3,\n4,5,6,\n").unwrap();
+        assert_eq!(layer.get(Pos::new(0, 0)), Some(&1));
+        assert_eq!(layer.get(Pos::new(2, 1)), Some(&6));
+        assert_eq!(layer.get(Pos::new(3, 0)), None);
+    }
+
+    #[test]
+    fn skips_blank_lines_and_whitespace() {
+        let layer = read_layer_csv("\n  0, 7 ,0\n\n 7,0,7 \n").unwrap();
+        assert_eq!(layer.get(Pos::new(1, 0)), Some(&7));
+        assert_eq!(layer.get(Pos::new(0, 1)), Some(&7));
+    }
+
+    #[test]
+    fn keeps_flip_flags_on_raw_gids() {
+        let layer = read_layer_csv("2147483649\n").unwrap();
+        let raw = *layer.get(Pos::new(0, 0)).unwrap();
+        assert_eq!(raw & FLIPPED_HORIZONTALLY, FLIPPED_HORIZONTALLY);
+        assert_eq!(gid(raw), 1);
+    }
+
+    #[test]
+    fn rejects_bad_layers() {
+        assert_eq!(read_layer_csv(""), Err(TiledError::InvalidLayer));
+        assert_eq!(read_layer_csv("1,2\n3\n"), Err(TiledError::RaggedRows));
+        assert_eq!(read_layer_csv("1,-2\n"), Err(TiledError::InvalidLayer));
+    }
+}
@@ -50,6 +50,50 @@
 //! ### `cell`
 //!
 //! Provides `GridWrite` when a mutable cell is wrapping a `GridWrite` type.
+//!
+//! ### `console`
+//!
+//! Provides terminal cell grids and an incremental ANSI renderer through `grixy::console`.
+//!
+//! ### `defmt`
+//!
+//! Provides `defmt::Format` adapters for grid errors, coordinates, and previews.
+//!
+//! ### `embedded-graphics`
+//!
+//! Provides `DrawTarget` and `ImageDrawable` adapters for the `embedded-graphics` crate.
+//!
+//! ### `embedded-io`
+//!
+//! Provides streaming reads and writes of `gxy` frames over `embedded-io` traits.
+//!
+//! ### `ndarray`
+//!
+//! Provides conversions between `GridBuf` and `ndarray::Array2` (and `ArrayView2`).
+//!
+//! ### `noise`
+//!
+//! Provides seeded value and Perlin noise generators through `grixy::noise`.
+//!
+//! ### `path`
+//!
+//! Provides A* pathfinding over any readable grid.
+//!
+//! ### `rayon`
+//!
+//! Provides parallel (row-band) fills, maps, and row iteration for `GridBuf`.
+//!
+//! ### `simd`
+//!
+//! Provides vectorization-friendly chunked fills for `u8`/`u32` grid buffers.
+//!
+//! ### `std`
+//!
+//! Provides `GridRead`/`GridWrite` when a `std::sync::Mutex` or `RwLock` is wrapping a grid.
+//!
+//! ### `wfc`
+//!
+//! Provides a seeded Wave Function Collapse generator through `grixy::wfc`.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![no_std]
@@ -58,10 +102,27 @@ pub(crate) mod internal;
 
 #[cfg(feature = "buffer")]
 pub mod buf;
+#[cfg(feature = "console")]
+pub mod console;
 pub mod core;
+#[cfg(feature = "defmt")]
+pub mod defmt;
+#[cfg(feature = "embedded-graphics")]
+pub mod embedded_graphics;
+#[cfg(all(feature = "alloc", feature = "buffer"))]
+pub mod fmt;
+#[cfg(feature = "noise")]
+pub mod noise;
 pub mod ops;
 pub mod prelude;
+#[cfg(feature = "buffer")]
+pub mod present;
+pub mod sim;
+pub mod tilemap;
 pub mod transform;
 
+#[cfg(feature = "wfc")]
+pub mod wfc;
+
 #[cfg(test)]
 pub mod test;
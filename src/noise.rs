@@ -0,0 +1,206 @@
+//! Deterministic value and Perlin noise grid generators.
+//!
+//! Both generators are seeded and fully deterministic: the same size, seed, and parameters
+//! always produce the same grid on every platform. No floating-point intrinsics are required,
+//! so the module works on `no_std` targets.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{core::Pos, noise::value_noise, ops::GridRead as _};
+//!
+//! let heightmap = value_noise(64, 64, 42, 4);
+//! let height = *heightmap.get(Pos::new(10, 20)).unwrap();
+//! assert!((0.0..=1.0).contains(&height));
+//! ```
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{buf::GridBuf, ops::layout::RowMajor};
+
+/// Gradient directions for [`perlin`], with diagonals normalized to unit length.
+const GRADS: [(f32, f32); 8] = {
+    const DIAG: f32 = core::f32::consts::FRAC_1_SQRT_2;
+    [
+        (1.0, 0.0),
+        (-1.0, 0.0),
+        (0.0, 1.0),
+        (0.0, -1.0),
+        (DIAG, DIAG),
+        (-DIAG, DIAG),
+        (DIAG, -DIAG),
+        (-DIAG, -DIAG),
+    ]
+};
+
+/// Hashes a lattice point with the seed into a uniformly mixed value.
+fn hash(seed: u64, x: u64, y: u64) -> u64 {
+    let mut z =
+        seed ^ x.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ y.wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns the lattice value at a point, uniformly distributed in `[0, 1)`.
+#[allow(clippy::cast_precision_loss)]
+fn lattice(seed: u64, x: u64, y: u64) -> f32 {
+    (hash(seed, x, y) >> 40) as f32 / (1u32 << 24) as f32
+}
+
+/// The smoothstep fade curve, `3t^2 - 2t^3`.
+fn fade(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Splits a pixel coordinate into its lattice cell and smoothed fractional offset.
+#[allow(clippy::cast_precision_loss)]
+fn split(coord: usize, cell: usize) -> (u64, f32) {
+    ((coord / cell) as u64, (coord % cell) as f32 / cell as f32)
+}
+
+/// Generates fractal value noise, returning a grid of heights in `[0, 1]`.
+///
+/// The base octave spans half the larger grid dimension per lattice cell, and each further
+/// octave halves both the cell size and its contribution (fractal Brownian motion). More
+/// octaves add finer detail.
+///
+/// ## Panics
+///
+/// Panics if `octaves` is `0`.
+#[must_use]
+pub fn value_noise(
+    width: usize,
+    height: usize,
+    seed: u64,
+    octaves: u32,
+) -> GridBuf<f32, Vec<f32>, RowMajor> {
+    assert!(octaves > 0, "At least one octave is required");
+    let base_cell = (width.max(height) / 2).max(1);
+
+    let mut cells = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let mut total = 0.0;
+            let mut amplitude = 1.0;
+            let mut range = 0.0;
+            let mut cell = base_cell;
+            for octave in 0..u64::from(octaves) {
+                let (x0, fx) = split(x, cell);
+                let (y0, fy) = split(y, cell);
+                let seed = seed.wrapping_add(octave);
+                let top = lerp(lattice(seed, x0, y0), lattice(seed, x0 + 1, y0), fade(fx));
+                let bottom = lerp(
+                    lattice(seed, x0, y0 + 1),
+                    lattice(seed, x0 + 1, y0 + 1),
+                    fade(fx),
+                );
+                total += lerp(top, bottom, fade(fy)) * amplitude;
+                range += amplitude;
+                amplitude /= 2.0;
+                cell = (cell / 2).max(1);
+            }
+            cells.push(total / range);
+        }
+    }
+    GridBuf::from_buffer(cells, width)
+}
+
+/// Generates Perlin gradient noise, returning a grid of values in `[-1, 1]`.
+///
+/// `frequency` is the number of lattice cells across the grid's width; higher frequencies
+/// produce finer features. Values are centered on `0`, so heightmaps usually remap them to
+/// `[0, 1]` with `(value + 1.0) / 2.0`.
+///
+/// ## Panics
+///
+/// Panics if `frequency` is `0`.
+#[must_use]
+pub fn perlin(
+    width: usize,
+    height: usize,
+    seed: u64,
+    frequency: usize,
+) -> GridBuf<f32, Vec<f32>, RowMajor> {
+    assert!(frequency > 0, "A zero frequency has no lattice");
+    let cell = width.max(1).div_ceil(frequency).max(1);
+
+    let grad = |x: u64, y: u64, dx: f32, dy: f32| {
+        #[allow(clippy::cast_possible_truncation)]
+        let (gx, gy) = GRADS[(hash(seed, x, y) & 7) as usize];
+        gx * dx + gy * dy
+    };
+
+    let mut cells = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let (x0, fx) = split(x, cell);
+            let (y0, fy) = split(y, cell);
+            let top = lerp(
+                grad(x0, y0, fx, fy),
+                grad(x0 + 1, y0, fx - 1.0, fy),
+                fade(fx),
+            );
+            let bottom = lerp(
+                grad(x0, y0 + 1, fx, fy - 1.0),
+                grad(x0 + 1, y0 + 1, fx - 1.0, fy - 1.0),
+                fade(fx),
+            );
+            cells.push(lerp(top, bottom, fade(fy)));
+        }
+    }
+    GridBuf::from_buffer(cells, width)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn bits(grid: GridBuf<f32, Vec<f32>, RowMajor>) -> Vec<u32> {
+        grid.into_inner()
+            .0
+            .iter()
+            .map(|value| value.to_bits())
+            .collect()
+    }
+
+    #[test]
+    fn value_noise_is_deterministic_per_seed() {
+        assert_eq!(bits(value_noise(8, 8, 1, 3)), bits(value_noise(8, 8, 1, 3)));
+        assert_ne!(bits(value_noise(8, 8, 1, 3)), bits(value_noise(8, 8, 2, 3)));
+    }
+
+    #[test]
+    fn value_noise_stays_in_range() {
+        let grid = value_noise(16, 16, 99, 4);
+        assert!(grid.into_inner().0.iter().all(|v| (0.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn perlin_is_deterministic_per_seed() {
+        assert_eq!(bits(perlin(8, 8, 1, 4)), bits(perlin(8, 8, 1, 4)));
+        assert_ne!(bits(perlin(8, 8, 1, 4)), bits(perlin(8, 8, 2, 4)));
+    }
+
+    #[test]
+    fn perlin_stays_in_range() {
+        let grid = perlin(16, 16, 7, 4);
+        assert!(grid.into_inner().0.iter().all(|v| (-1.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn octaves_add_detail() {
+        // With more octaves the output differs from the base octave alone.
+        assert_ne!(bits(value_noise(8, 8, 5, 1)), bits(value_noise(8, 8, 5, 4)));
+    }
+}
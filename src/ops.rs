@@ -55,7 +55,11 @@
 //! assert_eq!(my_grid.grid[55], 42);
 //! ```
 
+pub mod blend;
 pub mod layout;
+#[cfg(all(feature = "alloc", feature = "buffer"))]
+pub mod morphology;
+pub mod stats;
 pub mod unchecked;
 
 #[cfg(feature = "alloc")]
@@ -64,14 +68,65 @@ mod alloc;
 #[cfg(feature = "cell")]
 mod cell;
 
+#[cfg(feature = "alloc")]
+mod flood;
+
+#[cfg(feature = "path")]
+mod path;
+
+#[cfg(feature = "std")]
+mod sync;
+
 mod base;
+mod blocked;
+mod boundary;
+mod colorize;
+mod curve;
 mod diff;
 mod draw;
+mod eq;
+mod filter;
+mod line;
+mod map;
+mod perimeter;
 mod read;
+mod read_mut;
+mod render;
+mod shift;
+mod sparse;
+mod stamp;
+mod swap;
 mod write;
+mod zip;
 
 pub use base::{ExactSizeGrid, GridBase};
-pub use diff::GridDiff;
-pub use draw::copy_rect;
+pub use blocked::{for_each_blocked, iter_pos_blocked};
+pub use boundary::trace_boundary;
+pub use colorize::{Colormap, colorize};
+pub use curve::{draw_bezier_cubic, draw_bezier_quad, draw_polyline};
+pub use diff::{GridDiff, diff};
+pub use draw::{blit_rect, copy_col, copy_rect, copy_row, draw_grid_lines};
+pub use eq::grids_equal;
+pub use filter::{EdgeMode, filter};
+#[cfg(feature = "alloc")]
+pub use flood::flood_region;
+pub use line::{
+    draw_line, draw_line_aa, draw_line_thick, line_iter, line_iter_supercover, line_of_sight,
+};
+pub use map::map_rect;
+#[cfg(feature = "path")]
+pub use path::astar;
+#[cfg(all(feature = "path", feature = "buffer"))]
+pub use path::distance_field;
+pub use perimeter::perimeter_iter;
 pub use read::{GridIter, GridRead};
+pub use read_mut::GridReadMut;
+pub use render::DisplayGrid;
+#[cfg(feature = "alloc")]
+pub use render::{render_ascii, render_braille, render_half_blocks};
+pub use shift::{move_rect, scroll};
+pub use sparse::GridSparse;
+pub use stamp::stamp;
+pub use swap::{swap_rect, swap_rect_within};
 pub use write::GridWrite;
+pub use zip::zip_rect;
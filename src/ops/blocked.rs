@@ -0,0 +1,157 @@
+use crate::{
+    core::{Pos, Rect},
+    ops::{
+        GridRead,
+        layout::{self, Traversal},
+    },
+};
+
+/// Returns an iterator over the block rectangles that tile `bounds`.
+///
+/// Blocks are yielded left to right, top to bottom; blocks at the right and bottom edges are
+/// clipped to `bounds`.
+fn blocks(bounds: Rect, block_w: usize, block_h: usize) -> impl Iterator<Item = Rect> {
+    assert!(
+        block_w > 0 && block_h > 0,
+        "Block dimensions must be non-zero"
+    );
+    let left = bounds.top_left().x;
+    let top = bounds.top_left().y;
+    (top..bounds.bottom()).step_by(block_h).flat_map(move |y| {
+        (left..bounds.right()).step_by(block_w).map(move |x| {
+            Rect::from_ltwh(
+                x,
+                y,
+                block_w.min(bounds.right() - x),
+                block_h.min(bounds.bottom() - y),
+            )
+        })
+    })
+}
+
+/// Iterates the positions of a rectangle in cache-sized blocks.
+///
+/// The rectangle is tiled into `block_w` × `block_h` blocks, visited left to right and top to
+/// bottom; within each block, positions follow the layout's traversal order. Large transposes
+/// and convolutions are memory-bound in plain streaming order, and a blocked order keeps each
+/// working set cache-resident.
+///
+/// ## Panics
+///
+/// Panics if `block_w` or `block_h` is zero.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{iter_pos_blocked, layout::RowMajor}, core::Rect};
+///
+/// let mut pos = iter_pos_blocked::<RowMajor>(Rect::from_ltwh(0, 0, 4, 2), 2, 2);
+/// assert_eq!(pos.next(), Some(Pos::new(0, 0)));
+/// assert_eq!(pos.next(), Some(Pos::new(1, 0)));
+/// assert_eq!(pos.next(), Some(Pos::new(0, 1)));
+/// assert_eq!(pos.next(), Some(Pos::new(1, 1)));
+/// assert_eq!(pos.next(), Some(Pos::new(2, 0)));
+/// ```
+pub fn iter_pos_blocked<L>(
+    bounds: Rect,
+    block_w: usize,
+    block_h: usize,
+) -> impl Iterator<Item = Pos>
+where
+    L: layout::Traversal,
+{
+    blocks(bounds, block_w, block_h).flat_map(L::iter_pos)
+}
+
+/// Calls a function for every element of a rectangular region, in cache-sized blocks.
+///
+/// The region is intersected with the grid and tiled as in [`iter_pos_blocked`], using the
+/// grid's own layout within each block so linear buffers read each block's rows as contiguous
+/// segments.
+///
+/// ## Panics
+///
+/// Panics if `block_w` or `block_h` is zero.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::{for_each_blocked, GridRead}, buf::GridBuf};
+///
+/// let grid = GridBuf::new_filled(64, 64, 1u32);
+/// let mut sum = 0;
+/// for_each_blocked(&grid, Rect::from_ltwh(0, 0, 64, 64), 16, 16, |_, value| sum += value);
+/// assert_eq!(sum, 64 * 64);
+/// ```
+pub fn for_each_blocked<'a, E, G>(
+    src: &'a G,
+    bounds: Rect,
+    block_w: usize,
+    block_h: usize,
+    mut f: impl FnMut(Pos, E),
+) where
+    G: GridRead<Element<'a> = E>,
+{
+    let bounds = src.trim_rect(bounds);
+    for block in blocks(bounds, block_w, block_h) {
+        <G::Layout as Traversal>::iter_pos(block)
+            .zip(src.iter_rect(block))
+            .for_each(|(pos, value)| f(pos, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{buf::GridBuf, ops::layout::RowMajor};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iter_pos_blocked_visits_blocks_in_order() {
+        let pos: Vec<_> = iter_pos_blocked::<RowMajor>(Rect::from_ltwh(0, 0, 4, 4), 2, 2)
+            .take(8)
+            .collect();
+        assert_eq!(
+            pos,
+            [
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(0, 1),
+                Pos::new(1, 1),
+                Pos::new(2, 0),
+                Pos::new(3, 0),
+                Pos::new(2, 1),
+                Pos::new(3, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_pos_blocked_clips_edge_blocks() {
+        let count = iter_pos_blocked::<RowMajor>(Rect::from_ltwh(0, 0, 5, 3), 2, 2).count();
+        assert_eq!(count, 15);
+    }
+
+    #[test]
+    #[should_panic(expected = "Block dimensions must be non-zero")]
+    fn iter_pos_blocked_rejects_zero_blocks() {
+        let _ = iter_pos_blocked::<RowMajor>(Rect::from_ltwh(0, 0, 4, 4), 0, 2);
+    }
+
+    #[test]
+    fn for_each_blocked_visits_every_cell_once() {
+        let grid = GridBuf::<u32, _, RowMajor>::new_filled(8, 8, 1);
+        let mut sum = 0;
+        let mut visited = Vec::new();
+        for_each_blocked(&grid, Rect::from_ltwh(0, 0, 9, 9), 3, 3, |pos, value| {
+            sum += *value;
+            visited.push(pos);
+        });
+        assert_eq!(sum, 64);
+        visited.sort_by_key(|pos| (pos.y, pos.x));
+        visited.dedup();
+        assert_eq!(visited.len(), 64);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{core::Pos, ops::GridRead};
+
+/// The Moore neighborhood in clockwise order, starting from the west neighbor.
+const DIRS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+
+/// Traces the boundary of a region, yielding its ordered outline positions.
+///
+/// The region is the set of cells for which `predicate` returns `true`, and `start` must be a
+/// boundary cell of it; tracing walks the outline clockwise using Moore neighbor tracing and
+/// stops once the outline closes. Each outline position is yielded once, beginning with `start`.
+/// If `start` is not part of the region, nothing is yielded; an isolated cell yields just
+/// itself.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::{GridWrite as _, trace_boundary}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new_filled(4, 4, 0u8);
+/// grid.fill_rect_solid(Rect::from_ltwh(0, 0, 2, 2), 1);
+///
+/// let outline: Vec<_> = trace_boundary(&grid, |&cell| cell == 1, Pos::new(0, 0)).collect();
+/// assert_eq!(outline.len(), 4);
+/// assert_eq!(outline[0], Pos::new(0, 0));
+/// ```
+pub fn trace_boundary<'a, G>(
+    grid: &'a G,
+    predicate: impl Fn(G::Element<'a>) -> bool + 'a,
+    start: Pos,
+) -> impl Iterator<Item = Pos> + 'a
+where
+    G: GridRead,
+{
+    let is_region = move |x: isize, y: isize| {
+        if x < 0 || y < 0 {
+            return false;
+        }
+        #[allow(clippy::cast_sign_loss)]
+        grid.get(Pos::new(x as usize, y as usize))
+            .is_some_and(&predicate)
+    };
+
+    #[allow(clippy::cast_possible_wrap)]
+    let start = (start.x as isize, start.y as isize);
+    let mut cur = start;
+    let mut dir_from = 0;
+    let mut first_move: Option<((isize, isize), usize)> = None;
+    let mut started = false;
+    let mut done = !is_region(start.0, start.1);
+
+    core::iter::from_fn(move || {
+        loop {
+            if done {
+                return None;
+            }
+            if !started {
+                started = true;
+                #[allow(clippy::cast_sign_loss)]
+                return Some(Pos::new(cur.0 as usize, cur.1 as usize));
+            }
+            // Scan the Moore neighborhood clockwise, starting just past the backtrack cell.
+            let Some(k) = (1..=8).find(|k| {
+                let (dx, dy) = DIRS[(dir_from + k) % 8];
+                is_region(cur.0 + dx, cur.1 + dy)
+            }) else {
+                done = true;
+                return None;
+            };
+            let (dx, dy) = DIRS[(dir_from + k) % 8];
+            let next = (cur.0 + dx, cur.1 + dy);
+            // The new backtrack cell is the last non-region neighbor checked before `next`.
+            let (bx, by) = DIRS[(dir_from + k + 7) % 8];
+            let back = (cur.0 + bx, cur.1 + by);
+            let rel = (back.0 - next.0, back.1 - next.1);
+            dir_from = DIRS.iter().position(|&d| d == rel).unwrap_or(0);
+            cur = next;
+            if first_move.is_none() {
+                first_move = Some((cur, dir_from));
+            } else if first_move == Some((cur, dir_from)) {
+                done = true;
+                return None;
+            }
+            if cur != start {
+                #[allow(clippy::cast_sign_loss)]
+                return Some(Pos::new(cur.0 as usize, cur.1 as usize));
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{core::Rect, ops::GridWrite as _, test::NaiveGrid};
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn square_outline_clockwise() {
+        let mut grid = NaiveGrid::<u8>::new(4, 4);
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, 2, 2), 1);
+
+        let outline: Vec<_> = trace_boundary(&grid, |&cell| cell == 1, Pos::new(0, 0)).collect();
+        assert_eq!(
+            outline,
+            [
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(1, 1),
+                Pos::new(0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn larger_region_skips_interior() {
+        let mut grid = NaiveGrid::<u8>::new(5, 5);
+        grid.fill_rect_solid(Rect::from_ltwh(1, 1, 3, 3), 1);
+
+        let outline: Vec<_> = trace_boundary(&grid, |&cell| cell == 1, Pos::new(1, 1)).collect();
+        assert_eq!(outline.len(), 8);
+        assert!(!outline.contains(&Pos::new(2, 2)));
+    }
+
+    #[test]
+    fn isolated_cell_yields_itself() {
+        let mut grid = NaiveGrid::<u8>::new(3, 3);
+        grid.set(Pos::new(1, 1), 1).unwrap();
+
+        let outline: Vec<_> = trace_boundary(&grid, |&cell| cell == 1, Pos::new(1, 1)).collect();
+        assert_eq!(outline, [Pos::new(1, 1)]);
+    }
+
+    #[test]
+    fn start_outside_region_yields_nothing() {
+        let grid = NaiveGrid::<u8>::new(3, 3);
+        assert_eq!(
+            trace_boundary(&grid, |&cell| cell == 1, Pos::new(0, 0)).count(),
+            0
+        );
+    }
+}
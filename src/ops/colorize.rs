@@ -0,0 +1,159 @@
+//! Colorizes scalar grids for heat-map style visualization.
+
+use crate::{
+    ops::GridRead,
+    transform::{GridConvertExt as _, Mapped},
+};
+
+/// A built-in colormap turning a normalized scalar into a packed `0xAA_BB_GG_RR` pixel.
+///
+/// Samples are clamped to `0.0..=1.0` (`NaN` maps to `0.0`) and are always fully opaque. The
+/// perceptual maps are piecewise-linear approximations over nine anchor colors, which is plenty
+/// for previews while keeping the tables small.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Colormap {
+    /// Black to white.
+    #[default]
+    Grayscale,
+
+    /// Dark purple through teal to yellow; perceptually uniform and colorblind-friendly.
+    Viridis,
+
+    /// Dark blue through green and yellow to dark red; higher contrast than `Viridis`.
+    Turbo,
+}
+
+/// Anchor colors for [`Colormap::Viridis`], evenly spaced over `0.0..=1.0`.
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 44, 122],
+    [59, 81, 139],
+    [44, 113, 142],
+    [33, 144, 141],
+    [39, 173, 129],
+    [92, 200, 99],
+    [170, 220, 50],
+    [253, 231, 37],
+];
+
+/// Anchor colors for [`Colormap::Turbo`], evenly spaced over `0.0..=1.0`.
+const TURBO: [[u8; 3]; 9] = [
+    [48, 18, 59],
+    [68, 116, 235],
+    [31, 201, 163],
+    [113, 238, 74],
+    [164, 252, 60],
+    [234, 220, 56],
+    [252, 150, 38],
+    [225, 70, 21],
+    [122, 4, 3],
+];
+
+impl Colormap {
+    /// Samples the colormap at `t`, clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn sample(self, t: f32) -> u32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        match self {
+            Self::Grayscale => {
+                let v = quantize(t);
+                rgb(v, v, v)
+            }
+            Self::Viridis => sample_anchors(&VIRIDIS, t),
+            Self::Turbo => sample_anchors(&TURBO, t),
+        }
+    }
+}
+
+/// Linearly interpolates between the two anchors surrounding `t`.
+fn sample_anchors(anchors: &[[u8; 3]; 9], t: f32) -> u32 {
+    #[allow(clippy::cast_precision_loss)]
+    let scaled = t * (anchors.len() - 1) as f32;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let index = (scaled as usize).min(anchors.len() - 2);
+    #[allow(clippy::cast_precision_loss)]
+    let frac = scaled - index as f32;
+    let [r0, g0, b0] = anchors[index];
+    let [r1, g1, b1] = anchors[index + 1];
+    rgb(lerp(r0, r1, frac), lerp(g0, g1, frac), lerp(b0, b1, frac))
+}
+
+/// Linearly interpolates between two channel values, rounding to nearest.
+fn lerp(a: u8, b: u8, t: f32) -> u8 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let v = (f32::from(a) + (f32::from(b) - f32::from(a)) * t + 0.5) as u8;
+    v
+}
+
+/// Quantizes a normalized scalar to a channel value, rounding to nearest.
+fn quantize(t: f32) -> u8 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let v = (t * 255.0 + 0.5) as u8;
+    v
+}
+
+/// Packs fully opaque channels as `0xAA_BB_GG_RR`.
+const fn rgb(r: u8, g: u8, b: u8) -> u32 {
+    0xFF00_0000 | (b as u32) << 16 | (g as u32) << 8 | r as u32
+}
+
+/// Colorizes a normalized scalar grid with the given colormap.
+///
+/// The returned grid lazily samples the colormap per read, producing fully opaque
+/// `0xAA_BB_GG_RR` pixels; combine with [`GridConvertExt::copied`][] when the source yields
+/// references.
+///
+/// ```rust
+/// use grixy::{buf::GridBuf, core::Pos, ops::{Colormap, GridRead as _, colorize}, transform::GridConvertExt as _};
+///
+/// let field = GridBuf::new_filled(2, 2, 1.0f32);
+/// let pixels = colorize(field.copied(), Colormap::Grayscale);
+/// assert_eq!(pixels.get(Pos::new(0, 0)), Some(0xFFFF_FFFF));
+/// ```
+///
+/// [`GridConvertExt::copied`]: crate::transform::GridConvertExt::copied
+pub fn colorize<G>(grid: G, colormap: Colormap) -> Mapped<impl Fn(f32) -> u32, G, u32>
+where
+    for<'x> G: GridRead<Element<'x> = f32> + 'x,
+{
+    grid.map(move |t| colormap.sample(t))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{core::Pos, test::NaiveGrid};
+    use alloc::vec;
+
+    #[test]
+    fn grayscale_spans_black_to_white() {
+        assert_eq!(Colormap::Grayscale.sample(0.0), 0xFF00_0000);
+        assert_eq!(Colormap::Grayscale.sample(0.5), 0xFF80_8080);
+        assert_eq!(Colormap::Grayscale.sample(1.0), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    fn samples_clamp_out_of_range_and_nan() {
+        assert_eq!(Colormap::Grayscale.sample(-1.0), 0xFF00_0000);
+        assert_eq!(Colormap::Grayscale.sample(2.0), 0xFFFF_FFFF);
+        assert_eq!(Colormap::Grayscale.sample(f32::NAN), 0xFF00_0000);
+    }
+
+    #[test]
+    fn viridis_and_turbo_hit_their_endpoints() {
+        assert_eq!(Colormap::Viridis.sample(0.0), rgb(68, 1, 84));
+        assert_eq!(Colormap::Viridis.sample(1.0), rgb(253, 231, 37));
+        assert_eq!(Colormap::Turbo.sample(0.0), rgb(48, 18, 59));
+        assert_eq!(Colormap::Turbo.sample(1.0), rgb(122, 4, 3));
+    }
+
+    #[test]
+    fn colorize_samples_per_cell() {
+        let field = NaiveGrid::with_cells(2, 1, vec![0.0f32, 1.0]);
+        let pixels = colorize(field.copied(), Colormap::Grayscale);
+        assert_eq!(pixels.get(Pos::new(0, 0)), Some(0xFF00_0000));
+        assert_eq!(pixels.get(Pos::new(1, 0)), Some(0xFFFF_FFFF));
+    }
+}
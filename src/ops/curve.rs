@@ -0,0 +1,255 @@
+use crate::{
+    core::Pos,
+    ops::{GridWrite, draw_line},
+};
+
+/// Draws straight line segments connecting each consecutive pair of positions.
+///
+/// Each segment is rasterized with [`draw_line`], so cells outside the grid are ignored. An
+/// empty or single-position slice draws nothing or a single cell, respectively.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_polyline, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new(5, 5);
+/// draw_polyline(
+///     &mut grid,
+///     &[Pos::new(0, 0), Pos::new(4, 0), Pos::new(4, 4)],
+///     1,
+/// );
+///
+/// assert_eq!(grid.get(Pos::new(2, 0)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(4, 2)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(2, 2)), Some(&0));
+/// ```
+pub fn draw_polyline<T, G>(grid: &mut G, points: &[Pos], value: T)
+where
+    G: GridWrite<Element = T>,
+    T: Copy,
+{
+    match points {
+        [] => {}
+        [point] => {
+            let _ = grid.set(*point, value);
+        }
+        _ => {
+            for pair in points.windows(2) {
+                draw_line(grid, pair[0], pair[1], value);
+            }
+        }
+    }
+}
+
+/// Draws a quadratic Bézier curve from `from` to `to` with control point `ctrl`.
+///
+/// The curve is flattened into line segments, subdividing adaptively based on the size of the
+/// control polygon, and each segment is rasterized with [`draw_line`]; cells outside the grid
+/// are ignored. Evaluation uses integer arithmetic only.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_bezier_quad, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new(5, 5);
+/// draw_bezier_quad(&mut grid, Pos::new(0, 4), Pos::new(0, 0), Pos::new(4, 0), 1);
+///
+/// assert_eq!(grid.get(Pos::new(0, 4)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(4, 0)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(4, 4)), Some(&0));
+/// ```
+pub fn draw_bezier_quad<T, G>(grid: &mut G, from: Pos, ctrl: Pos, to: Pos, value: T)
+where
+    G: GridWrite<Element = T>,
+    T: Copy,
+{
+    let n = segments(&[from, ctrl, to]);
+    let mut last = from;
+    for i in 1..=n {
+        let m = n - i;
+        let x = weighted(
+            &[(from.x, m * m), (ctrl.x, 2 * m * i), (to.x, i * i)],
+            n * n,
+        );
+        let y = weighted(
+            &[(from.y, m * m), (ctrl.y, 2 * m * i), (to.y, i * i)],
+            n * n,
+        );
+        let next = Pos::new(x, y);
+        draw_line(grid, last, next, value);
+        last = next;
+    }
+}
+
+/// Draws a cubic Bézier curve from `from` to `to` with control points `ctrl_a` and `ctrl_b`.
+///
+/// The curve is flattened into line segments, subdividing adaptively based on the size of the
+/// control polygon, and each segment is rasterized with [`draw_line`]; cells outside the grid
+/// are ignored. Evaluation uses integer arithmetic only.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_bezier_cubic, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new(5, 5);
+/// draw_bezier_cubic(
+///     &mut grid,
+///     Pos::new(0, 4),
+///     Pos::new(0, 0),
+///     Pos::new(4, 0),
+///     Pos::new(4, 4),
+///     1,
+/// );
+///
+/// assert_eq!(grid.get(Pos::new(0, 4)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(4, 4)), Some(&1));
+/// ```
+pub fn draw_bezier_cubic<T, G>(grid: &mut G, from: Pos, ctrl_a: Pos, ctrl_b: Pos, to: Pos, value: T)
+where
+    G: GridWrite<Element = T>,
+    T: Copy,
+{
+    let n = segments(&[from, ctrl_a, ctrl_b, to]);
+    let mut last = from;
+    for i in 1..=n {
+        let m = n - i;
+        let x = weighted(
+            &[
+                (from.x, m * m * m),
+                (ctrl_a.x, 3 * m * m * i),
+                (ctrl_b.x, 3 * m * i * i),
+                (to.x, i * i * i),
+            ],
+            n * n * n,
+        );
+        let y = weighted(
+            &[
+                (from.y, m * m * m),
+                (ctrl_a.y, 3 * m * m * i),
+                (ctrl_b.y, 3 * m * i * i),
+                (to.y, i * i * i),
+            ],
+            n * n * n,
+        );
+        let next = Pos::new(x, y);
+        draw_line(grid, last, next, value);
+        last = next;
+    }
+}
+
+/// Returns the number of line segments to flatten a curve into.
+///
+/// Subdivision is proportional to the Manhattan length of the control polygon, so larger curves
+/// are split into more segments.
+fn segments(points: &[Pos]) -> usize {
+    let length: usize = points
+        .windows(2)
+        .map(|pair| pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y))
+        .sum();
+    length.clamp(1, 1024)
+}
+
+/// Returns the weighted average of `terms` (value, weight) pairs, rounded to nearest.
+fn weighted(terms: &[(usize, usize)], total: usize) -> usize {
+    let sum: usize = terms.iter().map(|&(value, weight)| value * weight).sum();
+    (sum + total / 2) / total
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{ops::GridRead as _, test::NaiveGrid};
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn draw_polyline_two_segments() {
+        let mut grid = NaiveGrid::<u8>::new(3, 3);
+        draw_polyline(
+            &mut grid,
+            &[Pos::new(0, 0), Pos::new(2, 0), Pos::new(2, 2)],
+            1,
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 1, 1,
+            0, 0, 1,
+            0, 0, 1,
+        ]);
+    }
+
+    #[test]
+    fn draw_polyline_single_point() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_polyline(&mut grid, &[Pos::new(1, 0)], 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            0, 1,
+            0, 0,
+        ]);
+    }
+
+    #[test]
+    fn draw_polyline_empty() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_polyline(&mut grid, &[], 1);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn draw_bezier_quad_hits_endpoints() {
+        let mut grid = NaiveGrid::<u8>::new(5, 5);
+        draw_bezier_quad(&mut grid, Pos::new(0, 4), Pos::new(0, 0), Pos::new(4, 0), 1);
+
+        assert_eq!(grid.get(Pos::new(0, 4)), Some(&1));
+        assert_eq!(grid.get(Pos::new(4, 0)), Some(&1));
+        // The curve bends toward the control point, away from the far corner.
+        assert_eq!(grid.get(Pos::new(4, 4)), Some(&0));
+        assert_eq!(grid.get(Pos::new(3, 4)), Some(&0));
+    }
+
+    #[test]
+    fn draw_bezier_quad_degenerate_is_a_line() {
+        let mut grid = NaiveGrid::<u8>::new(3, 3);
+        draw_bezier_quad(&mut grid, Pos::new(0, 0), Pos::new(1, 1), Pos::new(2, 2), 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 0, 0,
+            0, 1, 0,
+            0, 0, 1,
+        ]);
+    }
+
+    #[test]
+    fn draw_bezier_cubic_hits_endpoints() {
+        let mut grid = NaiveGrid::<u8>::new(5, 5);
+        draw_bezier_cubic(
+            &mut grid,
+            Pos::new(0, 4),
+            Pos::new(0, 0),
+            Pos::new(4, 0),
+            Pos::new(4, 4),
+            1,
+        );
+
+        assert_eq!(grid.get(Pos::new(0, 4)), Some(&1));
+        assert_eq!(grid.get(Pos::new(4, 4)), Some(&1));
+    }
+
+    #[test]
+    fn draw_bezier_clips_out_of_bounds() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_bezier_quad(&mut grid, Pos::new(0, 0), Pos::new(4, 0), Pos::new(4, 4), 1);
+
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+    }
+}
@@ -1,8 +1,48 @@
 use crate::{
     core::{Pos, Rect},
-    ops::{ExactSizeGrid, GridRead, layout::Traversal as _},
+    ops::{
+        ExactSizeGrid, GridRead,
+        layout::{RowMajor, Traversal as _},
+    },
 };
 
+/// Returns an iterator over positions where two grids differ, yielding both elements.
+///
+/// Unlike [`GridDiff::diff`], the grids may be unrelated types, and each difference carries the
+/// element from both sides. Positions are visited in row-major order over the intersection of
+/// the two grids, so the grids should be the same size. For `RowMajor` buffer-backed grids,
+/// `GridBuf::diff_rows` skips unchanged rows with a single slice comparison each.
+///
+/// # Examples
+///
+/// ```rust
+/// use grixy::prelude::*;
+/// use grixy::ops::diff;
+///
+/// let a = GridBuf::new_filled(3, 3, 0u8);
+/// let mut b = GridBuf::new_filled(3, 3, 0u8);
+/// b[Pos::new(1, 1)] = 42;
+///
+/// let changed: Vec<_> = diff(&a, &b).collect();
+/// assert_eq!(changed, [(Pos::new(1, 1), &0u8, &42u8)]);
+/// ```
+pub fn diff<'a, A, B>(
+    a: &'a A,
+    b: &'a B,
+) -> impl Iterator<Item = (Pos, A::Element<'a>, B::Element<'a>)> + 'a
+where
+    A: GridRead + ExactSizeGrid,
+    B: GridRead + ExactSizeGrid,
+    A::Element<'a>: PartialEq<B::Element<'a>>,
+{
+    let bounds = Rect::from_ltwh(0, 0, a.width().min(b.width()), a.height().min(b.height()));
+    RowMajor::iter_pos(bounds).filter_map(move |pos| {
+        let x = a.get(pos)?;
+        let y = b.get(pos)?;
+        (x != y).then_some((pos, x, y))
+    })
+}
+
 /// Extension trait for comparing two grids.
 ///
 /// Automatically implemented for all types that implement [`GridRead`] and [`ExactSizeGrid`].
@@ -101,4 +141,36 @@ mod tests {
         let changed: Vec<_> = a.diff(&b).collect();
         assert_eq!(changed.len(), 4);
     }
+
+    #[test]
+    fn diff_fn_yields_both_elements() {
+        let a = GridBuf::new_filled(3, 3, 0u8);
+        let mut b = GridBuf::new_filled(3, 3, 0u8);
+        b[Pos::new(2, 0)] = 7;
+        b[Pos::new(0, 2)] = 9;
+
+        let changed: Vec<_> = super::diff(&a, &b).collect();
+        assert_eq!(
+            changed,
+            [(Pos::new(2, 0), &0u8, &7u8), (Pos::new(0, 2), &0u8, &9u8)]
+        );
+    }
+
+    #[test]
+    fn diff_fn_compares_unrelated_grid_types() {
+        let buf = GridBuf::new_filled(2, 2, 1u8);
+        let naive = crate::test::NaiveGrid::with_cells(2, 2, alloc::vec![1u8, 1, 2, 1]);
+
+        let changed: Vec<_> = super::diff(&buf, &naive).collect();
+        assert_eq!(changed, [(Pos::new(0, 1), &1u8, &2u8)]);
+    }
+
+    #[test]
+    fn diff_fn_covers_the_intersection_of_sizes() {
+        let a = GridBuf::new_filled(2, 3, 0u8);
+        let b = GridBuf::new_filled(3, 2, 1u8);
+
+        let changed: Vec<_> = super::diff(&a, &b).collect();
+        assert_eq!(changed.len(), 4);
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     core::{Pos, Rect},
-    ops::{GridRead, GridWrite},
+    ops::{ExactSizeGrid, GridRead, GridWrite, write::SMALL_RECT_MAX},
 };
 
 /// Copies a rectangular region from a source grid to a destination grid.
@@ -29,12 +29,185 @@ pub fn copy_rect<'a, E>(
     from: Rect,
     to: Pos,
 ) {
+    // Glyph-sized copies go cell by cell: building the layout position iterators costs more
+    // than the handful of bounds-checked accesses they would save.
+    if from.width() <= SMALL_RECT_MAX && from.height() <= SMALL_RECT_MAX {
+        for y in 0..from.height() {
+            for x in 0..from.width() {
+                let offset = Pos::new(x, y);
+                let Some(value) = src.get(from.top_left() + offset) else {
+                    continue;
+                };
+                let _ = dst.set(to + offset, value);
+            }
+        }
+        return;
+    }
     dst.fill_rect_iter(
         Rect::from_ltwh(to.x, to.y, from.width(), from.height()),
         src.iter_rect(from),
     );
 }
 
+/// Copies a rectangular region from a source grid to a destination grid through a blend function.
+///
+/// Each copied element is combined with the current destination element via `blend` before being
+/// written, making this the blended counterpart of [`copy_rect`]. Both rectangles are intersected
+/// with their grids up front, so the per-cell accesses in the copy loop always succeed; cells
+/// outside either grid are clipped.
+///
+/// For `u32` premultiplied-RGBA grids, the scalar kernels in [`blend`](crate::ops::blend) fit
+/// the `blend` parameter directly, and their slice variants pair with `GridBuf::blit_rect_from`
+/// (behind the `simd` feature) to composite whole rows at a time.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, transform::GridConvertExt as _, ops::{blit_rect, GridRead}, buf::GridBuf};
+///
+/// let src = GridBuf::new_filled(2, 2, 1);
+/// let mut dst = GridBuf::new_filled(3, 3, 2);
+/// blit_rect(
+///     &src.copied(),
+///     &mut dst,
+///     Rect::from_ltwh(0, 0, 2, 2),
+///     Pos::new(1, 1),
+///     |current, new| current + new,
+/// );
+///
+/// assert_eq!(dst.get(Pos::new(1, 1)), Some(&3));
+/// assert_eq!(dst.get(Pos::new(0, 0)), Some(&2));
+/// ```
+pub fn blit_rect<'a, E, G>(
+    src: &'a impl GridRead<Element<'a> = E>,
+    dst: &mut G,
+    from: Rect,
+    to: Pos,
+    blend: impl Fn(<G as GridRead>::Element<'_>, E) -> <G as GridWrite>::Element,
+) where
+    G: GridRead + GridWrite,
+{
+    let from = src.trim_rect(from);
+    let dst_rect = dst.trim_rect(Rect::from_ltwh(to.x, to.y, from.width(), from.height()));
+    let width = from.width().min(dst_rect.width());
+    let height = from.height().min(dst_rect.height());
+    for y in 0..height {
+        for x in 0..width {
+            let offset = Pos::new(x, y);
+            let Some(value) = src.get(from.top_left() + offset) else {
+                continue;
+            };
+            let Some(current) = dst.get(to + offset) else {
+                continue;
+            };
+            let value = blend(current, value);
+            let _ = dst.set(to + offset, value);
+        }
+    }
+}
+
+/// Strokes evenly spaced horizontal and vertical lines across the entire grid.
+///
+/// Lines are drawn at every multiple of `cell_w` (vertical) and `cell_h` (horizontal), starting
+/// at the top-left corner, which is useful for debug overlays and tile editors. Horizontal lines
+/// are drawn with row-aligned [`fill_rect_solid`][] calls, so linear buffers take the aligned
+/// slice path. A `cell_w` or `cell_h` of `0` draws nothing.
+///
+/// [`fill_rect_solid`]: GridWrite::fill_rect_solid
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_grid_lines, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new(5, 5);
+/// draw_grid_lines(&mut grid, 2, 2, 1);
+///
+/// assert_eq!(grid.get(Pos::new(0, 1)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(1, 0)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(1, 1)), Some(&0));
+/// ```
+pub fn draw_grid_lines<T, G>(dst: &mut G, cell_w: usize, cell_h: usize, value: T)
+where
+    G: GridWrite<Element = T> + ExactSizeGrid,
+    T: Copy,
+{
+    if cell_w == 0 || cell_h == 0 {
+        return;
+    }
+    for y in (0..dst.height()).step_by(cell_h) {
+        dst.fill_rect_solid(Rect::from_ltwh(0, y, dst.width(), 1), value);
+    }
+    for x in (0..dst.width()).step_by(cell_w) {
+        dst.fill_rect_solid(Rect::from_ltwh(x, 0, 1, dst.height()), value);
+    }
+}
+
+/// Copies a full row from a source grid to a row of a destination grid.
+///
+/// This is shorthand for [`copy_rect`] with a one-row rectangle. Cells that do not fit in the
+/// destination row are ignored, as with [`copy_rect`]. For linear `RowMajor` buffers, both sides
+/// of the copy take the aligned slice path, so whole rows are moved with slice copies.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, transform::GridConvertExt as _, ops::{copy_row, GridRead}, buf::GridBuf};
+///
+/// let src = GridBuf::new_filled(3, 3, 1);
+/// let mut dst = GridBuf::new(3, 3);
+/// copy_row(&src.copied(), 0, &mut dst, 2);
+///
+/// assert_eq!(dst.get(Pos::new(0, 2)), Some(&1));
+/// assert_eq!(dst.get(Pos::new(0, 0)), Some(&0));
+/// ```
+#[inline]
+pub fn copy_row<'a, E>(
+    src: &'a (impl GridRead<Element<'a> = E> + ExactSizeGrid),
+    src_y: usize,
+    dst: &mut impl GridWrite<Element = E>,
+    dst_y: usize,
+) {
+    copy_rect(
+        src,
+        dst,
+        Rect::from_ltwh(0, src_y, src.width(), 1),
+        Pos::new(0, dst_y),
+    );
+}
+
+/// Copies a full column from a source grid to a column of a destination grid.
+///
+/// This is shorthand for [`copy_rect`] with a one-column rectangle. Cells that do not fit in the
+/// destination column are ignored, as with [`copy_rect`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, transform::GridConvertExt as _, ops::{copy_col, GridRead}, buf::GridBuf};
+///
+/// let src = GridBuf::new_filled(3, 3, 1);
+/// let mut dst = GridBuf::new(3, 3);
+/// copy_col(&src.copied(), 0, &mut dst, 2);
+///
+/// assert_eq!(dst.get(Pos::new(2, 0)), Some(&1));
+/// assert_eq!(dst.get(Pos::new(0, 0)), Some(&0));
+/// ```
+#[inline]
+pub fn copy_col<'a, E>(
+    src: &'a (impl GridRead<Element<'a> = E> + ExactSizeGrid),
+    src_x: usize,
+    dst: &mut impl GridWrite<Element = E>,
+    dst_x: usize,
+) {
+    copy_rect(
+        src,
+        dst,
+        Rect::from_ltwh(src_x, 0, 1, src.height()),
+        Pos::new(dst_x, 0),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -100,6 +273,128 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn copy_rect_larger_than_small_path() {
+        let src = NaiveGrid::<i32>::with_cells(9, 9, [1; 81]);
+
+        let mut dst = NaiveGrid::<i32>::new(9, 9);
+        copy_rect(
+            &src.copied(),
+            &mut dst,
+            Rect::from_ltwh(0, 0, 9, 9),
+            Pos::new(0, 0),
+        );
+
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[1; 81]);
+    }
+
+    #[test]
+    fn blit_rect_blends_with_destination() {
+        #[rustfmt::skip]
+        let src = NaiveGrid::<i32>::with_cells(2, 2, [
+            1, 2,
+            3, 4,
+        ]);
+
+        let mut dst = NaiveGrid::<i32>::with_cells(3, 3, [10; 9]);
+        blit_rect(
+            &src.copied(),
+            &mut dst,
+            Rect::from_ltwh(0, 0, 2, 2),
+            Pos::new(1, 1),
+            |current, new| current + new,
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            10, 10, 10,
+            10, 11, 12,
+            10, 13, 14,
+        ]);
+    }
+
+    #[test]
+    fn blit_rect_clips_out_of_bounds() {
+        let src = NaiveGrid::<i32>::with_cells(2, 2, [1, 2, 3, 4]);
+
+        let mut dst = NaiveGrid::<i32>::new(3, 3);
+        blit_rect(
+            &src.copied(),
+            &mut dst,
+            Rect::from_ltwh(0, 0, 2, 2),
+            Pos::new(2, 2),
+            |_, new| new,
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            0, 0, 0,
+            0, 0, 0,
+            0, 0, 1,
+        ]);
+    }
+
+    #[test]
+    fn draw_grid_lines_overlay() {
+        let mut grid = NaiveGrid::<u8>::new(5, 5);
+        draw_grid_lines(&mut grid, 2, 2, 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 1, 1, 1, 1,
+            1, 0, 1, 0, 1,
+            1, 1, 1, 1, 1,
+            1, 0, 1, 0, 1,
+            1, 1, 1, 1, 1,
+        ]);
+    }
+
+    #[test]
+    fn draw_grid_lines_zero_cell_size_draws_nothing() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_grid_lines(&mut grid, 0, 2, 1);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn copy_row_between_grids() {
+        #[rustfmt::skip]
+        let src = NaiveGrid::<i32>::with_cells(3, 2, [
+            1, 2, 3,
+            4, 5, 6,
+        ]);
+
+        let mut dst = NaiveGrid::<i32>::new(3, 2);
+        copy_row(&src.copied(), 1, &mut dst, 0);
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            4, 5, 6,
+            0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn copy_col_between_grids() {
+        #[rustfmt::skip]
+        let src = NaiveGrid::<i32>::with_cells(2, 3, [
+            1, 2,
+            3, 4,
+            5, 6,
+        ]);
+
+        let mut dst = NaiveGrid::<i32>::new(2, 3);
+        copy_col(&src.copied(), 0, &mut dst, 1);
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            0, 1,
+            0, 3,
+            0, 5,
+        ]);
+    }
+
     #[test]
     fn copy_rect_completely_outof_bounds() {
         #[rustfmt::skip]
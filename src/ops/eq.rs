@@ -0,0 +1,81 @@
+use crate::{
+    core::Rect,
+    ops::{
+        ExactSizeGrid, GridRead,
+        layout::{RowMajor, Traversal as _},
+    },
+};
+
+/// Returns whether two grids have the same dimensions and elementwise-equal contents.
+///
+/// Unlike the `PartialEq` implementations on [`GridBuf`](crate::buf::GridBuf), this compares
+/// grids of unrelated types: positions are visited in row-major order and read through
+/// [`GridRead::get`], so the grids' own layouts do not need to match.
+///
+/// # Examples
+///
+/// ```rust
+/// use grixy::prelude::*;
+/// use grixy::ops::grids_equal;
+///
+/// let a = GridBuf::new_filled(3, 3, 7u8);
+/// let mut b = GridBuf::new_filled(3, 3, 7u8);
+/// assert!(grids_equal(&a, &b));
+///
+/// b.set(Pos::new(1, 1), 0).unwrap();
+/// assert!(!grids_equal(&a, &b));
+/// ```
+pub fn grids_equal<'a, A, B>(a: &'a A, b: &'a B) -> bool
+where
+    A: GridRead + ExactSizeGrid,
+    B: GridRead + ExactSizeGrid,
+    A::Element<'a>: PartialEq<B::Element<'a>>,
+{
+    if a.width() != b.width() || a.height() != b.height() {
+        return false;
+    }
+    let bounds = Rect::from_ltwh(0, 0, a.width(), a.height());
+    RowMajor::iter_pos(bounds).all(|pos| match (a.get(pos), b.get(pos)) {
+        (Some(x), Some(y)) => x == y,
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{buf::GridBuf, ops::layout::RowMajor, test::NaiveGrid};
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn equal_grids_of_different_types() {
+        let buf = GridBuf::<_, _, RowMajor>::from_buffer(vec![1u8, 2, 3, 4], 2);
+        let naive = NaiveGrid::with_cells(2, 2, vec![1u8, 2, 3, 4]);
+        assert!(grids_equal(&buf, &naive));
+    }
+
+    #[test]
+    fn differing_contents_are_unequal() {
+        let buf = GridBuf::<_, _, RowMajor>::from_buffer(vec![1u8, 2, 3, 4], 2);
+        let naive = NaiveGrid::with_cells(2, 2, vec![1u8, 2, 9, 4]);
+        assert!(!grids_equal(&buf, &naive));
+    }
+
+    #[test]
+    fn differing_dimensions_are_unequal() {
+        let wide = GridBuf::<_, _, RowMajor>::from_buffer(vec![1u8, 2, 3, 4], 4);
+        let tall = GridBuf::<_, _, RowMajor>::from_buffer(vec![1u8, 2, 3, 4], 1);
+        assert!(!grids_equal(&wide, &tall));
+    }
+
+    #[test]
+    fn empty_grids_are_equal() {
+        let a = NaiveGrid::<u8>::new(0, 0);
+        let b = NaiveGrid::<u8>::new(0, 0);
+        assert!(grids_equal(&a, &b));
+        assert!(!grids_equal(&a, &NaiveGrid::<u8>::new(2, 2)));
+    }
+}
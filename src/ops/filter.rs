@@ -0,0 +1,166 @@
+use crate::{
+    core::Pos,
+    ops::{ExactSizeGrid, GridRead, GridWrite},
+};
+
+/// How [`filter`] samples cells that fall outside the source grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeMode {
+    /// Out-of-bounds samples use the nearest edge cell.
+    Clamp,
+    /// Out-of-bounds samples wrap around to the opposite edge.
+    Wrap,
+    /// Out-of-bounds samples are `0.0`.
+    Zero,
+}
+
+/// Convolves a source grid with a `K`x`K` kernel, writing the result to a destination grid.
+///
+/// Every destination cell receives the weighted sum of the source cells under the kernel
+/// centered on it; samples outside the source are resolved by `edge`. The convolution is eager,
+/// writing each cell exactly once, which suits bulk blurs better than composing lazy adapters.
+/// Writes outside `dst` are ignored, so a smaller destination clips.
+///
+/// ## Panics
+///
+/// Panics if `K` is even, since the kernel then has no center cell.
+///
+/// ## Examples
+///
+/// A 3x3 box blur:
+///
+/// ```rust
+/// use grixy::{
+///     buf::GridBuf,
+///     core::Pos,
+///     ops::{EdgeMode, GridRead as _, GridWrite as _, filter},
+///     transform::GridConvertExt as _,
+/// };
+///
+/// let mut src = GridBuf::new_filled(3, 3, 0.0f32);
+/// src.set(Pos::new(1, 1), 9.0).unwrap();
+/// let mut dst = GridBuf::new_filled(3, 3, 0.0f32);
+///
+/// filter(&src.copied(), &[[1.0 / 9.0; 3]; 3], &mut dst, EdgeMode::Zero);
+/// assert!((dst.get(Pos::new(0, 0)).unwrap() - 1.0).abs() < 1e-6);
+/// ```
+pub fn filter<const K: usize, S, D>(src: &S, kernel: &[[f32; K]; K], dst: &mut D, edge: EdgeMode)
+where
+    S: GridRead + ExactSizeGrid,
+    for<'a> S::Element<'a>: Into<f32>,
+    D: GridWrite<Element = f32>,
+{
+    assert!(K % 2 == 1, "Kernel size must be odd");
+    let (width, height) = (src.width(), src.height());
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    let radius = (K / 2) as isize;
+    let resolve = |coord: isize, len: usize| -> Option<usize> {
+        #[allow(clippy::cast_possible_wrap)]
+        let len_wrap = len as isize;
+        #[allow(clippy::cast_sign_loss)]
+        match edge {
+            EdgeMode::Clamp => Some(coord.clamp(0, len_wrap - 1) as usize),
+            EdgeMode::Wrap => Some(coord.rem_euclid(len_wrap) as usize),
+            EdgeMode::Zero => (0..len_wrap).contains(&coord).then_some(coord as usize),
+        }
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut acc = 0.0;
+            for (ky, row) in kernel.iter().enumerate() {
+                for (kx, &weight) in row.iter().enumerate() {
+                    #[allow(clippy::cast_possible_wrap)]
+                    let sx = x as isize + kx as isize - radius;
+                    #[allow(clippy::cast_possible_wrap)]
+                    let sy = y as isize + ky as isize - radius;
+                    let sample = resolve(sx, width)
+                        .zip(resolve(sy, height))
+                        .and_then(|(sx, sy)| src.get(Pos::new(sx, sy)))
+                        .map_or(0.0, Into::into);
+                    acc += weight * sample;
+                }
+            }
+            let _ = dst.set(Pos::new(x, y), acc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{test::NaiveGrid, transform::GridConvertExt as _};
+    use alloc::vec;
+
+    use super::*;
+
+    const IDENTITY: [[f32; 3]; 3] = [[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]];
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn identity_kernel_copies() {
+        #[rustfmt::skip]
+        let src = NaiveGrid::<u8>::with_cells(2, 2, vec![
+            1, 2,
+            3, 4,
+        ]);
+        let mut dst = NaiveGrid::<f32>::new(2, 2);
+
+        filter(&src.copied(), &IDENTITY, &mut dst, EdgeMode::Zero);
+        let cells: alloc::vec::Vec<f32> = dst.into_iter().collect();
+        assert!(
+            cells
+                .iter()
+                .zip([1.0, 2.0, 3.0, 4.0])
+                .all(|(&a, b)| close(a, b))
+        );
+    }
+
+    #[test]
+    fn box_blur_spreads_impulse() {
+        let mut src = NaiveGrid::<u8>::new(3, 3);
+        src.set(Pos::new(1, 1), 9).unwrap();
+        let mut dst = NaiveGrid::<f32>::new(3, 3);
+
+        filter(
+            &src.copied(),
+            &[[1.0 / 9.0; 3]; 3],
+            &mut dst,
+            EdgeMode::Zero,
+        );
+        assert!(dst.into_iter().all(|cell| close(cell, 1.0)));
+    }
+
+    #[test]
+    fn clamp_repeats_edge_cells() {
+        let src = NaiveGrid::<u8>::with_cells(2, 1, vec![6, 0]);
+        let mut dst = NaiveGrid::<f32>::new(2, 1);
+
+        // A kernel that samples only the cell to the left.
+        let shifted = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
+        filter(&src.copied(), &shifted, &mut dst, EdgeMode::Clamp);
+        let cells: alloc::vec::Vec<f32> = dst.into_iter().collect();
+        assert!(close(cells[0], 6.0)); // Clamped to itself.
+        assert!(close(cells[1], 6.0));
+    }
+
+    #[test]
+    fn wrap_samples_opposite_edge() {
+        let src = NaiveGrid::<u8>::with_cells(2, 1, vec![6, 2]);
+        let mut dst = NaiveGrid::<f32>::new(2, 1);
+
+        let shifted = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
+        filter(&src.copied(), &shifted, &mut dst, EdgeMode::Wrap);
+        let cells: alloc::vec::Vec<f32> = dst.into_iter().collect();
+        assert!(close(cells[0], 2.0)); // Wraps to the right edge.
+        assert!(close(cells[1], 6.0));
+    }
+}
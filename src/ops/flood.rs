@@ -0,0 +1,122 @@
+use crate::{core::Pos, ops::GridRead};
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("The `alloc` feature must be enabled to use this module.");
+
+extern crate alloc;
+
+use alloc::collections::{BTreeSet, VecDeque};
+
+/// The 4-connected neighborhood, in row-major order.
+const DIRS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Enumerates the 4-connected region containing `seed` without modifying the grid.
+///
+/// The region is the set of cells for which `predicate` returns `true` that are reachable from
+/// `seed` through other such cells. Positions are yielded in breadth-first order, starting with
+/// `seed`; if `seed` is out of bounds or does not match the predicate, nothing is yielded. The
+/// traversal is lazy, so abandoning the iterator early abandons the remaining flood.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{GridWrite as _, flood_region}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new_filled(3, 1, 0u8);
+/// grid.set(Pos::new(1, 0), 1).unwrap();
+///
+/// let region: Vec<_> = flood_region(&grid, Pos::new(0, 0), |&cell| cell == 0).collect();
+/// assert_eq!(region, [Pos::new(0, 0)]);
+/// ```
+pub fn flood_region<'a, G>(
+    grid: &'a G,
+    seed: Pos,
+    predicate: impl Fn(G::Element<'a>) -> bool + 'a,
+) -> impl Iterator<Item = Pos> + 'a
+where
+    G: GridRead,
+{
+    let matches = move |pos: Pos| grid.get(pos).is_some_and(&predicate);
+
+    let mut queue = VecDeque::new();
+    let mut visited = BTreeSet::new();
+    if matches(seed) {
+        queue.push_back(seed);
+        visited.insert((seed.y, seed.x));
+    }
+
+    core::iter::from_fn(move || {
+        let pos = queue.pop_front()?;
+        for (dx, dy) in DIRS {
+            let Some(x) = pos.x.checked_add_signed(dx) else {
+                continue;
+            };
+            let Some(y) = pos.y.checked_add_signed(dy) else {
+                continue;
+            };
+            let next = Pos::new(x, y);
+            if matches(next) && visited.insert((y, x)) {
+                queue.push_back(next);
+            }
+        }
+        Some(pos)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{core::Rect, ops::GridWrite as _, test::NaiveGrid};
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn floods_connected_cells_only() {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 3, vec![
+            0, 1, 0,
+            0, 1, 0,
+            1, 1, 0,
+        ]);
+
+        let region: Vec<_> = flood_region(&grid, Pos::new(0, 0), |&cell| cell == 0).collect();
+        assert_eq!(region, [Pos::new(0, 0), Pos::new(0, 1)]);
+    }
+
+    #[test]
+    fn starts_at_seed_in_breadth_first_order() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, 2, 2), 1);
+
+        let region: Vec<_> = flood_region(&grid, Pos::new(0, 0), |&cell| cell == 1).collect();
+        assert_eq!(
+            region,
+            [
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(0, 1),
+                Pos::new(1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn seed_not_matching_yields_nothing() {
+        let grid = NaiveGrid::<u8>::new(2, 2);
+        assert_eq!(
+            flood_region(&grid, Pos::new(0, 0), |&cell| cell == 1).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn seed_out_of_bounds_yields_nothing() {
+        let grid = NaiveGrid::<u8>::new(2, 2);
+        assert_eq!(
+            flood_region(&grid, Pos::new(5, 5), |&cell| cell == 0).count(),
+            0
+        );
+    }
+}
@@ -1,3 +1,16 @@
 //! Defines how to traverse and optionally, store grids in linear memory.
 
 pub use ixy::layout::*;
+
+/// Returns the stride that [`Linear::pos_to_index`] expects for a `width × height` grid.
+///
+/// Linear layouts index along their major runs: [`RowMajor`] strides by the grid's width, while
+/// [`ColumnMajor`] strides by its height. [`Linear::len_aligned`] reports the number of those
+/// runs, so dividing it out of the grid's area recovers the run length for any layout.
+#[cfg(feature = "buffer")]
+pub(crate) fn stride<L: Linear>(width: usize, height: usize) -> usize {
+    match L::len_aligned(ixy::Size::new(width, height)) {
+        0 => 0,
+        runs => width * height / runs,
+    }
+}
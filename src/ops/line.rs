@@ -0,0 +1,527 @@
+use crate::{
+    core::Pos,
+    ops::{GridRead, GridWrite},
+};
+
+/// Draws a straight line between two positions using Bresenham's algorithm.
+///
+/// Every cell on the line is set to `value`. Cells that fall outside the grid are ignored, as
+/// with [`GridWrite::set`]; the rasterized line never leaves the bounding box of its endpoints.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_line, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new(3, 3);
+/// draw_line(&mut grid, Pos::new(0, 0), Pos::new(2, 2), 1);
+///
+/// assert_eq!(grid.get(Pos::new(1, 1)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(2, 1)), Some(&0));
+/// ```
+pub fn draw_line<T, G>(grid: &mut G, from: Pos, to: Pos, value: T)
+where
+    G: GridWrite<Element = T>,
+    T: Copy,
+{
+    line_iter(from, to).for_each(|pos| {
+        let _ = grid.set(pos, value);
+    });
+}
+
+/// Returns an iterator over the positions of a line traced with Bresenham's algorithm.
+///
+/// Exactly one cell is visited per column (or row, for steep lines), which matches the raster
+/// produced by [`draw_line`]. The iterator is independent of any grid, so it can also back
+/// line-of-sight checks; positions never leave the bounding box of the endpoints. For every cell
+/// the ideal segment passes through, see [`line_iter_supercover`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::line_iter};
+///
+/// let positions: Vec<_> = line_iter(Pos::new(0, 0), Pos::new(2, 2)).collect();
+/// assert_eq!(positions, [Pos::new(0, 0), Pos::new(1, 1), Pos::new(2, 2)]);
+/// ```
+pub fn line_iter(from: Pos, to: Pos) -> impl Iterator<Item = Pos> {
+    #[allow(clippy::cast_possible_wrap)]
+    let (mut x0, mut y0) = (from.x as isize, from.y as isize);
+    #[allow(clippy::cast_possible_wrap)]
+    let (x1, y1) = (to.x as isize, to.y as isize);
+
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    let mut done = false;
+
+    core::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        #[allow(clippy::cast_sign_loss)]
+        let pos = Pos::new(x0 as usize, y0 as usize);
+        if x0 == x1 && y0 == y1 {
+            done = true;
+        } else {
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y0 += sy;
+            }
+        }
+        Some(pos)
+    })
+}
+
+/// Returns an iterator over every cell a line segment passes through (a supercover line).
+///
+/// Unlike [`line_iter`], which steps diagonally, the supercover variant steps one axis at a time
+/// and so yields a 4-connected path covering each cell the ideal segment touches; when the
+/// segment passes exactly through a cell corner, the horizontal neighbor is taken. The iterator
+/// is independent of any grid; positions never leave the bounding box of the endpoints.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::line_iter_supercover};
+///
+/// let positions: Vec<_> = line_iter_supercover(Pos::new(0, 0), Pos::new(2, 1)).collect();
+/// assert_eq!(positions.len(), 4);
+/// assert_eq!(positions[0], Pos::new(0, 0));
+/// assert_eq!(positions[3], Pos::new(2, 1));
+/// ```
+pub fn line_iter_supercover(from: Pos, to: Pos) -> impl Iterator<Item = Pos> {
+    #[allow(clippy::cast_possible_wrap)]
+    let (mut x, mut y) = (from.x as isize, from.y as isize);
+    #[allow(clippy::cast_possible_wrap)]
+    let (x1, y1) = (to.x as isize, to.y as isize);
+
+    let dx = (x1 - x).abs();
+    let dy = (y1 - y).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut err = dx - dy;
+    let mut remaining = 1 + dx + dy;
+
+    core::iter::from_fn(move || {
+        if remaining == 0 {
+            return None;
+        }
+        remaining -= 1;
+        #[allow(clippy::cast_sign_loss)]
+        let pos = Pos::new(x as usize, y as usize);
+        if err > 0 || (err == 0 && x != x1) {
+            x += sx;
+            err -= 2 * dy;
+        } else {
+            y += sy;
+            err += 2 * dx;
+        }
+        Some(pos)
+    })
+}
+
+/// Draws a straight line between two positions with a configurable width in cells.
+///
+/// The line is traced with Bresenham's algorithm and a filled disc of diameter `width` is
+/// stamped at each traced cell, so the stroke has rounded caps and joins. A `width` of `1` is
+/// equivalent to [`draw_line`], and a `width` of `0` draws nothing. Cells that fall outside the
+/// grid are ignored.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_line_thick, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new(5, 5);
+/// draw_line_thick(&mut grid, Pos::new(0, 2), Pos::new(4, 2), 3, 1);
+///
+/// assert_eq!(grid.get(Pos::new(2, 1)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(2, 2)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(2, 3)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(2, 0)), Some(&0));
+/// ```
+pub fn draw_line_thick<T, G>(grid: &mut G, from: Pos, to: Pos, width: usize, value: T)
+where
+    G: GridWrite<Element = T>,
+    T: Copy,
+{
+    if width == 0 {
+        return;
+    }
+    #[allow(clippy::cast_possible_wrap)]
+    let radius = (width / 2) as isize;
+    line_iter(from, to).for_each(|pos| {
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                #[allow(clippy::cast_sign_loss)]
+                if 4 * (dx * dx + dy * dy) as usize > width * width {
+                    continue;
+                }
+                #[allow(clippy::cast_possible_wrap)]
+                let (x, y) = (pos.x as isize + dx, pos.y as isize + dy);
+                if x < 0 || y < 0 {
+                    continue;
+                }
+                #[allow(clippy::cast_sign_loss)]
+                let _ = grid.set(Pos::new(x as usize, y as usize), value);
+            }
+        }
+    });
+}
+
+/// Draws an anti-aliased line between two positions using Xiaolin Wu's algorithm.
+///
+/// For each column (or row, for steep lines) of the line, the two cells nearest the ideal line
+/// are written through `blend` with complementary coverage values in `0..=255`; cells with zero
+/// coverage are left untouched. Coverage is computed with 16.16 fixed-point arithmetic, so no
+/// floating-point support is required. Cells that fall outside the grid are ignored.
+///
+/// For hard-edged lines, use [`draw_line`] instead.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{draw_line_aa, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::<u8, _, _>::new(4, 2);
+/// draw_line_aa(&mut grid, Pos::new(0, 0), Pos::new(3, 1), |_, cover| cover);
+///
+/// assert_eq!(grid.get(Pos::new(0, 0)), Some(&255));
+/// assert_eq!(grid.get(Pos::new(3, 1)), Some(&255));
+/// ```
+pub fn draw_line_aa<G>(
+    grid: &mut G,
+    from: Pos,
+    to: Pos,
+    blend: impl Fn(<G as GridRead>::Element<'_>, u8) -> <G as GridWrite>::Element,
+) where
+    G: GridRead + GridWrite,
+{
+    let mut plot = |x: i64, y: i64, cover: u8, steep: bool| {
+        if cover == 0 || x < 0 || y < 0 {
+            return;
+        }
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let pos = if steep {
+            Pos::new(y as usize, x as usize)
+        } else {
+            Pos::new(x as usize, y as usize)
+        };
+        let Some(current) = grid.get(pos) else {
+            return;
+        };
+        let value = blend(current, cover);
+        let _ = grid.set(pos, value);
+    };
+
+    #[allow(clippy::cast_possible_wrap)]
+    let (mut x0, mut y0, mut x1, mut y1) = (from.x as i64, from.y as i64, to.x as i64, to.y as i64);
+
+    let steep = (y1 - y0).abs() > (x1 - x0).abs();
+    if steep {
+        core::mem::swap(&mut x0, &mut y0);
+        core::mem::swap(&mut x1, &mut y1);
+    }
+    if x0 > x1 {
+        core::mem::swap(&mut x0, &mut x1);
+        core::mem::swap(&mut y0, &mut y1);
+    }
+
+    let dx = x1 - x0;
+    if dx == 0 {
+        plot(x0, y0, 255, steep);
+        return;
+    }
+
+    let gradient = ((y1 - y0) << 16) / dx;
+    let mut acc = y0 << 16;
+    for x in x0..=x1 {
+        let y = acc >> 16;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let frac = ((acc >> 8) & 0xFF) as u8;
+        plot(x, y, 255 - frac, steep);
+        plot(x, y + 1, frac, steep);
+        acc += gradient;
+    }
+}
+
+/// Returns whether a straight line between two positions is unobstructed.
+///
+/// The line is traced with [`line_iter_supercover`], so every cell the ideal segment passes
+/// through is tested with `blocks`; cells outside the grid also block. The endpoints themselves
+/// are exempt, so a blocking target is still considered visible, and a cell can always see
+/// itself.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{GridWrite as _, line_of_sight}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new_filled(3, 1, 0u8);
+/// grid.set(Pos::new(1, 0), 1).unwrap();
+///
+/// assert!(!line_of_sight(&grid, Pos::new(0, 0), Pos::new(2, 0), |&cell| cell == 1));
+/// assert!(line_of_sight(&grid, Pos::new(0, 0), Pos::new(1, 0), |&cell| cell == 1));
+/// ```
+pub fn line_of_sight<'a, G>(
+    grid: &'a G,
+    from: Pos,
+    to: Pos,
+    blocks: impl Fn(G::Element<'a>) -> bool,
+) -> bool
+where
+    G: GridRead,
+{
+    line_iter_supercover(from, to)
+        .filter(|&pos| pos != from && pos != to)
+        .all(|pos| grid.get(pos).is_some_and(|elem| !blocks(elem)))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::test::NaiveGrid;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn draw_line_horizontal() {
+        let mut grid = NaiveGrid::<u8>::new(3, 2);
+        draw_line(&mut grid, Pos::new(0, 1), Pos::new(2, 1), 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            0, 0, 0,
+            1, 1, 1,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_diagonal() {
+        let mut grid = NaiveGrid::<u8>::new(3, 3);
+        draw_line(&mut grid, Pos::new(2, 0), Pos::new(0, 2), 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            0, 0, 1,
+            0, 1, 0,
+            1, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_clips_out_of_bounds() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_line(&mut grid, Pos::new(0, 0), Pos::new(3, 0), 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 1,
+            0, 0,
+        ]);
+    }
+
+    #[test]
+    fn line_iter_diagonal() {
+        let positions: Vec<_> = line_iter(Pos::new(2, 2), Pos::new(0, 0)).collect();
+        assert_eq!(positions, [Pos::new(2, 2), Pos::new(1, 1), Pos::new(0, 0)]);
+    }
+
+    #[test]
+    fn line_iter_single_point() {
+        let positions: Vec<_> = line_iter(Pos::new(1, 1), Pos::new(1, 1)).collect();
+        assert_eq!(positions, [Pos::new(1, 1)]);
+    }
+
+    #[test]
+    fn line_iter_supercover_is_four_connected() {
+        let positions: Vec<_> = line_iter_supercover(Pos::new(0, 0), Pos::new(3, 1)).collect();
+        assert_eq!(
+            positions,
+            [
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(2, 0),
+                Pos::new(2, 1),
+                Pos::new(3, 1),
+            ]
+        );
+        for pair in positions.windows(2) {
+            let steps = pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y);
+            assert_eq!(steps, 1);
+        }
+    }
+
+    #[test]
+    fn line_iter_supercover_diagonal_covers_corners() {
+        let positions: Vec<_> = line_iter_supercover(Pos::new(0, 0), Pos::new(2, 2)).collect();
+        assert_eq!(positions.len(), 5);
+        assert_eq!(positions[0], Pos::new(0, 0));
+        assert_eq!(positions[4], Pos::new(2, 2));
+    }
+
+    #[test]
+    fn draw_line_thick_horizontal() {
+        let mut grid = NaiveGrid::<u8>::new(4, 4);
+        draw_line_thick(&mut grid, Pos::new(0, 1), Pos::new(3, 1), 3, 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 1, 1, 1,
+            1, 1, 1, 1,
+            1, 1, 1, 1,
+            0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_thick_width_one_matches_draw_line() {
+        let mut thick = NaiveGrid::<u8>::new(3, 3);
+        draw_line_thick(&mut thick, Pos::new(0, 0), Pos::new(2, 2), 1, 1);
+
+        let mut thin = NaiveGrid::<u8>::new(3, 3);
+        draw_line(&mut thin, Pos::new(0, 0), Pos::new(2, 2), 1);
+
+        assert_eq!(
+            thick.into_iter().collect::<Vec<_>>(),
+            thin.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn draw_line_thick_width_zero_draws_nothing() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_line_thick(&mut grid, Pos::new(0, 0), Pos::new(1, 1), 0, 1);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn draw_line_thick_clips_at_edges() {
+        let mut grid = NaiveGrid::<u8>::new(3, 3);
+        draw_line_thick(&mut grid, Pos::new(0, 0), Pos::new(0, 2), 3, 1);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 1, 0,
+            1, 1, 0,
+            1, 1, 0,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_aa_diagonal_is_full_coverage() {
+        let mut grid = NaiveGrid::<u8>::new(3, 3);
+        draw_line_aa(&mut grid, Pos::new(0, 0), Pos::new(2, 2), |_, cover| cover);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            255, 0, 0,
+            0, 255, 0,
+            0, 0, 255,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_aa_gentle_slope_splits_coverage() {
+        let mut grid = NaiveGrid::<u8>::new(4, 2);
+        draw_line_aa(&mut grid, Pos::new(0, 0), Pos::new(3, 1), |_, cover| cover);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            255, 170, 85, 0,
+            0, 85, 170, 255,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_aa_steep_slope_transposes() {
+        let mut grid = NaiveGrid::<u8>::new(2, 4);
+        draw_line_aa(&mut grid, Pos::new(0, 0), Pos::new(1, 3), |_, cover| cover);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            255, 0,
+            170, 85,
+            85, 170,
+            0, 255,
+        ]);
+    }
+
+    #[test]
+    fn draw_line_aa_single_point() {
+        let mut grid = NaiveGrid::<u8>::new(2, 2);
+        draw_line_aa(&mut grid, Pos::new(1, 1), Pos::new(1, 1), |_, cover| cover);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            0, 0,
+            0, 255,
+        ]);
+    }
+
+    #[test]
+    fn line_of_sight_clear() {
+        let grid = NaiveGrid::<u8>::new(4, 4);
+        assert!(line_of_sight(
+            &grid,
+            Pos::new(0, 0),
+            Pos::new(3, 3),
+            |&cell| cell == 1
+        ));
+    }
+
+    #[test]
+    fn line_of_sight_blocked_between() {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 3, alloc::vec![
+            0, 0, 0,
+            0, 1, 0,
+            0, 0, 0,
+        ]);
+        assert!(!line_of_sight(
+            &grid,
+            Pos::new(0, 0),
+            Pos::new(2, 2),
+            |&cell| cell == 1
+        ));
+    }
+
+    #[test]
+    fn line_of_sight_blocking_endpoints_are_visible() {
+        let grid = NaiveGrid::with_cells(2, 1, alloc::vec![1, 1]);
+        assert!(line_of_sight(
+            &grid,
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            |&cell| cell == 1
+        ));
+        assert!(line_of_sight(
+            &grid,
+            Pos::new(0, 0),
+            Pos::new(0, 0),
+            |&cell| cell == 1
+        ));
+    }
+
+    #[test]
+    fn line_of_sight_out_of_bounds_blocks() {
+        let grid = NaiveGrid::<u8>::new(2, 2);
+        assert!(!line_of_sight(
+            &grid,
+            Pos::new(0, 0),
+            Pos::new(5, 0),
+            |&cell| cell == 1
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    core::Rect,
+    ops::{GridRead, GridWrite, layout::Traversal as _},
+};
+
+/// Reads and rewrites each cell in a rectangular region in place.
+///
+/// Elements are visited in the traversal order of the grid's write layout. Out-of-bounds cells
+/// are skipped, and the bounding rectangle is treated as _exclusive_ of the right and bottom
+/// edges.
+///
+/// For linear buffers, [`GridBuf::map_rect`][] rewrites the region through the aligned mutable
+/// slice path instead of per-cell reads and writes.
+///
+/// [`GridBuf::map_rect`]: crate::buf::GridBuf::map_rect
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::{map_rect, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new_filled(3, 3, 1);
+/// map_rect(&mut grid, Rect::from_ltwh(0, 0, 2, 2), |&x| x * 2);
+///
+/// assert_eq!(grid.get(Pos::new(0, 0)), Some(&2));
+/// assert_eq!(grid.get(Pos::new(2, 2)), Some(&1));
+/// ```
+pub fn map_rect<T, G>(grid: &mut G, bounds: Rect, mut f: impl FnMut(&T) -> T)
+where
+    T: Copy,
+    G: GridWrite<Element = T> + for<'x> GridRead<Element<'x> = &'x T>,
+{
+    let bounds = grid.trim_rect(bounds);
+    for pos in <G as GridWrite>::Layout::iter_pos(bounds) {
+        let Some(&value) = grid.get(pos) else {
+            continue;
+        };
+        let _ = grid.set(pos, f(&value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::test::NaiveGrid;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn map_rect_partial_region() {
+        #[rustfmt::skip]
+        let mut grid = NaiveGrid::<i32>::with_cells(3, 3, [
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ]);
+
+        map_rect(&mut grid, Rect::from_ltwh(1, 1, 2, 2), |&x| x * 10);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            1, 2, 3,
+            4, 50, 60,
+            7, 80, 90,
+        ]);
+    }
+
+    #[test]
+    fn map_rect_trims_out_of_bounds() {
+        let mut grid = NaiveGrid::<i32>::with_cells(2, 2, [1, 2, 3, 4]);
+
+        map_rect(&mut grid, Rect::from_ltwh(1, 1, 3, 3), |&x| x + 1);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[1, 2, 3, 5]);
+    }
+}
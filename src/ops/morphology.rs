@@ -0,0 +1,362 @@
+//! Morphological operations over bit grids.
+//!
+//! Provides [`erode`], [`dilate`], and their compositions [`open`] and [`close`] for
+//! [`GridBits`], with the structuring element given as a second (small) bit grid anchored at
+//! its center cell. Rows are processed as machine words rather than per-cell, so wide masks are
+//! cleaned up a word at a time.
+//!
+//! Cells outside the grid are treated as unset: erosion shrinks regions at the borders and
+//! dilation never wraps.
+//!
+//! ## Examples
+//!
+//! Removing single-cell noise from a collision mask:
+//!
+//! ```rust
+//! use grixy::{buf::bits::GridBits, core::Pos, ops::{GridRead as _, GridWrite as _, morphology}};
+//!
+//! let mut mask = GridBits::<u8, _, _>::new(8, 8);
+//! mask.set(Pos::new(3, 3), true).unwrap(); // A lone speck.
+//!
+//! let mut cross = GridBits::<u8, _, _>::new(3, 3);
+//! for (x, y) in [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)] {
+//!     cross.set(Pos::new(x, y), true).unwrap();
+//! }
+//!
+//! let cleaned = morphology::open(&mask, &cross);
+//! assert_eq!(cleaned.get(Pos::new(3, 3)), Some(false));
+//! ```
+
+#[cfg(not(all(feature = "alloc", feature = "buffer")))]
+compile_error!("The `alloc` and `buffer` features must be enabled to use this module.");
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    buf::bits::{BitOps, GridBits},
+    core::Pos,
+    ops::{ExactSizeGrid as _, GridRead as _, layout::RowMajor},
+};
+
+const LANE: usize = usize::BITS as usize;
+
+/// Erodes a bit grid: a cell stays set only if the element fits entirely inside the region.
+///
+/// The structuring `element` is anchored at its center cell; cells outside the grid count as
+/// unset, so regions shrink at the borders.
+#[must_use]
+pub fn erode<T, B, E>(
+    src: &GridBits<T, B, RowMajor>,
+    element: &GridBits<T, E, RowMajor>,
+) -> GridBits<T, Vec<T>, RowMajor>
+where
+    T: BitOps + Default,
+    B: AsRef<[T]>,
+    E: AsRef<[T]>,
+{
+    combine(src, element, false)
+}
+
+/// Dilates a bit grid: a cell becomes set if the element placed on it touches the region.
+///
+/// The structuring `element` is anchored at its center cell; dilation never wraps around the
+/// grid edges.
+#[must_use]
+pub fn dilate<T, B, E>(
+    src: &GridBits<T, B, RowMajor>,
+    element: &GridBits<T, E, RowMajor>,
+) -> GridBits<T, Vec<T>, RowMajor>
+where
+    T: BitOps + Default,
+    B: AsRef<[T]>,
+    E: AsRef<[T]>,
+{
+    combine(src, element, true)
+}
+
+/// Opens a bit grid (erosion followed by dilation), removing features smaller than the element.
+#[must_use]
+pub fn open<T, B, E>(
+    src: &GridBits<T, B, RowMajor>,
+    element: &GridBits<T, E, RowMajor>,
+) -> GridBits<T, Vec<T>, RowMajor>
+where
+    T: BitOps + Default,
+    B: AsRef<[T]>,
+    E: AsRef<[T]>,
+{
+    dilate(&erode(src, element), element)
+}
+
+/// Closes a bit grid (dilation followed by erosion), filling holes smaller than the element.
+#[must_use]
+pub fn close<T, B, E>(
+    src: &GridBits<T, B, RowMajor>,
+    element: &GridBits<T, E, RowMajor>,
+) -> GridBits<T, Vec<T>, RowMajor>
+where
+    T: BitOps + Default,
+    B: AsRef<[T]>,
+    E: AsRef<[T]>,
+{
+    erode(&dilate(src, element), element)
+}
+
+/// Applies one morphological pass, accumulating shifted rows with `OR` (dilate) or `AND`.
+fn combine<T, B, E>(
+    src: &GridBits<T, B, RowMajor>,
+    element: &GridBits<T, E, RowMajor>,
+    dilating: bool,
+) -> GridBits<T, Vec<T>, RowMajor>
+where
+    T: BitOps + Default,
+    B: AsRef<[T]>,
+    E: AsRef<[T]>,
+{
+    let (width, height) = (src.width(), src.height());
+    let mut dst = GridBits::new(width, height);
+    if width == 0 || height == 0 {
+        return dst;
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    let offsets: Vec<(isize, isize)> = {
+        let (ax, ay) = (element.width() / 2, element.height() / 2);
+        let mut offsets = Vec::new();
+        for y in 0..element.height() {
+            for x in 0..element.width() {
+                if element.get(Pos::new(x, y)) == Some(true) {
+                    offsets.push((x as isize - ax as isize, y as isize - ay as isize));
+                }
+            }
+        }
+        offsets
+    };
+
+    let lanes = width.div_ceil(LANE);
+    let tail_mask = lane_mask(width - (lanes - 1) * LANE);
+    let mut row = vec![0usize; lanes];
+    let mut shifted = vec![0usize; lanes];
+    let mut acc = vec![0usize; lanes];
+
+    for y in 0..height {
+        if dilating {
+            acc.fill(0);
+        } else {
+            acc.fill(usize::MAX);
+        }
+        for &(dx, dy) in &offsets {
+            // Dilation pulls from the opposite offset of erosion's probe.
+            let (dx, dy) = if dilating { (-dx, -dy) } else { (dx, dy) };
+            #[allow(clippy::cast_possible_wrap)]
+            let sy = y as isize + dy;
+            #[allow(clippy::cast_sign_loss)]
+            if sy < 0 || sy as usize >= height {
+                row.fill(0);
+            } else {
+                #[allow(clippy::cast_sign_loss)]
+                load_row(src.as_ref(), width, sy as usize, &mut row);
+            }
+            shift_lanes(&row, &mut shifted, -dx);
+            if dilating {
+                for (acc, lane) in acc.iter_mut().zip(&shifted) {
+                    *acc |= lane;
+                }
+            } else {
+                for (acc, lane) in acc.iter_mut().zip(&shifted) {
+                    *acc &= lane;
+                }
+            }
+        }
+        acc[lanes - 1] &= tail_mask;
+        store_row(dst.as_mut(), width, y, &acc);
+    }
+    dst
+}
+
+const fn lane_mask(bits: usize) -> usize {
+    if bits >= LANE {
+        usize::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// Gathers one grid row out of the packed buffer into whole lanes.
+fn load_row<T: BitOps>(words: &[T], width: usize, y: usize, row: &mut [usize]) {
+    for (lane, out) in row.iter_mut().enumerate() {
+        let start = y * width + lane * LANE;
+        let count = LANE.min(width - lane * LANE);
+        *out = read_bits(words, start, count);
+    }
+}
+
+/// Scatters whole lanes back into one grid row of the packed buffer.
+fn store_row<T: BitOps>(words: &mut [T], width: usize, y: usize, row: &[usize]) {
+    for (lane, &value) in row.iter().enumerate() {
+        let start = y * width + lane * LANE;
+        let count = LANE.min(width - lane * LANE);
+        write_bits(words, start, count, value);
+    }
+}
+
+/// Reads `count` (at most `LANE`) bits starting at linear bit index `start`.
+fn read_bits<T: BitOps>(words: &[T], start: usize, count: usize) -> usize {
+    let mut out = 0;
+    let mut got = 0;
+    while got < count {
+        let index = (start + got) / T::MAX_WIDTH;
+        let offset = (start + got) % T::MAX_WIDTH;
+        let take = (T::MAX_WIDTH - offset).min(count - got);
+        let word = words.get(index).map_or(0, |word| word.to_usize());
+        out |= ((word >> offset) & lane_mask(take)) << got;
+        got += take;
+    }
+    out
+}
+
+/// Writes `count` (at most `LANE`) bits starting at linear bit index `start`.
+fn write_bits<T: BitOps>(words: &mut [T], start: usize, count: usize, value: usize) {
+    let mut put = 0;
+    while put < count {
+        let index = (start + put) / T::MAX_WIDTH;
+        let offset = (start + put) % T::MAX_WIDTH;
+        let take = (T::MAX_WIDTH - offset).min(count - put);
+        let Some(word) = words.get_mut(index) else {
+            break;
+        };
+        let mask = lane_mask(take);
+        let current = word.to_usize();
+        let chunk = (value >> put) & mask;
+        *word = T::from_usize(
+            (current & !(mask << offset) | (chunk << offset)) & lane_mask(T::MAX_WIDTH),
+        );
+        put += take;
+    }
+}
+
+/// Shifts lanes by `by` bit positions, where positive shifts move towards higher indices.
+fn shift_lanes(lanes: &[usize], out: &mut [usize], by: isize) {
+    out.fill(0);
+    #[allow(clippy::cast_sign_loss)]
+    let (words, bits) = (by.unsigned_abs() / LANE, by.unsigned_abs() % LANE);
+    if by >= 0 {
+        for i in (0..out.len()).rev() {
+            let Some(lower) = i.checked_sub(words) else {
+                continue;
+            };
+            out[i] = lanes[lower] << bits;
+            if bits > 0 && lower > 0 {
+                out[i] |= lanes[lower - 1] >> (LANE - bits);
+            }
+        }
+    } else {
+        for (i, slot) in out.iter_mut().enumerate() {
+            let upper = i + words;
+            if upper >= lanes.len() {
+                continue;
+            }
+            *slot = lanes[upper] >> bits;
+            if bits > 0 && upper + 1 < lanes.len() {
+                *slot |= lanes[upper + 1] << (LANE - bits);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ops::GridWrite as _;
+
+    use super::*;
+
+    fn cross() -> GridBits<u8, Vec<u8>, RowMajor> {
+        let mut element = GridBits::new(3, 3);
+        for (x, y) in [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)] {
+            element.set(Pos::new(x, y), true).unwrap();
+        }
+        element
+    }
+
+    fn set_rect(
+        grid: &mut GridBits<u8, Vec<u8>, RowMajor>,
+        x0: usize,
+        y0: usize,
+        w: usize,
+        h: usize,
+    ) {
+        for y in y0..y0 + h {
+            for x in x0..x0 + w {
+                grid.set(Pos::new(x, y), true).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn dilate_spreads_to_cross() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(8, 5);
+        grid.set(Pos::new(3, 2), true).unwrap();
+
+        let dilated = dilate(&grid, &cross());
+        let expected = [(3, 1), (2, 2), (3, 2), (4, 2), (3, 3)];
+        for y in 0..5 {
+            for x in 0..8 {
+                let want = expected.contains(&(x, y));
+                assert_eq!(dilated.get(Pos::new(x, y)), Some(want), "at ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn erode_removes_lone_cells() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(8, 5);
+        grid.set(Pos::new(3, 2), true).unwrap();
+
+        let eroded = erode(&grid, &cross());
+        assert!(eroded.iter().all(|bit| !bit));
+    }
+
+    #[test]
+    fn erode_keeps_interior() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(8, 5);
+        set_rect(&mut grid, 1, 1, 5, 3);
+
+        let eroded = erode(&grid, &cross());
+        assert_eq!(eroded.get(Pos::new(3, 2)), Some(true));
+        assert_eq!(eroded.get(Pos::new(1, 1)), Some(false));
+    }
+
+    #[test]
+    fn open_removes_noise_but_keeps_bulk() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(16, 7);
+        set_rect(&mut grid, 2, 2, 5, 4);
+        grid.set(Pos::new(12, 1), true).unwrap(); // Noise.
+
+        let opened = open(&grid, &cross());
+        assert_eq!(opened.get(Pos::new(12, 1)), Some(false));
+        assert_eq!(opened.get(Pos::new(4, 3)), Some(true));
+    }
+
+    #[test]
+    fn close_fills_small_holes() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(16, 7);
+        set_rect(&mut grid, 2, 1, 6, 5);
+        grid.set(Pos::new(4, 3), false).unwrap(); // A hole.
+
+        let closed = close(&grid, &cross());
+        assert_eq!(closed.get(Pos::new(4, 3)), Some(true));
+    }
+
+    #[test]
+    fn dilation_does_not_wrap_rows() {
+        let mut grid = GridBits::<u8, _, RowMajor>::new(8, 3);
+        grid.set(Pos::new(7, 1), true).unwrap();
+
+        let dilated = dilate(&grid, &cross());
+        assert_eq!(dilated.get(Pos::new(0, 1)), Some(false));
+        assert_eq!(dilated.get(Pos::new(0, 2)), Some(false));
+        assert_eq!(dilated.get(Pos::new(6, 1)), Some(true));
+    }
+}
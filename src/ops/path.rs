@@ -0,0 +1,330 @@
+use crate::{core::Pos, ops::GridRead};
+
+#[cfg(not(feature = "path"))]
+compile_error!("The `path` feature must be enabled to use this module.");
+
+extern crate alloc;
+
+use alloc::{
+    collections::{BTreeMap, BinaryHeap},
+    vec::Vec,
+};
+use core::cmp::Reverse;
+
+#[cfg(feature = "buffer")]
+use crate::{
+    buf::GridBuf,
+    ops::{ExactSizeGrid, layout::RowMajor},
+};
+
+/// The 4-connected neighborhood, in row-major order.
+const DIRS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Finds the cheapest 4-connected path from `start` to `goal` using A* search.
+///
+/// The cost of stepping _into_ a cell is given by `cost`, where `None` marks the cell as
+/// impassable; `heuristic` estimates the remaining cost from a position to `goal` and must not
+/// overestimate it (Manhattan distance is a good default for uniform costs). Returns the full
+/// path including both endpoints, or `None` if `goal` is unreachable. A path from a cell to
+/// itself is just that cell; the cost of the start cell is never charged.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{GridWrite as _, astar}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new_filled(3, 3, 0u8);
+/// grid.set(Pos::new(1, 0), 1).unwrap();
+/// grid.set(Pos::new(1, 1), 1).unwrap();
+///
+/// let path = astar(
+///     &grid,
+///     Pos::new(0, 0),
+///     Pos::new(2, 0),
+///     |&cell| if cell == 1 { None } else { Some(1) },
+///     |pos| pos.x.abs_diff(2) + pos.y,
+/// );
+/// assert_eq!(path.map(|path| path.len()), Some(7));
+/// ```
+pub fn astar<'a, G>(
+    grid: &'a G,
+    start: Pos,
+    goal: Pos,
+    cost: impl Fn(G::Element<'a>) -> Option<usize>,
+    heuristic: impl Fn(Pos) -> usize,
+) -> Option<Vec<Pos>>
+where
+    G: GridRead,
+{
+    cost(grid.get(start)?)?;
+
+    // Entries are `(f, (y, x))`; `g` holds the best known cost so far per visited cell.
+    let mut open = BinaryHeap::new();
+    let mut g = BTreeMap::new();
+    let mut came_from = BTreeMap::new();
+
+    g.insert((start.y, start.x), 0usize);
+    open.push(Reverse((heuristic(start), (start.y, start.x))));
+
+    while let Some(Reverse((f, (y, x)))) = open.pop() {
+        let pos = Pos::new(x, y);
+        let g_cur = g[&(y, x)];
+        if f > g_cur.saturating_add(heuristic(pos)) {
+            // A cheaper route to this cell was found after this entry was queued.
+            continue;
+        }
+        if pos == goal {
+            let mut path = Vec::new();
+            let mut step = (y, x);
+            loop {
+                path.push(Pos::new(step.1, step.0));
+                match came_from.get(&step) {
+                    Some(&prev) => step = prev,
+                    None => break,
+                }
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for (dx, dy) in DIRS {
+            let Some(nx) = x.checked_add_signed(dx) else {
+                continue;
+            };
+            let Some(ny) = y.checked_add_signed(dy) else {
+                continue;
+            };
+            let next = Pos::new(nx, ny);
+            let Some(step) = grid.get(next).and_then(&cost) else {
+                continue;
+            };
+            let g_next = g_cur.saturating_add(step);
+            if g_next < g.get(&(ny, nx)).copied().unwrap_or(usize::MAX) {
+                g.insert((ny, nx), g_next);
+                came_from.insert((ny, nx), (y, x));
+                open.push(Reverse((g_next.saturating_add(heuristic(next)), (ny, nx))));
+            }
+        }
+    }
+
+    None
+}
+
+/// Computes the shortest 4-connected distance from every cell to its nearest source.
+///
+/// Distances are found with uniform-cost (Dijkstra) flooding from all of `sources` at once. The
+/// cost of stepping _into_ a cell is given by `cost`, where `None` marks the cell as impassable.
+/// Source cells have distance `0`, and cells unreachable from every source (including all cells
+/// when `sources` is empty) are left at `u32::MAX`. Sources outside the grid are ignored.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::distance_field, buf::GridBuf};
+///
+/// let grid = GridBuf::new_filled(3, 1, 0u8);
+/// let field = distance_field(&grid, &[Pos::new(0, 0)], |_| Some(1));
+///
+/// assert_eq!(field[Pos::new(2, 0)], 2);
+/// ```
+#[cfg(feature = "buffer")]
+pub fn distance_field<'a, G>(
+    grid: &'a G,
+    sources: &[Pos],
+    cost: impl Fn(G::Element<'a>) -> Option<u32>,
+) -> GridBuf<u32, Vec<u32>, RowMajor>
+where
+    G: GridRead + ExactSizeGrid,
+{
+    let mut field = GridBuf::new_filled(grid.width(), grid.height(), u32::MAX);
+    let mut open = BinaryHeap::new();
+
+    for &source in sources {
+        if grid.contains(source) {
+            field[source] = 0;
+            open.push(Reverse((0u32, (source.y, source.x))));
+        }
+    }
+
+    while let Some(Reverse((distance, (y, x)))) = open.pop() {
+        if distance > field[Pos::new(x, y)] {
+            // A nearer source reached this cell after this entry was queued.
+            continue;
+        }
+        for (dx, dy) in DIRS {
+            let Some(nx) = x.checked_add_signed(dx) else {
+                continue;
+            };
+            let Some(ny) = y.checked_add_signed(dy) else {
+                continue;
+            };
+            let next = Pos::new(nx, ny);
+            let Some(step) = grid.get(next).and_then(&cost) else {
+                continue;
+            };
+            let next_distance = distance.saturating_add(step);
+            if next_distance < field[next] {
+                field[next] = next_distance;
+                open.push(Reverse((next_distance, (ny, nx))));
+            }
+        }
+    }
+
+    field
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::test::NaiveGrid;
+    use alloc::vec;
+
+    use super::*;
+
+    fn manhattan(goal: Pos) -> impl Fn(Pos) -> usize {
+        move |pos| pos.x.abs_diff(goal.x) + pos.y.abs_diff(goal.y)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn walkable(cell: &u8) -> Option<usize> {
+        if *cell == 1 { None } else { Some(1) }
+    }
+
+    #[test]
+    fn straight_line_path() {
+        let grid = NaiveGrid::<u8>::new(4, 1);
+        let goal = Pos::new(3, 0);
+
+        let path = astar(&grid, Pos::new(0, 0), goal, walkable, manhattan(goal));
+        assert_eq!(
+            path,
+            Some(vec![
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(2, 0),
+                Pos::new(3, 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn routes_around_walls() {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 3, vec![
+            0, 1, 0,
+            0, 1, 0,
+            0, 0, 0,
+        ]);
+        let goal = Pos::new(2, 0);
+
+        let path = astar(&grid, Pos::new(0, 0), goal, walkable, manhattan(goal)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&Pos::new(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+    }
+
+    #[test]
+    fn prefers_cheaper_cells() {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 2, vec![
+            0u8, 9, 0,
+            0,   0, 0,
+        ]);
+        let goal = Pos::new(2, 0);
+
+        let path = astar(
+            &grid,
+            Pos::new(0, 0),
+            goal,
+            |&cell| Some(usize::from(cell) + 1),
+            manhattan(goal),
+        )
+        .unwrap();
+        assert!(path.contains(&Pos::new(1, 1)));
+        assert!(!path.contains(&Pos::new(1, 0)));
+    }
+
+    #[test]
+    fn unreachable_goal_is_none() {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 2, vec![
+            0, 1, 0,
+            1, 1, 0,
+        ]);
+        let goal = Pos::new(2, 0);
+
+        assert_eq!(
+            astar(&grid, Pos::new(0, 0), goal, walkable, manhattan(goal)),
+            None
+        );
+    }
+
+    #[test]
+    fn start_equals_goal() {
+        let grid = NaiveGrid::<u8>::new(2, 2);
+        let path = astar(
+            &grid,
+            Pos::new(1, 1),
+            Pos::new(1, 1),
+            walkable,
+            manhattan(Pos::new(1, 1)),
+        );
+        assert_eq!(path, Some(vec![Pos::new(1, 1)]));
+    }
+
+    #[test]
+    fn blocked_start_is_none() {
+        let grid = NaiveGrid::with_cells(2, 1, vec![1, 0]);
+        let goal = Pos::new(1, 0);
+
+        assert_eq!(
+            astar(&grid, Pos::new(0, 0), goal, walkable, manhattan(goal)),
+            None
+        );
+    }
+
+    #[cfg(feature = "buffer")]
+    #[test]
+    fn distance_field_single_source() {
+        let grid = GridBuf::<u8, _, RowMajor>::new(3, 3);
+        let field = distance_field(&grid, &[Pos::new(0, 0)], |_| Some(1));
+
+        assert_eq!(field[Pos::new(0, 0)], 0);
+        assert_eq!(field[Pos::new(2, 2)], 4);
+        assert_eq!(field[Pos::new(1, 2)], 3);
+    }
+
+    #[cfg(feature = "buffer")]
+    #[test]
+    fn distance_field_multiple_sources_take_nearest() {
+        let grid = GridBuf::<u8, _, RowMajor>::new(5, 1);
+        let field = distance_field(&grid, &[Pos::new(0, 0), Pos::new(4, 0)], |_| Some(1));
+
+        assert_eq!(field[Pos::new(1, 0)], 1);
+        assert_eq!(field[Pos::new(2, 0)], 2);
+        assert_eq!(field[Pos::new(3, 0)], 1);
+    }
+
+    #[cfg(feature = "buffer")]
+    #[test]
+    fn distance_field_walls_stay_unreachable() {
+        #[rustfmt::skip]
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![
+            0, 1, 0u8,
+        ], 3);
+        let field = distance_field(&grid, &[Pos::new(0, 0)], |&cell| {
+            if cell == 1 { None } else { Some(1) }
+        });
+
+        assert_eq!(field[Pos::new(1, 0)], u32::MAX);
+        assert_eq!(field[Pos::new(2, 0)], u32::MAX);
+    }
+
+    #[cfg(feature = "buffer")]
+    #[test]
+    fn distance_field_no_sources() {
+        let grid = GridBuf::<u8, _, RowMajor>::new(2, 2);
+        let field = distance_field(&grid, &[], |_| Some(1));
+        assert_eq!(field[Pos::new(0, 0)], u32::MAX);
+    }
+}
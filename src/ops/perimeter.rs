@@ -0,0 +1,96 @@
+use crate::core::{Pos, Rect};
+
+/// Returns an iterator over the border positions of a rectangle, in clockwise order.
+///
+/// Positions are yielded starting from the top-left corner: the top row left to right, the right
+/// column downwards, the bottom row right to left, and the left column upwards. Each border cell
+/// is yielded exactly once, including for rectangles a single row or column wide; an empty
+/// rectangle yields nothing. The iterator is independent of any grid — to read the border
+/// elements, see [`GridRead::iter_perimeter`][].
+///
+/// [`GridRead::iter_perimeter`]: crate::ops::GridRead::iter_perimeter
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::perimeter_iter};
+///
+/// let border: Vec<_> = perimeter_iter(Rect::from_ltwh(0, 0, 3, 3)).collect();
+/// assert_eq!(border.len(), 8);
+/// assert_eq!(border[0], Pos::new(0, 0));
+/// assert_eq!(border[7], Pos::new(0, 1));
+/// ```
+pub fn perimeter_iter(bounds: Rect) -> impl Iterator<Item = Pos> {
+    let (w, h) = (bounds.width(), bounds.height());
+    let (w, h) = if w == 0 || h == 0 { (0, 0) } else { (w, h) };
+    let tl = bounds.top_left();
+
+    let top = (0..w).map(move |x| tl + Pos::new(x, 0));
+    let right = (1..h).map(move |y| tl + Pos::new(w - 1, y));
+    let bottom = if h > 1 { 0..w.saturating_sub(1) } else { 0..0 }
+        .rev()
+        .map(move |x| tl + Pos::new(x, h - 1));
+    let left = if w > 1 { 1..h.saturating_sub(1) } else { 1..1 }
+        .rev()
+        .map(move |y| tl + Pos::new(0, y));
+
+    top.chain(right).chain(bottom).chain(left)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn perimeter_clockwise_from_top_left() {
+        let border: Vec<_> = perimeter_iter(Rect::from_ltwh(1, 1, 3, 3)).collect();
+        assert_eq!(
+            border,
+            [
+                Pos::new(1, 1),
+                Pos::new(2, 1),
+                Pos::new(3, 1),
+                Pos::new(3, 2),
+                Pos::new(3, 3),
+                Pos::new(2, 3),
+                Pos::new(1, 3),
+                Pos::new(1, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn perimeter_single_row() {
+        let border: Vec<_> = perimeter_iter(Rect::from_ltwh(0, 0, 3, 1)).collect();
+        assert_eq!(border, [Pos::new(0, 0), Pos::new(1, 0), Pos::new(2, 0)]);
+    }
+
+    #[test]
+    fn perimeter_single_column() {
+        let border: Vec<_> = perimeter_iter(Rect::from_ltwh(2, 0, 1, 3)).collect();
+        assert_eq!(border, [Pos::new(2, 0), Pos::new(2, 1), Pos::new(2, 2)]);
+    }
+
+    #[test]
+    fn perimeter_empty_rect() {
+        assert_eq!(perimeter_iter(Rect::from_ltwh(0, 0, 0, 3)).count(), 0);
+    }
+
+    #[test]
+    fn perimeter_two_by_two_has_no_duplicates() {
+        let border: Vec<_> = perimeter_iter(Rect::from_ltwh(0, 0, 2, 2)).collect();
+        assert_eq!(
+            border,
+            [
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(1, 1),
+                Pos::new(0, 1),
+            ]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use core::hash::{Hash, Hasher};
+
 use crate::{
     core::{Pos, Rect},
     ops::{
@@ -29,6 +31,46 @@ pub trait GridRead: GridBase {
     /// If the position is out of bounds, it returns `None`.
     fn get(&self, pos: Pos) -> Option<Self::Element<'_>>;
 
+    /// Returns a reference to an element at a position given as any [`GridIndex`].
+    ///
+    /// This accepts `(x, y)` tuples and `[x, y]` arrays as well as [`Pos`], trading the explicit
+    /// constructor for ergonomics at call sites that read many cells.
+    ///
+    /// [`GridIndex`]: crate::core::GridIndex
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::new_filled(3, 3, 7u8);
+    /// assert_eq!(grid.get_at((1, 2)), Some(&7));
+    /// assert_eq!(grid.get_at([3, 0]), None);
+    /// ```
+    fn get_at(&self, index: impl crate::core::GridIndex) -> Option<Self::Element<'_>> {
+        self.get(index.into_pos())
+    }
+
+    /// Returns a reference to an element at a signed position.
+    ///
+    /// Negative coordinates are out of bounds and return `None`, so world-coordinate code can
+    /// sample near the origin without converting (and bounds-checking) at every call site.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::{core::SignedPos, prelude::*};
+    ///
+    /// let grid = GridBuf::new_filled(3, 3, 7u8);
+    /// assert_eq!(grid.get_signed(SignedPos::new(1, 1)), Some(&7));
+    /// assert_eq!(grid.get_signed(SignedPos::new(-1, 1)), None);
+    /// ```
+    fn get_signed(&self, pos: crate::core::SignedPos) -> Option<Self::Element<'_>> {
+        let x = usize::try_from(pos.x).ok()?;
+        let y = usize::try_from(pos.y).ok()?;
+        self.get(Pos::new(x, y))
+    }
+
     /// Returns an iterator over elements in a rectangular region of the grid.
     ///
     /// Elements are returned in an order agreeable to the grid's internal layout. Out-of-bounds
@@ -67,6 +109,308 @@ pub trait GridRead: GridBase {
         let trimmed = self.trim_rect(bounds);
         Self::Layout::iter_pos(trimmed).filter_map(move |pos| self.get(pos).map(|elem| (pos, elem)))
     }
+
+    /// Folds every `(position, element)` pair in a rectangular region into an accumulator.
+    ///
+    /// Pairs are visited in the traversal order defined by `Self::Layout`, starting from `init`;
+    /// out-of-bounds positions are skipped. This runs arbitrary reductions, such as sums or
+    /// bounding boxes of matches, without collecting the region first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::new_filled(3, 3, 2u32);
+    /// let sum = grid.fold_rect(Rect::from_ltwh(0, 0, 3, 1), 0, |acc, _, &v| acc + v);
+    /// assert_eq!(sum, 6);
+    /// ```
+    fn fold_rect<A>(
+        &self,
+        bounds: Rect,
+        init: A,
+        mut f: impl FnMut(A, Pos, Self::Element<'_>) -> A,
+    ) -> A {
+        self.iter_rect_with_pos(bounds)
+            .fold(init, |acc, (pos, elem)| f(acc, pos, elem))
+    }
+
+    /// Returns whether any element in a rectangular region matches a predicate.
+    ///
+    /// Iteration short-circuits on the first match, so collision-style queries stop as soon as a
+    /// hit is found. An empty region matches nothing and returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let mut grid = GridBuf::new_filled(3, 3, 0u8);
+    /// grid.set(Pos::new(2, 2), 1).unwrap();
+    /// assert!(grid.any_rect(Rect::from_ltwh(0, 0, 3, 3), |&v| v == 1));
+    /// assert!(!grid.any_rect(Rect::from_ltwh(0, 0, 2, 2), |&v| v == 1));
+    /// ```
+    fn any_rect(&self, bounds: Rect, predicate: impl FnMut(Self::Element<'_>) -> bool) -> bool {
+        self.iter_rect(bounds).any(predicate)
+    }
+
+    /// Returns whether every element in a rectangular region matches a predicate.
+    ///
+    /// Iteration short-circuits on the first mismatch. An empty region is vacuously `true`.
+    fn all_rect(&self, bounds: Rect, predicate: impl FnMut(Self::Element<'_>) -> bool) -> bool {
+        self.iter_rect(bounds).all(predicate)
+    }
+
+    /// Returns the position of the first element in a rectangular region matching a predicate.
+    ///
+    /// Positions are visited in the traversal order defined by `Self::Layout`, and iteration
+    /// stops at the first match. Returns `None` if nothing in the region matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let mut grid = GridBuf::new_filled(3, 3, 0u8);
+    /// grid.set(Pos::new(1, 2), b'@').unwrap();
+    /// let spawn = grid.find(Rect::from_ltwh(0, 0, 3, 3), |&v| v == b'@');
+    /// assert_eq!(spawn, Some(Pos::new(1, 2)));
+    /// ```
+    fn find(
+        &self,
+        bounds: Rect,
+        mut predicate: impl FnMut(Self::Element<'_>) -> bool,
+    ) -> Option<Pos> {
+        self.iter_rect_with_pos(bounds)
+            .find_map(|(pos, elem)| predicate(elem).then_some(pos))
+    }
+
+    /// Returns an iterator over the positions of every element in a rectangular region matching
+    /// a predicate, in the traversal order defined by `Self::Layout`.
+    fn find_all(
+        &self,
+        bounds: Rect,
+        mut predicate: impl FnMut(Self::Element<'_>) -> bool,
+    ) -> impl Iterator<Item = Pos> {
+        self.iter_rect_with_pos(bounds)
+            .filter_map(move |(pos, elem)| predicate(elem).then_some(pos))
+    }
+
+    /// Returns the number of elements in a rectangular region matching a predicate.
+    ///
+    /// For counting the set bits of a bit grid, `GridBits::count_set` does the same with
+    /// word-level popcounts instead of testing each cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let mut grid = GridBuf::new_filled(3, 3, 0u8);
+    /// grid.set(Pos::new(1, 1), 1).unwrap();
+    /// assert_eq!(grid.count_rect(Rect::from_ltwh(0, 0, 3, 3), |&v| v == 1), 1);
+    /// ```
+    fn count_rect(
+        &self,
+        bounds: Rect,
+        mut predicate: impl FnMut(Self::Element<'_>) -> bool,
+    ) -> usize {
+        self.iter_rect(bounds)
+            .map(|elem| usize::from(predicate(elem)))
+            .sum()
+    }
+
+    /// Hashes a rectangular region's contents into a hasher.
+    ///
+    /// The trimmed region's dimensions are hashed first, then every element in the traversal
+    /// order defined by `Self::Layout`, so regions with the same elements in different shapes
+    /// hash differently. The resulting hash is only stable across runs when the hasher itself
+    /// is deterministic.
+    fn hash_rect<H: Hasher>(&self, bounds: Rect, state: &mut H)
+    where
+        for<'x> Self::Element<'x>: Hash,
+    {
+        let trimmed = self.trim_rect(bounds);
+        (trimmed.width(), trimmed.height()).hash(state);
+        for elem in self.iter_rect(trimmed) {
+            elem.hash(state);
+        }
+    }
+
+    /// Returns an iterator over the rows of the grid, each an iterator over that row's elements.
+    ///
+    /// Rows are yielded top to bottom, and elements within a row left to right. For row slices
+    /// that support `memcpy`-style processing, see `GridBuf::rows`.
+    fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = Self::Element<'_>>>
+    where
+        Self: ExactSizeGrid,
+    {
+        (0..self.height()).map(move |y| self.iter_rect(Rect::from_ltwh(0, y, self.width(), 1)))
+    }
+
+    /// Returns an iterator over the columns of the grid, each an iterator over that column's
+    /// elements.
+    ///
+    /// Columns are yielded left to right, and elements within a column top to bottom. For
+    /// contiguous column slices over `ColumnMajor` buffers, see `GridBuf::cols`.
+    fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = Self::Element<'_>>>
+    where
+        Self: ExactSizeGrid,
+    {
+        (0..self.width()).map(move |x| self.iter_rect(Rect::from_ltwh(x, 0, 1, self.height())))
+    }
+
+    /// Returns an iterator over the main diagonal of the grid, from the top-left corner.
+    ///
+    /// Elements are yielded from `(0, 0)` moving down-right; the diagonal has
+    /// `min(width, height)` elements. For every diagonal of a region, see
+    /// [`iter_diags_main`](GridRead::iter_diags_main).
+    fn iter_diag_main(&self) -> impl Iterator<Item = Self::Element<'_>>
+    where
+        Self: ExactSizeGrid,
+    {
+        (0..self.width().min(self.height())).filter_map(move |i| self.get(Pos::new(i, i)))
+    }
+
+    /// Returns an iterator over the anti-diagonal of the grid, from the top-right corner.
+    ///
+    /// Elements are yielded from `(width - 1, 0)` moving down-left; the diagonal has
+    /// `min(width, height)` elements. For every anti-diagonal of a region, see
+    /// [`iter_diags_anti`](GridRead::iter_diags_anti).
+    fn iter_diag_anti(&self) -> impl Iterator<Item = Self::Element<'_>>
+    where
+        Self: ExactSizeGrid,
+    {
+        let width = self.width();
+        (0..width.min(self.height())).filter_map(move |i| self.get(Pos::new(width - 1 - i, i)))
+    }
+
+    /// Returns an iterator over every down-right diagonal of a rectangular region.
+    ///
+    /// Diagonals are yielded starting from the bottom-left corner of the region and ending at
+    /// the top-right corner, each an iterator over that diagonal's elements from its top-left
+    /// end. The bounding rectangle is trimmed to the grid.
+    fn iter_diags_main(
+        &self,
+        bounds: Rect,
+    ) -> impl Iterator<Item = impl Iterator<Item = Self::Element<'_>>> {
+        let bounds = self.trim_rect(bounds);
+        let (w, h) = (bounds.width(), bounds.height());
+        let count = if w == 0 || h == 0 { 0 } else { w + h - 1 };
+        (0..count).map(move |d| {
+            let (sx, sy) = if d < h {
+                (0, h - 1 - d)
+            } else {
+                (d - h + 1, 0)
+            };
+            let len = (w - sx).min(h - sy);
+            (0..len).filter_map(move |i| self.get(bounds.top_left() + Pos::new(sx + i, sy + i)))
+        })
+    }
+
+    /// Returns an iterator over every down-left anti-diagonal of a rectangular region.
+    ///
+    /// Diagonals are yielded starting from the top-left corner of the region and ending at the
+    /// bottom-right corner, each an iterator over that diagonal's elements from its top-right
+    /// end. The bounding rectangle is trimmed to the grid.
+    fn iter_diags_anti(
+        &self,
+        bounds: Rect,
+    ) -> impl Iterator<Item = impl Iterator<Item = Self::Element<'_>>> {
+        let bounds = self.trim_rect(bounds);
+        let (w, h) = (bounds.width(), bounds.height());
+        let count = if w == 0 || h == 0 { 0 } else { w + h - 1 };
+        (0..count).map(move |d| {
+            let sx = d.min(w - 1);
+            let sy = d - sx;
+            let len = (sx + 1).min(h - sy);
+            (0..len).filter_map(move |i| self.get(bounds.top_left() + Pos::new(sx - i, sy + i)))
+        })
+    }
+
+    /// Returns an iterator over maximal horizontal runs of equal elements in a region.
+    ///
+    /// Each item is a `(position, length, element)` triple describing a run: the position of its
+    /// leftmost cell, the number of equal cells, and the shared element. Runs never span rows,
+    /// and rows are scanned top to bottom. The bounding rectangle is trimmed to the grid. This
+    /// supports run-length encoding and span-based rendering without per-cell bookkeeping.
+    fn iter_runs(&self, bounds: Rect) -> impl Iterator<Item = (Pos, usize, Self::Element<'_>)>
+    where
+        for<'x> Self::Element<'x>: PartialEq,
+    {
+        let bounds = self.trim_rect(bounds);
+        let (left, right) = (bounds.top_left().x, bounds.right());
+        let bottom = bounds.bottom();
+        let mut y = bounds.top_left().y;
+        let mut x = left;
+        core::iter::from_fn(move || {
+            while y < bottom {
+                if x >= right {
+                    y += 1;
+                    x = left;
+                    continue;
+                }
+                let start = x;
+                let value = self.get(Pos::new(x, y))?;
+                x += 1;
+                while x < right {
+                    match self.get(Pos::new(x, y)) {
+                        Some(next) if next == value => x += 1,
+                        _ => break,
+                    }
+                }
+                return Some((Pos::new(start, y), x - start, value));
+            }
+            None
+        })
+    }
+
+    /// Returns an iterator over `(position, element)` pairs on the border of a rectangular
+    /// region.
+    ///
+    /// Positions are yielded in the clockwise order of [`perimeter_iter`], starting from the
+    /// top-left corner; the bounding rectangle is trimmed to the grid.
+    ///
+    /// [`perimeter_iter`]: crate::ops::perimeter_iter
+    fn iter_perimeter(&self, bounds: Rect) -> impl Iterator<Item = (Pos, Self::Element<'_>)> {
+        crate::ops::perimeter_iter(self.trim_rect(bounds))
+            .filter_map(move |pos| self.get(pos).map(|elem| (pos, elem)))
+    }
+
+    /// Returns an iterator over the in-bounds 4-neighborhood of a position.
+    ///
+    /// Yields `(position, element)` pairs for the cells directly above, left of, right of, and
+    /// below `pos`, in row-major order; neighbors outside the grid are skipped. For the
+    /// neighborhood including diagonals, see [`neighbors8`](GridRead::neighbors8).
+    fn neighbors(&self, pos: Pos) -> impl Iterator<Item = (Pos, Self::Element<'_>)> {
+        const OFFSETS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+        OFFSETS.into_iter().filter_map(move |(dx, dy)| {
+            let pos = Pos::new(pos.x.checked_add_signed(dx)?, pos.y.checked_add_signed(dy)?);
+            self.get(pos).map(|elem| (pos, elem))
+        })
+    }
+
+    /// Returns an iterator over the in-bounds 8-neighborhood of a position.
+    ///
+    /// Yields `(position, element)` pairs for the eight cells surrounding `pos`, including
+    /// diagonals, in row-major order; neighbors outside the grid are skipped. For the cardinal
+    /// neighborhood only, see [`neighbors`](GridRead::neighbors).
+    fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = (Pos, Self::Element<'_>)> {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        OFFSETS.into_iter().filter_map(move |(dx, dy)| {
+            let pos = Pos::new(pos.x.checked_add_signed(dx)?, pos.y.checked_add_signed(dy)?);
+            self.get(pos).map(|elem| (pos, elem))
+        })
+    }
 }
 
 /// A trait for grids that can be iterated over.
@@ -108,7 +452,7 @@ mod tests {
     use super::*;
 
     use crate::{buf::GridBuf, core::Size, ops::layout::RowMajor, transform::GridConvertExt as _};
-    use alloc::vec::Vec;
+    use alloc::{vec, vec::Vec};
 
     struct CheckedGridTest {
         grid: [[u8; 3]; 3],
@@ -177,6 +521,328 @@ mod tests {
         assert!(cells.is_empty());
     }
 
+    #[test]
+    fn fold_rect_sums_region() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let sum = grid.fold_rect(Rect::from_ltwh(1, 1, 2, 2), 0u32, |acc, _, v| {
+            acc + u32::from(v)
+        });
+        assert_eq!(sum, 5 + 6 + 8 + 9);
+    }
+
+    #[test]
+    fn fold_rect_tracks_positions() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let best = grid.fold_rect(Rect::from_ltwh(0, 0, 3, 3), None, |acc, pos, v| {
+            if v > 7 && acc.is_none() {
+                Some(pos)
+            } else {
+                acc
+            }
+        });
+        assert_eq!(best, Some(Pos::new(1, 2)));
+    }
+
+    #[test]
+    fn fold_rect_out_of_bounds_keeps_init() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert_eq!(
+            grid.fold_rect(Rect::from_ltwh(5, 5, 2, 2), 42, |_, _, _| 0),
+            42
+        );
+    }
+
+    #[test]
+    fn any_rect_short_circuits_on_match() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let mut calls = 0;
+        assert!(grid.any_rect(Rect::from_ltwh(0, 0, 3, 3), |v| {
+            calls += 1;
+            v == 2
+        }));
+        assert_eq!(calls, 2);
+        assert!(!grid.any_rect(Rect::from_ltwh(0, 0, 2, 2), |v| v == 9));
+    }
+
+    #[test]
+    fn all_rect_checks_whole_region() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert!(grid.all_rect(Rect::from_ltwh(0, 0, 3, 3), |v| v > 0));
+        assert!(!grid.all_rect(Rect::from_ltwh(0, 0, 3, 3), |v| v < 9));
+    }
+
+    #[test]
+    fn any_and_all_on_empty_region() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert!(!grid.any_rect(Rect::from_ltwh(5, 5, 1, 1), |_| true));
+        assert!(grid.all_rect(Rect::from_ltwh(5, 5, 1, 1), |_| false));
+    }
+
+    #[test]
+    fn find_first_match_in_layout_order() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 2, 9]],
+        };
+        assert_eq!(
+            grid.find(Rect::from_ltwh(0, 0, 3, 3), |v| v == 2),
+            Some(Pos::new(1, 0))
+        );
+        assert_eq!(grid.find(Rect::from_ltwh(0, 0, 3, 3), |v| v == 42), None);
+    }
+
+    #[test]
+    fn find_respects_bounds() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 2, 9]],
+        };
+        assert_eq!(
+            grid.find(Rect::from_ltwh(0, 1, 3, 2), |v| v == 2),
+            Some(Pos::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn find_all_yields_every_match() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 2, 9]],
+        };
+        let matches: Vec<_> = grid
+            .find_all(Rect::from_ltwh(0, 0, 3, 3), |v| v == 2)
+            .collect();
+        assert_eq!(matches, [Pos::new(1, 0), Pos::new(1, 2)]);
+    }
+
+    #[test]
+    fn get_signed_rejects_negative_coordinates() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert_eq!(grid.get_signed(crate::core::SignedPos::new(2, 1)), Some(6));
+        assert_eq!(grid.get_signed(crate::core::SignedPos::new(-1, 1)), None);
+        assert_eq!(grid.get_signed(crate::core::SignedPos::new(1, -3)), None);
+    }
+
+    #[test]
+    fn count_rect_counts_matches() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 2, 9]],
+        };
+        assert_eq!(grid.count_rect(Rect::from_ltwh(0, 0, 3, 3), |v| v == 2), 2);
+        assert_eq!(grid.count_rect(Rect::from_ltwh(0, 0, 3, 3), |v| v > 9), 0);
+    }
+
+    #[test]
+    fn count_rect_respects_bounds() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 2, 9]],
+        };
+        assert_eq!(grid.count_rect(Rect::from_ltwh(0, 0, 2, 1), |v| v > 0), 2);
+        assert_eq!(grid.count_rect(Rect::from_ltwh(5, 5, 2, 2), |_| true), 0);
+    }
+
+    #[test]
+    fn hash_rect_is_deterministic_per_region() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let bounds = Rect::from_ltwh(0, 0, 2, 2);
+        assert_eq!(region_hash(&grid, bounds), region_hash(&grid, bounds));
+        assert_ne!(
+            region_hash(&grid, bounds),
+            region_hash(&grid, Rect::from_ltwh(1, 1, 2, 2))
+        );
+    }
+
+    #[test]
+    fn hash_rect_includes_region_shape() {
+        let grid = CheckedGridTest {
+            grid: [[1, 1, 1], [1, 1, 1], [1, 1, 1]],
+        };
+        assert_ne!(
+            region_hash(&grid, Rect::from_ltwh(0, 0, 2, 1)),
+            region_hash(&grid, Rect::from_ltwh(0, 0, 1, 2))
+        );
+    }
+
+    fn region_hash(grid: &CheckedGridTest, bounds: Rect) -> u64 {
+        use core::hash::Hasher as _;
+        let mut hasher = crate::test::Fnv1aHasher::default();
+        grid.hash_rect(bounds, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn iter_rows_top_to_bottom() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        let rows: Vec<Vec<i32>> = grid.copied().iter_rows().map(Iterator::collect).collect();
+        assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn iter_cols_left_to_right() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 3);
+        let cols: Vec<Vec<i32>> = grid.copied().iter_cols().map(Iterator::collect).collect();
+        assert_eq!(cols, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn iter_diag_main_from_top_left() {
+        let grid =
+            GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).copied();
+        let diag: Vec<_> = grid.iter_diag_main().collect();
+        assert_eq!(diag, [1, 5, 9]);
+    }
+
+    #[test]
+    fn iter_diag_anti_from_top_right() {
+        let grid =
+            GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).copied();
+        let diag: Vec<_> = grid.iter_diag_anti().collect();
+        assert_eq!(diag, [3, 5, 7]);
+    }
+
+    #[test]
+    fn iter_diags_main_covers_rect() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let diags: Vec<Vec<u8>> = grid
+            .iter_diags_main(Rect::from_ltwh(0, 0, 3, 3))
+            .map(Iterator::collect)
+            .collect();
+        let expected: [&[u8]; 5] = [&[7], &[4, 8], &[1, 5, 9], &[2, 6], &[3]];
+        assert_eq!(diags, expected);
+    }
+
+    #[test]
+    fn iter_diags_anti_covers_rect() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let diags: Vec<Vec<u8>> = grid
+            .iter_diags_anti(Rect::from_ltwh(0, 0, 3, 3))
+            .map(Iterator::collect)
+            .collect();
+        let expected: [&[u8]; 5] = [&[1], &[2, 4], &[3, 5, 7], &[6, 8], &[9]];
+        assert_eq!(diags, expected);
+    }
+
+    #[test]
+    fn iter_diags_main_offset_rect() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let diags: Vec<Vec<u8>> = grid
+            .iter_diags_main(Rect::from_ltwh(1, 1, 2, 2))
+            .map(Iterator::collect)
+            .collect();
+        let expected: [&[u8]; 3] = [&[8], &[5, 9], &[6]];
+        assert_eq!(diags, expected);
+    }
+
+    #[test]
+    fn iter_runs_groups_equal_cells() {
+        let grid = CheckedGridTest {
+            grid: [[1, 1, 2], [2, 2, 2], [3, 1, 1]],
+        };
+        let runs: Vec<_> = grid.iter_runs(Rect::from_ltwh(0, 0, 3, 3)).collect();
+        assert_eq!(
+            runs,
+            [
+                (Pos::new(0, 0), 2, 1),
+                (Pos::new(2, 0), 1, 2),
+                (Pos::new(0, 1), 3, 2),
+                (Pos::new(0, 2), 1, 3),
+                (Pos::new(1, 2), 2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_runs_does_not_span_rows() {
+        let grid = CheckedGridTest {
+            grid: [[1, 1, 1], [1, 1, 1], [2, 2, 2]],
+        };
+        let runs: Vec<_> = grid.iter_runs(Rect::from_ltwh(0, 0, 3, 2)).collect();
+        assert_eq!(runs, [(Pos::new(0, 0), 3, 1), (Pos::new(0, 1), 3, 1)]);
+    }
+
+    #[test]
+    fn iter_runs_empty_rect() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert_eq!(grid.iter_runs(Rect::from_ltwh(3, 3, 2, 2)).count(), 0);
+    }
+
+    #[test]
+    fn iter_perimeter_reads_border() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let elems: Vec<_> = grid
+            .iter_perimeter(Rect::from_ltwh(0, 0, 3, 3))
+            .map(|(_, e)| e)
+            .collect();
+        assert_eq!(elems, [1, 2, 3, 6, 9, 8, 7, 4]);
+    }
+
+    #[test]
+    fn neighbors_interior() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let pairs: Vec<_> = grid.neighbors(Pos::new(1, 1)).collect();
+        assert_eq!(
+            pairs,
+            [
+                (Pos::new(1, 0), 2),
+                (Pos::new(0, 1), 4),
+                (Pos::new(2, 1), 6),
+                (Pos::new(1, 2), 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors_corner_skips_out_of_bounds() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let pairs: Vec<_> = grid.neighbors(Pos::new(0, 0)).collect();
+        assert_eq!(pairs, [(Pos::new(1, 0), 2), (Pos::new(0, 1), 4)]);
+    }
+
+    #[test]
+    fn neighbors8_interior() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let elems: Vec<_> = grid.neighbors8(Pos::new(1, 1)).map(|(_, e)| e).collect();
+        assert_eq!(elems, [1, 2, 3, 4, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn neighbors8_corner_skips_out_of_bounds() {
+        let grid = CheckedGridTest {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let elems: Vec<_> = grid.neighbors8(Pos::new(2, 2)).map(|(_, e)| e).collect();
+        assert_eq!(elems, [5, 6, 8]);
+    }
+
     #[test]
     fn collect() {
         let grid = GridBuf::new_filled(3, 3, 1);
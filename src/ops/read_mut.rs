@@ -0,0 +1,78 @@
+use crate::{
+    core::{Pos, Rect},
+    ops::{GridBase, layout},
+};
+
+/// Mutable in-place access to elements of a 2-dimensional grid.
+///
+/// This complements [`GridWrite`][]: where `set` replaces an element wholesale, `get_mut` hands
+/// out a mutable reference, so generic code can mutate elements in place (bump a counter, extend
+/// a tile's entity list) without an intermediate read-modify-write.
+///
+/// [`GridWrite`]: crate::ops::GridWrite
+pub trait GridReadMut: GridBase {
+    /// The type of elements in the grid.
+    type Element;
+
+    /// The type of layout used for the grid.
+    ///
+    /// ## Implementation
+    ///
+    /// It is not guaranteed that the internal storage of the grid matches this layout, but methods
+    /// that return iterators over the grid's elements should return them in the traversal order
+    /// defined by this layout.
+    ///
+    /// [`RowMajor`][layout::RowMajor] is a reasonable default implementation for most grids.
+    type Layout: layout::Traversal;
+
+    /// Returns a mutable reference to the element at `pos`, or `None` if out of bounds.
+    fn get_mut(&mut self, pos: Pos) -> Option<&mut Self::Element>;
+
+    /// Returns mutable references to the elements within a rectangular region.
+    ///
+    /// The bounds are trimmed to the grid's own bounds, and elements are yielded in the traversal
+    /// order defined by the grid's layout.
+    fn iter_rect_mut(&mut self, bounds: Rect) -> impl Iterator<Item = &mut Self::Element>;
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        buf::GridBuf,
+        core::{Pos, Rect},
+        ops::{GridRead as _, GridReadMut},
+    };
+
+    #[test]
+    fn get_mut_mutates_in_place() {
+        let mut grid = GridBuf::<u8, _, _>::new_filled(3, 3, 1);
+        *GridReadMut::get_mut(&mut grid, Pos::new(1, 1)).unwrap() += 9;
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&10));
+        assert!(GridReadMut::get_mut(&mut grid, Pos::new(3, 3)).is_none());
+    }
+
+    #[test]
+    fn iter_rect_mut_yields_region_in_row_order() {
+        let mut grid = GridBuf::<u8, _, _>::new(4, 3);
+        for (i, cell) in grid.iter_rect_mut(Rect::from_ltwh(1, 1, 2, 2)).enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                *cell = i as u8 + 1;
+            }
+        }
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&1));
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&2));
+        assert_eq!(grid.get(Pos::new(1, 2)), Some(&3));
+        assert_eq!(grid.get(Pos::new(2, 2)), Some(&4));
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+    }
+
+    #[test]
+    fn iter_rect_mut_trims_out_of_bounds() {
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2);
+        let count = grid.iter_rect_mut(Rect::from_ltwh(1, 1, 5, 5)).count();
+        assert_eq!(count, 1);
+    }
+}
@@ -0,0 +1,235 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::fmt::{self, Display, Write as _};
+
+use crate::{
+    core::Pos,
+    ops::{ExactSizeGrid, GridRead},
+};
+
+/// Wraps a grid so it can be formatted with [`Display`], one character per cell.
+///
+/// Rows are written top to bottom, each followed by a newline. The mapping closure decides the
+/// character for each element, so the same grid can be rendered differently per call site.
+///
+/// # Examples
+///
+/// ```rust
+/// use grixy::{buf::GridBuf, core::Pos, ops::{DisplayGrid, GridWrite as _}};
+///
+/// let mut grid = GridBuf::new_filled(3, 2, 0u8);
+/// grid.set(Pos::new(1, 0), 1).unwrap();
+///
+/// let display = DisplayGrid::new(&grid, |&v| if v == 0 { '.' } else { '#' });
+/// assert_eq!(display.to_string(), ".#.\n...\n");
+/// ```
+pub struct DisplayGrid<'a, G, F> {
+    grid: &'a G,
+    to_char: F,
+}
+
+impl<'a, G, F> DisplayGrid<'a, G, F> {
+    /// Creates a new display wrapper over a grid.
+    pub const fn new(grid: &'a G, to_char: F) -> Self {
+        Self { grid, to_char }
+    }
+}
+
+impl<'a, G, F> Display for DisplayGrid<'a, G, F>
+where
+    G: GridRead + ExactSizeGrid,
+    F: Fn(G::Element<'a>) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                if let Some(elem) = self.grid.get(Pos::new(x, y)) {
+                    f.write_char((self.to_char)(elem))?;
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a grid as a string, one character per cell and one line per row.
+///
+/// This is a convenience over [`DisplayGrid`] for `println!`-style debugging:
+///
+/// ```rust
+/// use grixy::{buf::GridBuf, ops::render_ascii};
+///
+/// let grid = GridBuf::new_filled(2, 2, 0u8);
+/// assert_eq!(render_ascii(&grid, |&v| if v == 0 { '.' } else { '#' }), "..\n..\n");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn render_ascii<'a, G>(
+    grid: &'a G,
+    to_char: impl Fn(G::Element<'a>) -> char,
+) -> alloc::string::String
+where
+    G: GridRead + ExactSizeGrid,
+{
+    let mut out = alloc::string::String::with_capacity((grid.width() + 1) * grid.height());
+    let _ = write!(out, "{}", DisplayGrid::new(grid, to_char));
+    out
+}
+
+/// Renders a bit grid as braille characters, packing 2×4 cells per character.
+///
+/// Each character covers two columns and four rows, so a bitmap previews at four times the
+/// resolution of [`render_ascii`] in the same terminal area. Cells beyond the grid's edge are
+/// left unset, and each band of four rows is followed by a newline.
+///
+/// ```rust
+/// use grixy::{buf::bits::GridBits, core::Pos, ops::{GridWrite as _, layout::RowMajor, render_braille}};
+///
+/// let mut grid = GridBits::<u8, _, RowMajor>::new(2, 4);
+/// grid.set(Pos::new(0, 0), true).unwrap();
+/// grid.set(Pos::new(1, 3), true).unwrap();
+/// assert_eq!(render_braille(&grid), "\u{2881}\n");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn render_braille<G>(grid: &G) -> alloc::string::String
+where
+    for<'x> G: GridRead<Element<'x> = bool>,
+    G: ExactSizeGrid,
+{
+    // Braille dot bits for each (dy, dx) offset within the 2×4 block (U+2800 + bits).
+    const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+    let mut out = alloc::string::String::new();
+    for top in (0..grid.height()).step_by(4) {
+        for left in (0..grid.width()).step_by(2) {
+            let mut bits = 0;
+            for (dy, row) in DOTS.iter().enumerate() {
+                for (dx, dot) in row.iter().enumerate() {
+                    if grid.get(Pos::new(left + dx, top + dy)) == Some(true) {
+                        bits |= dot;
+                    }
+                }
+            }
+            out.push(char::from_u32(0x2800 + bits).unwrap_or('\u{2800}'));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders a color grid as half-block characters, packing 1×2 cells per character.
+///
+/// Each `▀` covers one column and two rows: the upper cell becomes the foreground color and the
+/// lower cell the background, selected with 24-bit SGR sequences. The mapping closure converts
+/// each element to an `(r, g, b)` triple. Rows beyond the grid's edge keep the terminal's
+/// default background, and each band of two rows ends with an SGR reset and a newline.
+///
+/// ```rust
+/// use grixy::{buf::GridBuf, ops::render_half_blocks};
+///
+/// let grid = GridBuf::new_filled(1, 2, 0xFF0000u32);
+/// let out = render_half_blocks(&grid, |&v| ((v >> 16) as u8, (v >> 8) as u8, v as u8));
+/// assert_eq!(out, "\u{1b}[38;2;255;0;0;48;2;255;0;0m\u{2580}\u{1b}[0m\n");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn render_half_blocks<G>(
+    grid: &G,
+    to_rgb: impl for<'x> Fn(G::Element<'x>) -> (u8, u8, u8),
+) -> alloc::string::String
+where
+    G: GridRead + ExactSizeGrid,
+{
+    let mut out = alloc::string::String::new();
+    for top in (0..grid.height()).step_by(2) {
+        for x in 0..grid.width() {
+            let Some((r, g, b)) = grid.get(Pos::new(x, top)).map(&to_rgb) else {
+                continue;
+            };
+            if let Some((r2, g2, b2)) = grid.get(Pos::new(x, top + 1)).map(&to_rgb) {
+                let _ = write!(out, "\x1b[38;2;{r};{g};{b};48;2;{r2};{g2};{b2}m\u{2580}");
+            } else {
+                let _ = write!(out, "\x1b[38;2;{r};{g};{b}m\u{2580}");
+            }
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::test::NaiveGrid;
+    use alloc::{string::ToString as _, vec};
+
+    use super::*;
+
+    #[test]
+    fn display_renders_rows_with_newlines() {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 2, vec![
+            0, 1, 0,
+            1, 0, 1,
+        ]);
+        let display = DisplayGrid::new(&grid, |&v| if v == 0 { '.' } else { '#' });
+        assert_eq!(display.to_string(), ".#.\n#.#\n");
+    }
+
+    #[test]
+    fn display_empty_grid_is_empty() {
+        let grid = NaiveGrid::<u8>::new(0, 0);
+        assert_eq!(DisplayGrid::new(&grid, |_| '?').to_string(), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn render_ascii_matches_display() {
+        let grid = NaiveGrid::with_cells(2, 2, vec![0u8, 1, 2, 3]);
+        let to_char = |v: &u8| char::from(b'a' + v);
+        assert_eq!(render_ascii(&grid, to_char), "ab\ncd\n");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn render_braille_packs_2x4_blocks() {
+        use crate::transform::GridConvertExt as _;
+
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(4, 4, vec![
+            true, true, true,  false,
+            true, true, false, false,
+            true, true, false, false,
+            true, true, false, false,
+        ]);
+        assert_eq!(render_braille(&grid.copied()), "\u{28FF}\u{2801}\n");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn render_braille_clips_partial_blocks() {
+        use crate::transform::GridConvertExt as _;
+
+        let grid = NaiveGrid::with_cells(1, 1, vec![true]);
+        assert_eq!(render_braille(&grid.copied()), "\u{2801}\n");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn render_half_blocks_pairs_rows() {
+        let grid = NaiveGrid::with_cells(1, 2, vec![1u8, 2]);
+        let out = render_half_blocks(&grid, |&v| (v, 0, 0));
+        assert_eq!(out, "\u{1b}[38;2;1;0;0;48;2;2;0;0m\u{2580}\u{1b}[0m\n");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn render_half_blocks_odd_height_keeps_default_background() {
+        let grid = NaiveGrid::with_cells(1, 3, vec![1u8, 2, 3]);
+        let out = render_half_blocks(&grid, |&v| (v, 0, 0));
+        assert_eq!(
+            out,
+            "\u{1b}[38;2;1;0;0;48;2;2;0;0m\u{2580}\u{1b}[0m\n\u{1b}[38;2;3;0;0m\u{2580}\u{1b}[0m\n"
+        );
+    }
+}
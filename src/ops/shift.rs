@@ -0,0 +1,237 @@
+use crate::{
+    core::{Pos, Rect},
+    ops::{ExactSizeGrid, GridRead, GridWrite},
+};
+
+/// Moves a rectangular region within a single grid, filling the vacated cells.
+///
+/// The region is copied so that its top-left corner lands at `to`, handling overlapping source
+/// and destination ranges correctly (like `memmove`). Cells of the source region that are not
+/// covered by the destination are set to `fill`.
+///
+/// Cells that fall out of bounds of the grid are clipped: out-of-bounds destination cells are not
+/// written, and out-of-bounds source cells are not read.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::{move_rect, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::<_, _, grixy::ops::layout::RowMajor>::from_buffer(vec![1, 2, 3, 0], 4);
+/// move_rect(&mut grid, Rect::from_ltwh(0, 0, 3, 1), Pos::new(1, 0), 0);
+///
+/// assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+/// assert_eq!(grid.get(Pos::new(1, 0)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(3, 0)), Some(&3));
+/// ```
+pub fn move_rect<T, G>(grid: &mut G, src: Rect, to: Pos, fill: T)
+where
+    T: Copy,
+    G: GridWrite<Element = T> + for<'x> GridRead<Element<'x> = &'x T>,
+{
+    let width = src.width();
+    let height = src.height();
+    let origin = src.top_left();
+
+    // Iterate away from the destination so overlapping cells are read before being overwritten.
+    let reverse_x = to.x > origin.x;
+    let reverse_y = to.y > origin.y;
+    for yi in 0..height {
+        let y = if reverse_y { height - 1 - yi } else { yi };
+        for xi in 0..width {
+            let x = if reverse_x { width - 1 - xi } else { xi };
+            let offset = Pos::new(x, y);
+            let Some(&value) = grid.get(origin + offset) else {
+                continue;
+            };
+            let _ = grid.set(to + offset, value);
+        }
+    }
+
+    let dst = Rect::from_ltwh(to.x, to.y, width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let pos = origin + Pos::new(x, y);
+            if !dst.contains_pos(pos) {
+                let _ = grid.set(pos, fill);
+            }
+        }
+    }
+}
+
+/// Shifts the entire contents of a grid by a signed delta, filling exposed cells.
+///
+/// Contents that fall off the edge of the grid are clipped, and cells exposed by the shift are
+/// set to `fill`. A delta of `(0, 0)` leaves the grid unchanged; a delta at least as large as
+/// the grid in either axis fills the entire grid.
+///
+/// For `RowMajor` buffers, [`GridBuf::scroll`][] shifts whole rows with slice copies instead of
+/// per-element reads and writes.
+///
+/// [`GridBuf::scroll`]: crate::buf::GridBuf::scroll
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{scroll, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::<_, _, grixy::ops::layout::RowMajor>::from_buffer(vec![1, 2, 3], 3);
+/// scroll(&mut grid, 1, 0, 0);
+///
+/// assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+/// assert_eq!(grid.get(Pos::new(1, 0)), Some(&1));
+/// assert_eq!(grid.get(Pos::new(2, 0)), Some(&2));
+/// ```
+pub fn scroll<T, G>(grid: &mut G, dx: isize, dy: isize, fill: T)
+where
+    T: Copy,
+    G: GridWrite<Element = T> + for<'x> GridRead<Element<'x> = &'x T> + ExactSizeGrid,
+{
+    let width = grid.width();
+    let height = grid.height();
+    let sx = dx.unsigned_abs();
+    let sy = dy.unsigned_abs();
+    if sx >= width || sy >= height {
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, width, height), fill);
+        return;
+    }
+
+    let src = Rect::from_ltwh(
+        if dx < 0 { sx } else { 0 },
+        if dy < 0 { sy } else { 0 },
+        width - sx,
+        height - sy,
+    );
+    let to = Pos::new(if dx > 0 { sx } else { 0 }, if dy > 0 { sy } else { 0 });
+
+    // The same directional copy as `move_rect`, inlined: re-dispatching through its
+    // higher-ranked `GridRead` bound from another generic function does not compile.
+    let origin = src.top_left();
+    let reverse_x = to.x > origin.x;
+    let reverse_y = to.y > origin.y;
+    for yi in 0..src.height() {
+        let y = if reverse_y { src.height() - 1 - yi } else { yi };
+        for xi in 0..src.width() {
+            let x = if reverse_x { src.width() - 1 - xi } else { xi };
+            let offset = Pos::new(x, y);
+            let Some(&value) = grid.get(origin + offset) else {
+                continue;
+            };
+            let _ = grid.set(to + offset, value);
+        }
+    }
+
+    // The exposed strips cover every vacated source cell, so no separate back-fill is needed.
+    if dx > 0 {
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, sx, height), fill);
+    } else if dx < 0 {
+        grid.fill_rect_solid(Rect::from_ltwh(width - sx, 0, sx, height), fill);
+    }
+    if dy > 0 {
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, width, sy), fill);
+    } else if dy < 0 {
+        grid.fill_rect_solid(Rect::from_ltwh(0, height - sy, width, sy), fill);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::test::NaiveGrid;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn move_rect_right_overlapping() {
+        let mut grid = NaiveGrid::<i32>::with_cells(4, 1, [1, 2, 3, 4]);
+
+        move_rect(&mut grid, Rect::from_ltwh(0, 0, 3, 1), Pos::new(1, 0), 0);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn move_rect_left_overlapping() {
+        let mut grid = NaiveGrid::<i32>::with_cells(4, 1, [1, 2, 3, 4]);
+
+        move_rect(&mut grid, Rect::from_ltwh(1, 0, 3, 1), Pos::new(0, 0), 0);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[2, 3, 4, 0]);
+    }
+
+    #[test]
+    fn move_rect_down_right_fills_vacated() {
+        #[rustfmt::skip]
+        let mut grid = NaiveGrid::<i32>::with_cells(3, 3, [
+            1, 2, 0,
+            3, 4, 0,
+            0, 0, 0,
+        ]);
+
+        move_rect(&mut grid, Rect::from_ltwh(0, 0, 2, 2), Pos::new(1, 1), 9);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            9, 9, 0,
+            9, 1, 2,
+            0, 3, 4,
+        ]);
+    }
+
+    #[test]
+    fn move_rect_clips_out_of_bounds_destination() {
+        let mut grid = NaiveGrid::<i32>::with_cells(2, 1, [1, 2]);
+
+        move_rect(&mut grid, Rect::from_ltwh(0, 0, 2, 1), Pos::new(1, 0), 0);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[0, 1]);
+    }
+
+    #[test]
+    fn scroll_right() {
+        let mut grid = NaiveGrid::<i32>::with_cells(3, 1, [1, 2, 3]);
+
+        scroll(&mut grid, 1, 0, 0);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn scroll_up_left() {
+        #[rustfmt::skip]
+        let mut grid = NaiveGrid::<i32>::with_cells(3, 3, [
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ]);
+
+        scroll(&mut grid, -1, -1, 0);
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            5, 6, 0,
+            8, 9, 0,
+            0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn scroll_entire_grid_fills() {
+        let mut grid = NaiveGrid::<i32>::with_cells(2, 2, [1, 2, 3, 4]);
+
+        scroll(&mut grid, 2, 0, 9);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[9, 9, 9, 9]);
+    }
+
+    #[test]
+    fn scroll_zero_is_noop() {
+        let mut grid = NaiveGrid::<i32>::with_cells(2, 2, [1, 2, 3, 4]);
+
+        scroll(&mut grid, 0, 0, 9);
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[1, 2, 3, 4]);
+    }
+}
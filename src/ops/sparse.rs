@@ -0,0 +1,22 @@
+use crate::core::Pos;
+
+/// Read only the populated cells of a grid.
+///
+/// Implementations yield `(position, value)` pairs for populated cells without visiting every
+/// cell where the representation allows it; rendering a mostly-empty overlay does not need to
+/// scan the empty space. What counts as _populated_ is representation-specific: set bits for
+/// `GridBits`, and `Some` cells for `GridBuf<Option<T>>`.
+///
+/// For grids without a sparse representation, filter
+/// [`GridIter::iter_with_pos`](crate::ops::GridIter::iter_with_pos) instead.
+pub trait GridSparse {
+    /// The type of values yielded for populated cells.
+    type Value<'a>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over `(position, value)` pairs for populated cells only.
+    ///
+    /// Positions are yielded in the grid's storage order.
+    fn iter_set(&self) -> impl Iterator<Item = (Pos, Self::Value<'_>)>;
+}
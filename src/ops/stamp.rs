@@ -0,0 +1,128 @@
+use crate::{
+    core::Pos,
+    ops::{ExactSizeGrid, GridRead, GridWrite},
+};
+
+/// Applies a brush to a grid, centered at a point, skipping empty brush cells.
+///
+/// The brush is any grid of `Option<T>` elements; `None` cells are transparent and leave the
+/// destination unchanged. Each `Some` cell is combined with the current destination element via
+/// `blend` before being written. Cells that fall out of bounds of the destination (including
+/// those clipped above or left of the origin) are skipped.
+///
+/// The brush's anchor is its center, rounded down for even dimensions.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Pos, ops::{stamp, GridRead}, buf::GridBuf, transform::GridConvertExt as _};
+///
+/// let brush = GridBuf::<_, _, grixy::ops::layout::RowMajor>::from_buffer(
+///     vec![None, Some(1), None, Some(1), Some(1), Some(1), None, Some(1), None],
+///     3,
+/// );
+/// let mut dst = GridBuf::new_filled(5, 5, 0);
+/// stamp(&mut dst, &brush.copied(), Pos::new(2, 2), |_, new| new);
+///
+/// assert_eq!(dst.get(Pos::new(2, 2)), Some(&1)); // center
+/// assert_eq!(dst.get(Pos::new(1, 1)), Some(&0)); // transparent corner
+/// ```
+pub fn stamp<'a, T, G>(
+    dst: &mut G,
+    brush: &'a (impl GridRead<Element<'a> = Option<T>> + ExactSizeGrid),
+    center: Pos,
+    blend: impl Fn(<G as GridRead>::Element<'_>, T) -> <G as GridWrite>::Element,
+) where
+    G: GridRead + GridWrite,
+{
+    let brush_width = brush.width();
+    let brush_height = brush.height();
+    for y in 0..brush_height {
+        for x in 0..brush_width {
+            let Some(Some(value)) = brush.get(Pos::new(x, y)) else {
+                continue;
+            };
+            let (Some(dst_x), Some(dst_y)) = (
+                (center.x + x).checked_sub(brush_width / 2),
+                (center.y + y).checked_sub(brush_height / 2),
+            ) else {
+                continue;
+            };
+            let pos = Pos::new(dst_x, dst_y);
+            let Some(current) = dst.get(pos) else {
+                continue;
+            };
+            let value = blend(current, value);
+            let _ = dst.set(pos, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{test::NaiveGrid, transform::GridConvertExt as _};
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn cross_brush() -> NaiveGrid<Option<i32>> {
+        #[rustfmt::skip]
+        let brush = NaiveGrid::with_cells(3, 3, [
+            None,    Some(1), None,
+            Some(1), Some(1), Some(1),
+            None,    Some(1), None,
+        ]);
+        brush
+    }
+
+    #[test]
+    fn stamp_centered() {
+        let brush = cross_brush();
+        let mut dst = NaiveGrid::<i32>::new(5, 5);
+
+        stamp(&mut dst, &brush.copied(), Pos::new(2, 2), |_, new| new);
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 1, 1, 1, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn stamp_clips_at_origin() {
+        let brush = cross_brush();
+        let mut dst = NaiveGrid::<i32>::new(3, 3);
+
+        stamp(&mut dst, &brush.copied(), Pos::new(0, 0), |_, new| new);
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            1, 1, 0,
+            1, 0, 0,
+            0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn stamp_blends_with_destination() {
+        let brush = cross_brush();
+        let mut dst = NaiveGrid::<i32>::with_cells(3, 3, [10; 9]);
+
+        stamp(&mut dst, &brush.copied(), Pos::new(1, 1), |current, new| {
+            current + new
+        });
+
+        #[rustfmt::skip]
+        assert_eq!(dst.into_iter().collect::<Vec<_>>(), &[
+            10, 11, 10,
+            11, 11, 11,
+            10, 11, 10,
+        ]);
+    }
+}
@@ -0,0 +1,150 @@
+//! Statistics over rectangular grid regions.
+//!
+//! All operations trim the requested bounds to the grid and iterate with [`GridRead::iter_rect`],
+//! so buffer-backed grids use their aligned slice paths. Heat-map normalization typically pairs
+//! [`min_rect`] and [`max_rect`] per frame.
+
+use crate::{core::Rect, ops::GridRead};
+
+/// Returns the smallest element in a region, or `None` if the region is empty.
+///
+/// For partially ordered elements (such as floats), the result is the first element no other
+/// element compares less than.
+pub fn min_rect<G>(grid: &G, bounds: Rect) -> Option<G::Element<'_>>
+where
+    G: GridRead,
+    for<'x> G::Element<'x>: PartialOrd,
+{
+    grid.iter_rect(grid.trim_rect(bounds))
+        .reduce(|min, elem| if elem < min { elem } else { min })
+}
+
+/// Returns the largest element in a region, or `None` if the region is empty.
+///
+/// For partially ordered elements (such as floats), the result is the first element no other
+/// element compares greater than.
+pub fn max_rect<G>(grid: &G, bounds: Rect) -> Option<G::Element<'_>>
+where
+    G: GridRead,
+    for<'x> G::Element<'x>: PartialOrd,
+{
+    grid.iter_rect(grid.trim_rect(bounds))
+        .reduce(|max, elem| if elem > max { elem } else { max })
+}
+
+/// Returns the arithmetic mean of a region, or `None` if the region is empty.
+///
+/// Elements are accumulated as `f64`; for grids of references, use
+/// [`copied`](crate::transform::GridConvertExt::copied) first.
+pub fn mean_rect<'a, G>(grid: &'a G, bounds: Rect) -> Option<f64>
+where
+    G: GridRead,
+    G::Element<'a>: Into<f64>,
+{
+    let mut count: u32 = 0;
+    let mut total = 0.0;
+    for elem in grid.iter_rect(grid.trim_rect(bounds)) {
+        total += elem.into();
+        count += 1;
+    }
+    (count > 0).then(|| total / f64::from(count))
+}
+
+/// Counts the elements of a region into `N` buckets.
+///
+/// Each element is assigned a bucket index by `bucket`; indices of `N` or above are ignored, so
+/// a catch-all bucket must be mapped explicitly. A full `u8` histogram is
+/// `histogram_rect::<256, _>(grid, bounds, |&v| usize::from(v))`.
+pub fn histogram_rect<'a, const N: usize, G>(
+    grid: &'a G,
+    bounds: Rect,
+    bucket: impl Fn(G::Element<'a>) -> usize,
+) -> [usize; N]
+where
+    G: GridRead,
+{
+    let mut histogram = [0; N];
+    for elem in grid.iter_rect(grid.trim_rect(bounds)) {
+        if let Some(count) = histogram.get_mut(bucket(elem)) {
+            *count += 1;
+        }
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{core::Pos, test::NaiveGrid, transform::GridConvertExt as _};
+    use alloc::vec;
+
+    use super::*;
+
+    fn grid() -> NaiveGrid<u8> {
+        #[rustfmt::skip]
+        let grid = NaiveGrid::with_cells(3, 2, vec![
+            5, 1, 9,
+            3, 7, 2,
+        ]);
+        grid
+    }
+
+    #[test]
+    fn min_and_max_over_full_grid() {
+        let grid = grid();
+        let bounds = Rect::from_ltwh(0, 0, 3, 2);
+        assert_eq!(min_rect(&grid, bounds), Some(&1));
+        assert_eq!(max_rect(&grid, bounds), Some(&9));
+    }
+
+    #[test]
+    fn min_and_max_respect_bounds() {
+        let grid = grid();
+        let bounds = Rect::from_ltwh(0, 1, 2, 1);
+        assert_eq!(min_rect(&grid, bounds), Some(&3));
+        assert_eq!(max_rect(&grid, bounds), Some(&7));
+    }
+
+    #[test]
+    fn empty_region_is_none() {
+        let grid = grid();
+        let bounds = Rect::from_ltwh(5, 5, 2, 2);
+        assert_eq!(min_rect(&grid, bounds), None);
+        assert_eq!(mean_rect(&grid.copied(), bounds), None);
+    }
+
+    #[test]
+    fn mean_over_region() {
+        let grid = grid().copied();
+        let mean = mean_rect(&grid, Rect::from_ltwh(0, 0, 3, 1)).unwrap();
+        assert!((mean - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn histogram_counts_buckets() {
+        let grid = grid();
+        let histogram: [usize; 10] =
+            histogram_rect(&grid, Rect::from_ltwh(0, 0, 3, 2), |&v| usize::from(v));
+        assert_eq!(histogram[5], 1);
+        assert_eq!(histogram[9], 1);
+        assert_eq!(histogram[0], 0);
+        assert_eq!(histogram.iter().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn histogram_ignores_overflow_buckets() {
+        let grid = grid();
+        let histogram: [usize; 4] =
+            histogram_rect(&grid, Rect::from_ltwh(0, 0, 3, 2), |&v| usize::from(v));
+        assert_eq!(histogram.iter().sum::<usize>(), 3); // Only 1, 3, and 2 fit.
+    }
+
+    #[test]
+    fn trims_out_of_bounds_requests() {
+        let grid = grid();
+        assert_eq!(max_rect(&grid, Rect::from_ltwh(1, 0, 100, 100)), Some(&9));
+        let pos = Pos::new(1, 0);
+        assert_eq!(grid.get(pos), Some(&1));
+    }
+}
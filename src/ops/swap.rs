@@ -0,0 +1,187 @@
+use crate::{
+    core::{Pos, Rect},
+    ops::{GridRead, GridWrite},
+};
+
+/// Exchanges two equal-size rectangular regions between two grids element-wise.
+///
+/// The regions are trimmed to their common size; if either rectangle is smaller than the other,
+/// only the overlapping top-left portion is exchanged. Cells that are out of bounds of either
+/// grid are skipped, leaving both grids unchanged at those positions.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::{swap_rect, GridRead}, buf::GridBuf};
+///
+/// let mut a = GridBuf::new_filled(2, 2, 1);
+/// let mut b = GridBuf::new_filled(2, 2, 2);
+/// swap_rect(&mut a, Rect::from_ltwh(0, 0, 2, 2), &mut b, Rect::from_ltwh(0, 0, 2, 2));
+///
+/// assert_eq!(a.get(Pos::new(0, 0)), Some(&2));
+/// assert_eq!(b.get(Pos::new(0, 0)), Some(&1));
+/// ```
+pub fn swap_rect<T, A, B>(a: &mut A, rect_a: Rect, b: &mut B, rect_b: Rect)
+where
+    T: Copy,
+    A: GridWrite<Element = T> + for<'x> GridRead<Element<'x> = &'x T>,
+    B: GridWrite<Element = T> + for<'x> GridRead<Element<'x> = &'x T>,
+{
+    let width = rect_a.width().min(rect_b.width());
+    let height = rect_a.height().min(rect_b.height());
+    for y in 0..height {
+        for x in 0..width {
+            let pos_a = rect_a.top_left() + Pos::new(x, y);
+            let pos_b = rect_b.top_left() + Pos::new(x, y);
+            let (Some(&val_a), Some(&val_b)) = (a.get(pos_a), b.get(pos_b)) else {
+                continue;
+            };
+            let _ = a.set(pos_a, val_b);
+            let _ = b.set(pos_b, val_a);
+        }
+    }
+}
+
+/// Exchanges two equal-size rectangular regions within a single grid element-wise.
+///
+/// The regions are trimmed to their common size, and out-of-bounds cells are skipped, as with
+/// [`swap_rect`]. If the regions overlap, each pair of cells is exchanged sequentially in the
+/// traversal order of the grid's layout, so overlapping cells may be exchanged more than once;
+/// disjoint regions behave exactly like [`swap_rect`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::{Pos, Rect}, ops::{swap_rect_within, GridRead}, buf::GridBuf};
+///
+/// let mut grid = GridBuf::new_filled(4, 2, 0u8);
+/// grid.fill_rect_solid(Rect::from_ltwh(0, 0, 2, 2), 1);
+/// swap_rect_within(&mut grid, Rect::from_ltwh(0, 0, 2, 2), Rect::from_ltwh(2, 0, 2, 2));
+///
+/// assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+/// assert_eq!(grid.get(Pos::new(2, 0)), Some(&1));
+/// ```
+pub fn swap_rect_within<T, G>(grid: &mut G, rect_a: Rect, rect_b: Rect)
+where
+    T: Copy,
+    G: GridWrite<Element = T> + for<'x> GridRead<Element<'x> = &'x T>,
+{
+    let width = rect_a.width().min(rect_b.width());
+    let height = rect_a.height().min(rect_b.height());
+    for y in 0..height {
+        for x in 0..width {
+            let pos_a = rect_a.top_left() + Pos::new(x, y);
+            let pos_b = rect_b.top_left() + Pos::new(x, y);
+            if pos_a == pos_b {
+                continue;
+            }
+            let (Some(&val_a), Some(&val_b)) = (grid.get(pos_a), grid.get(pos_b)) else {
+                continue;
+            };
+            let _ = grid.set(pos_a, val_b);
+            let _ = grid.set(pos_b, val_a);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::test::NaiveGrid;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn swap_rect_between_grids() {
+        #[rustfmt::skip]
+        let mut a = NaiveGrid::<i32>::with_cells(2, 2, [
+            1, 2,
+            3, 4,
+        ]);
+        let mut b = NaiveGrid::<i32>::new(2, 2);
+
+        swap_rect(
+            &mut a,
+            Rect::from_ltwh(0, 0, 2, 2),
+            &mut b,
+            Rect::from_ltwh(0, 0, 2, 2),
+        );
+
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), &[0, 0, 0, 0]);
+        assert_eq!(b.into_iter().collect::<Vec<_>>(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn swap_rect_trims_to_common_size() {
+        let mut a = NaiveGrid::<i32>::with_cells(2, 2, [1, 1, 1, 1]);
+        let mut b = NaiveGrid::<i32>::new(2, 2);
+
+        swap_rect(
+            &mut a,
+            Rect::from_ltwh(0, 0, 1, 1),
+            &mut b,
+            Rect::from_ltwh(0, 0, 2, 2),
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(b.into_iter().collect::<Vec<_>>(), &[
+            1, 0,
+            0, 0,
+        ]);
+    }
+
+    #[test]
+    fn swap_rect_skips_out_of_bounds() {
+        let mut a = NaiveGrid::<i32>::with_cells(2, 2, [1, 2, 3, 4]);
+        let mut b = NaiveGrid::<i32>::new(2, 2);
+
+        swap_rect(
+            &mut a,
+            Rect::from_ltwh(1, 1, 2, 2),
+            &mut b,
+            Rect::from_ltwh(0, 0, 2, 2),
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(b.into_iter().collect::<Vec<_>>(), &[
+            4, 0,
+            0, 0,
+        ]);
+    }
+
+    #[test]
+    fn swap_rect_within_disjoint() {
+        #[rustfmt::skip]
+        let mut grid = NaiveGrid::<i32>::with_cells(4, 2, [
+            1, 1, 2, 2,
+            1, 1, 2, 2,
+        ]);
+
+        swap_rect_within(
+            &mut grid,
+            Rect::from_ltwh(0, 0, 2, 2),
+            Rect::from_ltwh(2, 0, 2, 2),
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[
+            2, 2, 1, 1,
+            2, 2, 1, 1,
+        ]);
+    }
+
+    #[test]
+    fn swap_rect_within_identical_rects_is_noop() {
+        let mut grid = NaiveGrid::<i32>::with_cells(2, 2, [1, 2, 3, 4]);
+
+        swap_rect_within(
+            &mut grid,
+            Rect::from_ltwh(0, 0, 2, 2),
+            Rect::from_ltwh(0, 0, 2, 2),
+        );
+
+        assert_eq!(grid.into_iter().collect::<Vec<_>>(), &[1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,137 @@
+extern crate std;
+
+use std::sync::{Mutex, RwLock};
+
+use crate::{
+    core::{Pos, Rect},
+    ops::{GridBase, GridRead, GridWrite},
+};
+
+macro_rules! impl_grid_base {
+    ($lock:ident, $read:ident) => {
+        impl<T> GridBase for $lock<T>
+        where
+            T: GridBase,
+        {
+            fn size_hint(&self) -> (crate::core::Size, Option<crate::core::Size>) {
+                self.$read().expect("Lock poisoned").size_hint()
+            }
+        }
+    };
+}
+
+macro_rules! impl_grid_read {
+    ($lock:ident, $read:ident) => {
+        /// Reads lock the grid for the duration of each call and return owned elements.
+        ///
+        /// Grids whose elements are references cannot be read through a lock, since the
+        /// borrow would outlive the guard; the `'static` bound restricts the impl to owned
+        /// element types.
+        impl<T, O> GridRead for $lock<T>
+        where
+            for<'a> T: GridRead<Element<'a> = O> + 'a,
+            O: 'static,
+        {
+            type Element<'b>
+                = O
+            where
+                Self: 'b;
+
+            type Layout = T::Layout;
+
+            fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+                self.$read().expect("Lock poisoned").get(pos)
+            }
+        }
+    };
+}
+
+macro_rules! impl_grid_write {
+    ($lock:ident) => {
+        impl<T> GridWrite for $lock<T>
+        where
+            T: GridWrite,
+        {
+            type Element = T::Element;
+            type Layout = T::Layout;
+
+            fn set(
+                &mut self,
+                pos: Pos,
+                value: Self::Element,
+            ) -> Result<(), crate::core::GridError> {
+                self.get_mut().expect("Lock poisoned").set(pos, value)
+            }
+
+            fn fill_rect(&mut self, bounds: Rect, f: impl FnMut(Pos) -> Self::Element) {
+                self.get_mut().expect("Lock poisoned").fill_rect(bounds, f);
+            }
+
+            fn fill_rect_iter(&mut self, dst: Rect, iter: impl IntoIterator<Item = Self::Element>) {
+                self.get_mut()
+                    .expect("Lock poisoned")
+                    .fill_rect_iter(dst, iter);
+            }
+
+            fn fill_rect_solid(&mut self, dst: Rect, value: Self::Element)
+            where
+                Self::Element: Copy,
+            {
+                self.get_mut()
+                    .expect("Lock poisoned")
+                    .fill_rect_solid(dst, value);
+            }
+        }
+    };
+}
+
+impl_grid_base!(Mutex, lock);
+impl_grid_base!(RwLock, read);
+impl_grid_read!(Mutex, lock);
+impl_grid_read!(RwLock, read);
+impl_grid_write!(Mutex);
+impl_grid_write!(RwLock);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test::NaiveGrid;
+
+    fn test_grid_write<'a>(grid: &mut (impl GridWrite<Element = u8> + 'a)) {
+        grid.set(Pos::new(1, 1), 42).unwrap();
+        grid.fill_rect(Rect::from_ltwh(0, 0, 3, 3), |_| 0);
+        grid.fill_rect_iter(Rect::from_ltwh(0, 0, 3, 3), [1, 2, 3]);
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, 3, 3), 99);
+    }
+
+    #[test]
+    fn test_mutex_grid_write() {
+        let mut grid = Mutex::new(NaiveGrid::new(3, 3));
+        test_grid_write(&mut grid);
+    }
+
+    #[test]
+    fn test_rwlock_grid_write() {
+        let mut grid = RwLock::new(NaiveGrid::new(3, 3));
+        test_grid_write(&mut grid);
+    }
+
+    #[test]
+    fn test_mutex_grid_read() {
+        use crate::transform::GridConvertExt as _;
+
+        let grid = Mutex::new(NaiveGrid::<u8>::new(3, 3).copied());
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(0));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_rwlock_grid_read() {
+        use crate::transform::GridConvertExt as _;
+
+        let grid = RwLock::new(NaiveGrid::<u8>::new(3, 3).copied());
+        assert_eq!(grid.get(Pos::new(2, 2)), Some(0));
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+    }
+}
@@ -10,7 +10,10 @@ mod write;
 pub use read::GridReadUnchecked;
 pub use write::GridWriteUnchecked;
 
-use crate::ops::ExactSizeGrid;
+use crate::{
+    core::{Pos, Rect},
+    ops::ExactSizeGrid,
+};
 
 /// A grid that reports an accurate size using `size_hint()`.
 ///
@@ -34,3 +37,115 @@ use crate::ops::ExactSizeGrid;
 /// [`GridRead`]: crate::ops::GridRead
 /// [`GridWrite`]: crate::ops::GridWrite
 pub unsafe trait TrustedSizeGrid: ExactSizeGrid {}
+
+/// Copies a rectangular region through a blend function without bounds checking.
+///
+/// This is the unchecked counterpart of [`blit_rect`][]: each source element is combined with the
+/// current destination element via `blend` before being written, but no rectangle is clipped and
+/// no per-cell bounds checks are performed.
+///
+/// ## Safety
+///
+/// The caller must ensure that **every position** in `from` is a valid position in `src`, and
+/// that every corresponding destination position (`to` offset by the position within `from`) is a
+/// valid position in `dst`. A position `(x, y)` is valid if `x < width()` and `y < height()`.
+///
+/// Reading or writing memory outside either grid's allocated storage is _[undefined behavior][]_.
+///
+/// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+/// [`blit_rect`]: crate::ops::blit_rect
+pub unsafe fn blit_rect_unchecked<'a, E, G>(
+    src: &'a impl GridReadUnchecked<Element<'a> = E>,
+    dst: &mut G,
+    from: Rect,
+    to: Pos,
+    blend: impl Fn(<G as GridReadUnchecked>::Element<'_>, E) -> <G as GridWriteUnchecked>::Element,
+) where
+    G: GridReadUnchecked + GridWriteUnchecked,
+{
+    for y in 0..from.height() {
+        for x in 0..from.width() {
+            let offset = Pos::new(x, y);
+            let value = unsafe { src.get_unchecked(from.top_left() + offset) };
+            let current = unsafe { dst.get_unchecked(to + offset) };
+            let value = blend(current, value);
+            unsafe { dst.set_unchecked(to + offset, value) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::layout::RowMajor;
+
+    struct UncheckedTestGrid {
+        grid: [[u8; 3]; 3],
+    }
+
+    impl GridReadUnchecked for UncheckedTestGrid {
+        type Element<'a> = u8;
+        type Layout = RowMajor;
+
+        unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
+            self.grid[pos.y][pos.x]
+        }
+    }
+
+    impl GridWriteUnchecked for UncheckedTestGrid {
+        type Element = u8;
+        type Layout = RowMajor;
+
+        unsafe fn set_unchecked(&mut self, pos: Pos, value: Self::Element) {
+            self.grid[pos.y][pos.x] = value;
+        }
+    }
+
+    #[test]
+    fn blit_rect_unchecked_blends_with_destination() {
+        let src = UncheckedTestGrid {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let mut dst = UncheckedTestGrid { grid: [[10; 3]; 3] };
+        unsafe {
+            blit_rect_unchecked(
+                &src,
+                &mut dst,
+                Rect::from_ltwh(0, 0, 2, 2),
+                Pos::new(1, 1),
+                |current, new| current + new,
+            );
+        }
+
+        #[rustfmt::skip]
+        assert_eq!(dst.grid, [
+            [10, 10, 10],
+            [10, 11, 12],
+            [10, 14, 15],
+        ]);
+    }
+
+    #[test]
+    fn blit_rect_unchecked_overwrite() {
+        let src = UncheckedTestGrid {
+            grid: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        let mut dst = UncheckedTestGrid { grid: [[0; 3]; 3] };
+        unsafe {
+            blit_rect_unchecked(
+                &src,
+                &mut dst,
+                Rect::from_ltwh(1, 1, 2, 2),
+                Pos::new(0, 0),
+                |_, new| new,
+            );
+        }
+
+        #[rustfmt::skip]
+        assert_eq!(dst.grid, [
+            [5, 6, 0],
+            [8, 9, 0],
+            [0, 0, 0],
+        ]);
+    }
+}
@@ -3,11 +3,15 @@ use ixy::HasSize;
 use crate::{
     core::{GridError, Pos, Rect},
     ops::{
-        ExactSizeGrid, GridBase,
+        ExactSizeGrid, GridBase, GridRead,
         layout::{self, Traversal as _},
     },
 };
 
+/// Largest rect dimension (in cells) that takes the direct per-cell path instead of building a
+/// layout position iterator; glyph-sized blits spend a large fraction of time in iterator setup.
+pub(crate) const SMALL_RECT_MAX: usize = 8;
+
 /// Write elements to a 2-dimensional grid position.
 pub trait GridWrite: GridBase {
     /// The type of elements in the grid.
@@ -31,9 +35,91 @@ pub trait GridWrite: GridBase {
     /// Returns an error if the position is out of bounds.
     fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), GridError>;
 
+    /// Sets the element at a position given as any [`GridIndex`].
+    ///
+    /// This accepts `(x, y)` tuples and `[x, y]` arrays as well as [`Pos`]; see
+    /// [`GridRead::get_at`][] for the read-side counterpart.
+    ///
+    /// [`GridIndex`]: crate::core::GridIndex
+    /// [`GridRead::get_at`]: crate::ops::GridRead::get_at
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the position is out of bounds.
+    fn set_at(
+        &mut self,
+        index: impl crate::core::GridIndex,
+        value: Self::Element,
+    ) -> Result<(), GridError> {
+        self.set(index.into_pos(), value)
+    }
+
+    /// Sets the element at a signed position.
+    ///
+    /// This is the write-side counterpart of [`GridRead::get_signed`][]: world-coordinate code
+    /// with an `i32` origin can write without converting at every call site.
+    ///
+    /// [`GridRead::get_signed`]: crate::ops::GridRead::get_signed
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the position is out of bounds. Negative coordinates are out of bounds;
+    /// in the reported position they saturate to `usize::MAX`.
+    fn set_signed(
+        &mut self,
+        pos: crate::core::SignedPos,
+        value: Self::Element,
+    ) -> Result<(), GridError> {
+        let x = usize::try_from(pos.x).unwrap_or(usize::MAX);
+        let y = usize::try_from(pos.y).unwrap_or(usize::MAX);
+        self.set(Pos::new(x, y), value)
+    }
+
+    /// Sets the element at a specified position, returning the previous value.
+    ///
+    /// This is a single-call alternative to a `get` followed by a `set` for grids that can also
+    /// read. Entity-on-tile logic that needs the displaced occupant avoids spelling out the pair
+    /// at every call site.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the position is out of bounds; the grid is left unchanged.
+    fn replace(
+        &mut self,
+        pos: Pos,
+        value: <Self as GridWrite>::Element,
+    ) -> Result<<Self as GridWrite>::Element, GridError>
+    where
+        Self: for<'x> GridRead<Element<'x> = &'x <Self as GridWrite>::Element>,
+        <Self as GridWrite>::Element: Copy,
+    {
+        let previous = *self.get(pos).ok_or(GridError::OutOfBounds { pos })?;
+        self.set(pos, value)?;
+        Ok(previous)
+    }
+
+    /// Copies an entire same-sized source grid into this grid.
+    ///
+    /// Elements are copied in an order agreeable to the grid's internal layout. If the source is
+    /// a different size, elements are paired in traversal order, so rows will misalign; callers
+    /// should ensure both grids have the same dimensions. For copies between linear `RowMajor`
+    /// buffers, `GridBuf::copy_rect_from` moves whole rows with slice copies instead.
+    fn copy_from<'a>(
+        &mut self,
+        src: &'a (impl GridRead<Element<'a> = Self::Element> + ExactSizeGrid),
+    ) where
+        Self: ExactSizeGrid,
+    {
+        self.fill_rect_iter(self.size().to_rect(), src.iter_rect(src.size().to_rect()));
+    }
+
     /// Clears the grid, setting all elements to their default value.
     ///
     /// Elements are set in an order agreeable to the grid's internal layout.
+    ///
+    /// To reset the grid to a specific value instead of the default, use
+    /// [`fill_solid`](GridWrite::fill_solid), which covers the entire grid without a manually
+    /// constructed full-size rectangle.
     fn clear(&mut self)
     where
         Self::Element: Default,
@@ -64,7 +150,9 @@ pub trait GridWrite: GridBase {
 
     /// Sets elements within the grid to a single value.
     ///
-    /// Elements are set in an order agreeable to the grid's internal layout.
+    /// Elements are set in an order agreeable to the grid's internal layout. This is the
+    /// value-taking counterpart of [`clear`](GridWrite::clear): the whole grid is covered, and
+    /// linear buffers take the aligned `fill` fast path.
     fn fill_solid(&mut self, value: Self::Element)
     where
         Self::Element: Copy,
@@ -157,7 +245,16 @@ pub trait GridWrite: GridBase {
     where
         Self::Element: Copy,
     {
-        self.fill_rect(self.trim_rect(dst), |_| value);
+        let dst = self.trim_rect(dst);
+        if dst.width() <= SMALL_RECT_MAX && dst.height() <= SMALL_RECT_MAX {
+            for y in dst.top_left().y..dst.bottom() {
+                for x in dst.top_left().x..dst.right() {
+                    let _ = self.set(Pos::new(x, y), value);
+                }
+            }
+            return;
+        }
+        self.fill_rect(dst, |_| value);
     }
 }
 
@@ -204,6 +301,17 @@ mod tests {
         assert_eq!(grid.grid[1][1], 42);
     }
 
+    #[test]
+    fn impl_set_signed() {
+        let mut grid = TestGrid { grid: [[0; 3]; 3] };
+        grid.set_signed(crate::core::SignedPos::new(1, 1), 42)
+            .unwrap();
+        assert_eq!(grid.grid[1][1], 42);
+        grid.set_signed(crate::core::SignedPos::new(-1, 1), 9)
+            .unwrap_err();
+        assert_eq!(grid.grid, [[0, 0, 0], [0, 42, 0], [0, 0, 0]]);
+    }
+
     #[test]
     fn impl_checked_set_out_of_bounds_x() {
         let mut grid = TestGrid { grid: [[0; 3]; 3] };
@@ -243,4 +351,51 @@ mod tests {
         grid.fill_rect_solid(bounds, 42);
         assert_eq!(grid.grid, [[42; 3]; 3]);
     }
+
+    #[test]
+    fn copy_from_same_size() {
+        use crate::transform::GridConvertExt as _;
+
+        let src = crate::test::NaiveGrid::<u8>::with_cells(2, 2, [1, 2, 3, 4]);
+        let mut dst = crate::test::NaiveGrid::<u8>::new(2, 2);
+        dst.copy_from(&src.copied());
+
+        assert_eq!(
+            dst.into_iter().collect::<alloc::vec::Vec<_>>(),
+            &[1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn copy_from_smaller_source_leaves_remainder() {
+        use crate::transform::GridConvertExt as _;
+
+        let src = crate::test::NaiveGrid::<u8>::with_cells(1, 1, [9]);
+        let mut dst = crate::test::NaiveGrid::<u8>::new(2, 2);
+        dst.copy_from(&src.copied());
+
+        assert_eq!(
+            dst.into_iter().collect::<alloc::vec::Vec<_>>(),
+            &[9, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn replace_returns_previous() {
+        let mut grid = crate::test::NaiveGrid::<u8>::with_cells(2, 2, [1, 2, 3, 4]);
+        let previous = grid.replace(Pos::new(1, 0), 9).unwrap();
+        assert_eq!(previous, 2);
+        assert_eq!(crate::ops::GridRead::get(&grid, Pos::new(1, 0)), Some(&9));
+    }
+
+    #[test]
+    fn replace_out_of_bounds() {
+        let mut grid = crate::test::NaiveGrid::<u8>::new(2, 2);
+        assert_eq!(
+            grid.replace(Pos::new(2, 2), 9),
+            Err(GridError::OutOfBounds {
+                pos: Pos::new(2, 2)
+            })
+        );
+    }
 }
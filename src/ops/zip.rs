@@ -0,0 +1,76 @@
+use crate::{
+    core::Rect,
+    ops::{GridRead, layout::Traversal as _},
+};
+
+/// Returns an iterator over paired elements from two grids within a rectangular region.
+///
+/// Elements are yielded in the traversal order of the first grid's layout; the bounding
+/// rectangle is trimmed to both grids, so only positions in bounds of each are paired. This
+/// allows per-cell comparisons between two frames without collecting either side first; to find
+/// only the positions that differ, see [`GridDiff::diff`][].
+///
+/// [`GridDiff::diff`]: crate::ops::GridDiff::diff
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::Rect, ops::zip_rect, buf::GridBuf};
+///
+/// let a = GridBuf::new_filled(2, 2, 1);
+/// let b = GridBuf::new_filled(2, 2, 2);
+/// let sums: Vec<_> = zip_rect(&a, &b, Rect::from_ltwh(0, 0, 2, 2))
+///     .map(|(a, b)| a + b)
+///     .collect();
+///
+/// assert_eq!(sums, [3, 3, 3, 3]);
+/// ```
+pub fn zip_rect<'a, 'b, A, B>(
+    a: &'a A,
+    b: &'b B,
+    bounds: Rect,
+) -> impl Iterator<Item = (A::Element<'a>, B::Element<'b>)>
+where
+    A: GridRead,
+    B: GridRead,
+{
+    let bounds = b.trim_rect(a.trim_rect(bounds));
+    A::Layout::iter_pos(bounds).filter_map(move |pos| Some((a.get(pos)?, b.get(pos)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{core::Rect, test::NaiveGrid};
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn zip_rect_pairs_in_layout_order() {
+        let a = NaiveGrid::<u8>::with_cells(2, 2, [1, 2, 3, 4]);
+        let b = NaiveGrid::<u8>::with_cells(2, 2, [10, 20, 30, 40]);
+
+        let pairs: Vec<_> = zip_rect(&a, &b, Rect::from_ltwh(0, 0, 2, 2)).collect();
+        assert_eq!(pairs, [(&1, &10), (&2, &20), (&3, &30), (&4, &40)]);
+    }
+
+    #[test]
+    fn zip_rect_partial_region() {
+        let a = NaiveGrid::<u8>::with_cells(2, 2, [1, 2, 3, 4]);
+        let b = NaiveGrid::<u8>::with_cells(2, 2, [10, 20, 30, 40]);
+
+        let pairs: Vec<_> = zip_rect(&a, &b, Rect::from_ltwh(1, 0, 1, 2)).collect();
+        assert_eq!(pairs, [(&2, &20), (&4, &40)]);
+    }
+
+    #[test]
+    fn zip_rect_trims_to_smaller_grid() {
+        let a = NaiveGrid::<u8>::with_cells(3, 1, [1, 2, 3]);
+        let b = NaiveGrid::<u8>::with_cells(2, 1, [10, 20]);
+
+        let pairs: Vec<_> = zip_rect(&a, &b, Rect::from_ltwh(0, 0, 3, 1)).collect();
+        assert_eq!(pairs, [(&1, &10), (&2, &20)]);
+    }
+}
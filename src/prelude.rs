@@ -9,11 +9,14 @@
 //! assert_eq!(grid.get(Pos::new(4, 4)), Some(&42));
 //! ```
 
+#[cfg(all(feature = "alloc", feature = "buffer"))]
+pub use crate::buf::VecGrid;
 #[cfg(feature = "buffer")]
-pub use crate::buf::{GridBuf, bits::GridBits};
-pub use crate::core::{GridError, HasSize as _, Pos, Rect, Size};
+pub use crate::buf::{GridBuf, atomic::AtomicGrid, bits::GridBits};
+pub use crate::core::{GridError, GridIndex, HasSize as _, Pos, Rect, SignedPos, Size};
 pub use crate::ops::{
-    ExactSizeGrid as _, GridBase, GridDiff as _, GridIter as _, GridRead, GridWrite, copy_rect,
+    ExactSizeGrid as _, GridBase, GridDiff as _, GridIter as _, GridRead, GridReadMut, GridWrite,
+    copy_rect,
     layout::{Block, ColumnMajor, Linear as _, RowMajor, Traversal as _},
 };
 pub use crate::transform::GridConvertExt as _;
@@ -0,0 +1,117 @@
+//! Presents `u32` pixel grids onto window frame buffers.
+//!
+//! Windowing crates such as `softbuffer` and `minifb` expose each frame as a flat `&mut [u32]`
+//! whose row pitch (elements per row) may differ from the grid's width. [`copy_frame`] copies a
+//! grid into such a frame, clipping to whichever is smaller, and [`copy_frame_scaled`] does the
+//! same with chunky integer upscaling.
+//!
+//! Neither helper depends on a specific windowing crate, so a "pixel playground" stays small:
+//!
+//! ```rust,ignore
+//! // Inside the redraw handler, with a resized `softbuffer` surface:
+//! let mut frame = surface.buffer_mut().unwrap();
+//! grixy::present::copy_frame(&grid, &mut frame, width as usize);
+//! frame.present().unwrap();
+//! ```
+
+use crate::{
+    buf::GridBuf,
+    ops::{ExactSizeGrid as _, layout::RowMajor},
+};
+
+/// Copies a grid into a frame buffer with the given row pitch.
+///
+/// Rows are copied top to bottom; columns beyond the pitch and rows beyond the frame length are
+/// clipped, so a grid larger or smaller than the frame is safe to present.
+pub fn copy_frame<B>(grid: &GridBuf<u32, B, RowMajor>, frame: &mut [u32], pitch: usize)
+where
+    B: AsRef<[u32]>,
+{
+    if pitch == 0 {
+        return;
+    }
+    let width = grid.width().min(pitch);
+    for (y, row) in grid.rows().enumerate() {
+        let start = y * pitch;
+        if start >= frame.len() {
+            break;
+        }
+        let len = width.min(frame.len() - start);
+        frame[start..start + len].copy_from_slice(&row[..len]);
+    }
+}
+
+/// Copies a grid into a frame buffer, scaling each cell to a `scale`x`scale` block.
+///
+/// This is useful for low-resolution grids presented in larger windows. Clipping follows
+/// [`copy_frame`]: pixels beyond the pitch or frame length are skipped.
+pub fn copy_frame_scaled<B>(
+    grid: &GridBuf<u32, B, RowMajor>,
+    frame: &mut [u32],
+    pitch: usize,
+    scale: usize,
+) where
+    B: AsRef<[u32]>,
+{
+    if pitch == 0 || scale == 0 {
+        return;
+    }
+    for (y, row) in grid.rows().enumerate() {
+        for sy in 0..scale {
+            let start = (y * scale + sy) * pitch;
+            if start >= frame.len() {
+                return;
+            }
+            let end = frame.len().min(start + pitch);
+            let out = &mut frame[start..end];
+            for (x, &pixel) in row.iter().enumerate() {
+                let from = x * scale;
+                if from >= out.len() {
+                    break;
+                }
+                let to = out.len().min(from + scale);
+                out[from..to].fill(pixel);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn copy_frame_matching_pitch() {
+        let grid = GridBuf::<u32, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let mut frame = [0u32; 4];
+        copy_frame(&grid, &mut frame, 2);
+        assert_eq!(frame, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn copy_frame_wider_pitch_leaves_padding() {
+        let grid = GridBuf::<u32, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let mut frame = [9u32; 8];
+        copy_frame(&grid, &mut frame, 4);
+        assert_eq!(frame, [1, 2, 9, 9, 3, 4, 9, 9]);
+    }
+
+    #[test]
+    fn copy_frame_clips_to_frame() {
+        let grid = GridBuf::<u32, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6], 2);
+        let mut frame = [0u32; 3];
+        copy_frame(&grid, &mut frame, 2);
+        assert_eq!(frame, [1, 2, 3]);
+    }
+
+    #[test]
+    fn copy_frame_scaled_doubles_cells() {
+        let grid = GridBuf::<u32, _, RowMajor>::from_buffer(vec![1, 2], 2);
+        let mut frame = [0u32; 8];
+        copy_frame_scaled(&grid, &mut frame, 4, 2);
+        assert_eq!(frame, [1, 1, 2, 2, 1, 1, 2, 2]);
+    }
+}
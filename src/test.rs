@@ -9,7 +9,7 @@ use alloc::{vec, vec::Vec};
 use crate::{
     core::{GridError, Size},
     ops::{
-        GridBase, GridRead, GridWrite,
+        ExactSizeGrid, GridBase, GridRead, GridWrite,
         layout::{self, Traversal as _},
     },
 };
@@ -59,6 +59,46 @@ impl<T> GridBase for NaiveGrid<T> {
     }
 }
 
+/// A deterministic FNV-1a hasher for asserting hash behavior without `std`.
+pub struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl core::hash::Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+/// Returns the FNV-1a hash of a value, for comparing hashes in tests.
+pub fn fnv1a_hash_of(value: &impl core::hash::Hash) -> u64 {
+    use core::hash::Hasher as _;
+    let mut hasher = Fnv1aHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T> ExactSizeGrid for NaiveGrid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+}
+
 impl<T> GridRead for NaiveGrid<T> {
     type Element<'a>
         = &'a T
@@ -0,0 +1,226 @@
+//! Tile-map rendering from a grid atlas.
+//!
+//! A [`TileSet`] slices an atlas grid into fixed-size tiles, and [`render_tilemap`] draws the
+//! tiles named by a map grid into a destination grid, clipping against both the camera and the
+//! destination. This replaces the double loop every tile-based game otherwise re-implements on
+//! top of [`copy_rect`].
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use grixy::{buf::GridBuf, core::Pos, ops::{GridRead as _, layout::RowMajor}, tilemap::{TileSet, render_tilemap}, transform::GridConvertExt as _};
+//!
+//! // A 4×2 atlas holding two 2×2 tiles: tile 0 is all 1s, tile 1 is all 2s.
+//! let atlas = GridBuf::<_, _, RowMajor>::from_buffer(vec![1u8, 1, 2, 2, 1, 1, 2, 2], 4);
+//! let tiles = TileSet::new(atlas.copied(), 2, 2);
+//!
+//! let map = GridBuf::<_, _, RowMajor>::from_buffer(vec![0usize, 1], 2);
+//! let mut dst = GridBuf::<u8, _, RowMajor>::new(4, 2);
+//! render_tilemap(&map.copied(), &tiles, &mut dst, Pos::new(0, 0));
+//!
+//! assert_eq!(dst.get(Pos::new(0, 0)), Some(&1));
+//! assert_eq!(dst.get(Pos::new(2, 0)), Some(&2));
+//! ```
+
+use crate::{
+    core::{Pos, Rect},
+    ops::{ExactSizeGrid, GridRead, GridWrite, copy_rect},
+};
+
+/// Identifies a tile within a [`TileSet`], counted row-major across the atlas.
+pub type TileId = usize;
+
+/// A grid atlas sliced into fixed-size tiles.
+///
+/// Tiles are numbered row-major: tile `0` is the atlas's top-left tile, and numbering continues
+/// across each row of tiles before moving down. Any partial row or column at the atlas's edge is
+/// ignored.
+pub struct TileSet<G> {
+    atlas: G,
+    tile_width: usize,
+    tile_height: usize,
+}
+
+impl<G> TileSet<G>
+where
+    G: ExactSizeGrid,
+{
+    /// Creates a tile set slicing `atlas` into `tile_width` × `tile_height` tiles.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either tile dimension is zero.
+    pub fn new(atlas: G, tile_width: usize, tile_height: usize) -> Self {
+        assert!(
+            tile_width > 0 && tile_height > 0,
+            "Tile dimensions must be non-zero"
+        );
+        Self {
+            atlas,
+            tile_width,
+            tile_height,
+        }
+    }
+
+    /// Returns the number of complete tiles in the atlas.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.columns() * (self.atlas.height() / self.tile_height)
+    }
+
+    /// Returns `true` if the atlas holds no complete tile.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the atlas rectangle covered by `id`, or `None` if out of range.
+    #[must_use]
+    pub fn tile_rect(&self, id: TileId) -> Option<Rect> {
+        if id >= self.len() {
+            return None;
+        }
+        let col = id % self.columns();
+        let row = id / self.columns();
+        Some(Rect::from_ltwh(
+            col * self.tile_width,
+            row * self.tile_height,
+            self.tile_width,
+            self.tile_height,
+        ))
+    }
+
+    /// Returns the number of complete tiles per atlas row.
+    fn columns(&self) -> usize {
+        self.atlas.width() / self.tile_width
+    }
+}
+
+/// Draws the tiles named by `map` into `dst`, offset by the `camera` position.
+///
+/// The camera gives the pixel position of the destination's top-left corner within the map's
+/// pixel space, so scrolling is a matter of moving the camera. Only tiles overlapping the
+/// destination are visited, edge tiles are trimmed to the visible portion, and map cells naming
+/// an out-of-range [`TileId`] are skipped.
+pub fn render_tilemap<'a, E, M, A, D>(map: &M, tiles: &'a TileSet<A>, dst: &mut D, camera: Pos)
+where
+    for<'x> M: GridRead<Element<'x> = TileId>,
+    M: ExactSizeGrid,
+    A: GridRead<Element<'a> = E> + ExactSizeGrid,
+    D: GridWrite<Element = E> + ExactSizeGrid,
+{
+    let (tile_width, tile_height) = (tiles.tile_width, tiles.tile_height);
+    let first_col = camera.x / tile_width;
+    let first_row = camera.y / tile_height;
+    let last_col = (camera.x + dst.width())
+        .div_ceil(tile_width)
+        .min(map.width());
+    let last_row = (camera.y + dst.height())
+        .div_ceil(tile_height)
+        .min(map.height());
+    for row in first_row..last_row {
+        for col in first_col..last_col {
+            let Some(id) = map.get(Pos::new(col, row)) else {
+                continue;
+            };
+            let Some(src) = tiles.tile_rect(id) else {
+                continue;
+            };
+            // The first visible row and column may start mid-tile, so trim the leading edge of
+            // the source rect; `copy_rect` clips the trailing edges against the destination.
+            let (px, py) = (col * tile_width, row * tile_height);
+            let skip_x = camera.x.saturating_sub(px);
+            let skip_y = camera.y.saturating_sub(py);
+            let from = Rect::from_ltwh(
+                src.top_left().x + skip_x,
+                src.top_left().y + skip_y,
+                tile_width - skip_x,
+                tile_height - skip_y,
+            );
+            let to = Pos::new(px + skip_x - camera.x, py + skip_y - camera.y);
+            copy_rect(&tiles.atlas, dst, from, to);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{
+        ops::grids_equal,
+        test::NaiveGrid,
+        transform::{Copied, GridConvertExt as _},
+    };
+    use alloc::vec;
+
+    /// A tile set over a 4×2 atlas of 2×2 tiles: tile 0 is all 1s, tile 1 is all 2s.
+    fn two_tile_set() -> TileSet<Copied<u8, NaiveGrid<u8>>> {
+        #[rustfmt::skip]
+        let atlas = NaiveGrid::with_cells(4, 2, vec![
+            1u8, 1, 2, 2,
+            1,   1, 2, 2,
+        ]);
+        TileSet::new(atlas.copied(), 2, 2)
+    }
+
+    #[test]
+    fn tile_rect_numbers_tiles_row_major() {
+        let tiles = two_tile_set();
+        assert_eq!(tiles.len(), 2);
+        assert!(!tiles.is_empty());
+        assert_eq!(tiles.tile_rect(0), Some(Rect::from_ltwh(0, 0, 2, 2)));
+        assert_eq!(tiles.tile_rect(1), Some(Rect::from_ltwh(2, 0, 2, 2)));
+        assert_eq!(tiles.tile_rect(2), None);
+    }
+
+    #[test]
+    fn renders_whole_map_at_origin() {
+        let tiles = two_tile_set();
+        let map = NaiveGrid::with_cells(2, 1, vec![0usize, 1]);
+        let mut dst = NaiveGrid::<u8>::new(4, 2);
+        render_tilemap(&map.copied(), &tiles, &mut dst, Pos::new(0, 0));
+
+        #[rustfmt::skip]
+        let expected = NaiveGrid::with_cells(4, 2, vec![
+            1u8, 1, 2, 2,
+            1,   1, 2, 2,
+        ]);
+        assert!(grids_equal(&dst, &expected));
+    }
+
+    #[test]
+    fn camera_offset_trims_edge_tiles() {
+        let tiles = two_tile_set();
+        #[rustfmt::skip]
+        let map = NaiveGrid::with_cells(2, 2, vec![
+            0usize, 1,
+            1,      0,
+        ]);
+        let mut dst = NaiveGrid::<u8>::new(2, 2);
+        render_tilemap(&map.copied(), &tiles, &mut dst, Pos::new(1, 1));
+
+        #[rustfmt::skip]
+        let expected = NaiveGrid::with_cells(2, 2, vec![
+            1u8, 2,
+            2,   1,
+        ]);
+        assert!(grids_equal(&dst, &expected));
+    }
+
+    #[test]
+    fn out_of_range_tile_ids_are_skipped() {
+        let tiles = two_tile_set();
+        let map = NaiveGrid::with_cells(2, 1, vec![9usize, 1]);
+        let mut dst = NaiveGrid::<u8>::new(4, 2);
+        render_tilemap(&map.copied(), &tiles, &mut dst, Pos::new(0, 0));
+
+        #[rustfmt::skip]
+        let expected = NaiveGrid::with_cells(4, 2, vec![
+            0u8, 0, 2, 2,
+            0,   0, 2, 2,
+        ]);
+        assert!(grids_equal(&dst, &expected));
+    }
+}
@@ -9,10 +9,18 @@
 //!
 //! - [`blend`](GridConvertExt::blend): Creates a blended version of the grid, applying a blend function when setting elements.
 //! - [`copied`](GridConvertExt::copied): Creates a grid that copies all of its elements.
+//! - [`with_bounds_policy`](GridConvertExt::with_bounds_policy): Applies an out-of-bounds policy to reads and writes.
+//! - [`observe`](GridConvertExt::observe): Calls a closure on every successful write.
+//! - [`track_dirty`](GridConvertExt::track_dirty): Records the bounding rectangle of modified cells.
+//! - [`checkpointed`](GridConvertExt::checkpointed): Adds snapshot/rollback checkpoints backed by a write journal.
+//! - [`versioned`](GridConvertExt::versioned): Stamps cells with the version of the last write that touched them.
 //! - [`flatten`](GridConvertExt::flatten): Collects the elements of the grid into a new buffer.
+//! - [`flatten_with_width`](GridConvertExt::flatten_with_width): Collects into a buffer with a chosen width.
 //! - [`map`](GridConvertExt::map): Creates a grid that applies a mapping function to its elements.
 //! - [`scale`](GridConvertExt::scale): Creates a scaled version of the grid.
 //! - [`view`](GridConvertExt::view): Creates a view of the grid over a specified rectangular region.
+//! - [`try_view`](GridConvertExt::try_view): Creates a view, or fails if the bounds exceed the grid.
+//! - [`view_clipped`](GridConvertExt::view_clipped): Creates a view clipped to the grid's bounds.
 //!
 //! ## Chaining transformations
 //!
@@ -55,27 +63,52 @@
 //! assert_eq!(rc.get(Pos::new(1, 1)), Some(&1));
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::marker::PhantomData;
 
 #[cfg(feature = "buffer")]
-use crate::ops::{ExactSizeGrid, layout};
+use crate::ops::layout;
 use crate::{
-    core::Rect,
-    ops::{GridRead, GridWrite},
+    core::{GridError, Pos, Rect},
+    ops::{ExactSizeGrid, GridRead, GridWrite},
 };
 
 mod blended;
 pub use blended::Blended;
 
+mod bounded;
+pub use bounded::{Bounded, BoundsPolicy};
+
+#[cfg(feature = "alloc")]
+mod checkpointed;
+#[cfg(feature = "alloc")]
+pub use checkpointed::{Checkpointed, Token};
+
 mod copied;
 pub use copied::Copied;
 
+mod dirty;
+pub use dirty::DirtyTracked;
+
 mod mapped;
 pub use mapped::Mapped;
 
+mod observed;
+pub use observed::Observed;
+
+mod offset;
+pub use offset::OffsetGrid;
+
 mod scaled;
 pub use scaled::Scaled;
 
+#[cfg(feature = "alloc")]
+mod versioned;
+#[cfg(feature = "alloc")]
+pub use versioned::Versioned;
+
 mod viewed;
 pub use viewed::Viewed;
 
@@ -134,6 +167,119 @@ pub trait GridConvertExt: GridRead {
         }
     }
 
+    /// Wraps the grid to call an observer closure on every successful write.
+    ///
+    /// The observer receives the position, the element being replaced, and a reference to the
+    /// new value. Failed writes are not observed.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    /// use grixy::transform::GridConvertExt as _;
+    ///
+    /// let mut changes = 0;
+    /// let mut grid = GridBuf::<u8, _, _>::new(3, 3).observe(|_, _: &u8, _: &u8| changes += 1);
+    /// grid.set(Pos::new(1, 1), 7).unwrap();
+    /// drop(grid);
+    /// assert_eq!(changes, 1);
+    /// ```
+    fn observe<F>(self, observer: F) -> Observed<Self, F>
+    where
+        Self: Sized,
+    {
+        Observed {
+            source: self,
+            observer,
+        }
+    }
+
+    /// Wraps the grid to record the bounding rectangle of all cells modified through it.
+    ///
+    /// Partial-redraw renderers can consume the rectangle with
+    /// [`DirtyTracked::take_dirty`] and repaint only that region.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    /// use grixy::transform::GridConvertExt as _;
+    ///
+    /// let mut grid = GridBuf::<u8, _, _>::new(8, 8).track_dirty();
+    /// grid.set(Pos::new(2, 1), 1).unwrap();
+    /// grid.set(Pos::new(4, 3), 1).unwrap();
+    /// assert_eq!(grid.take_dirty(), Some(Rect::from_ltwh(2, 1, 3, 3)));
+    /// assert_eq!(grid.take_dirty(), None);
+    /// ```
+    fn track_dirty(self) -> DirtyTracked<Self>
+    where
+        Self: Sized,
+    {
+        DirtyTracked {
+            source: self,
+            dirty: None,
+        }
+    }
+
+    /// Wraps the grid with snapshot/rollback checkpoints backed by a write journal.
+    ///
+    /// Each write records the value it overwrites, so [`Checkpointed::rollback`] restores the
+    /// state at an earlier [`Checkpointed::snapshot`] without cloning the whole grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    /// use grixy::transform::GridConvertExt as _;
+    ///
+    /// let mut grid = GridBuf::<u8, _, _>::new(3, 3).checkpointed();
+    /// let token = grid.snapshot();
+    /// grid.set(Pos::new(1, 1), 7).unwrap();
+    /// grid.rollback(token);
+    /// assert_eq!(grid.get(Pos::new(1, 1)), Some(&0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn checkpointed<'a, T>(self) -> Checkpointed<T, Self>
+    where
+        Self: Sized + GridRead<Element<'a> = &'a T> + 'a,
+        T: Clone + 'a,
+    {
+        Checkpointed {
+            source: self,
+            journal: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Wraps the grid to stamp every cell with the version of the last write that touched it.
+    ///
+    /// [`Versioned::changed_since`] then iterates only the cells modified after a recorded
+    /// version, which suits delta-based network replication.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    /// use grixy::transform::GridConvertExt as _;
+    ///
+    /// let mut grid = GridBuf::<u8, _, _>::new(3, 3).versioned();
+    /// grid.set(Pos::new(0, 0), 1).unwrap();
+    /// let tick = grid.version();
+    /// grid.set(Pos::new(2, 2), 2).unwrap();
+    /// assert_eq!(grid.changed_since(tick).collect::<Vec<_>>(), [Pos::new(2, 2)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn versioned(self) -> Versioned<Self>
+    where
+        Self: Sized + ExactSizeGrid,
+    {
+        let cells = self.width() * self.height();
+        Versioned {
+            source: self,
+            generations: alloc::vec![0; cells],
+            version: 0,
+        }
+    }
+
     /// Creates a view of the grid over a specified rectangular region.
     ///
     /// The view is a lightweight wrapper that allows access to a subset of the grid's elements.
@@ -158,6 +304,63 @@ pub trait GridConvertExt: GridRead {
         }
     }
 
+    /// Creates a view of the grid, or an error if the bounds exceed the grid's size.
+    ///
+    /// This is the fail-fast form of [`view`](GridConvertExt::view), which silently accepts
+    /// oversized bounds; see [`view_clipped`](GridConvertExt::view_clipped) to intersect them
+    /// instead.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError::OutOfBounds`], carrying the bounds' exclusive bottom-right corner,
+    /// if the bounds extend beyond the grid's dimensions.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::new_filled(3, 3, 1);
+    /// assert!(grid.try_view(Rect::from_ltwh(1, 1, 2, 2)).is_ok());
+    ///
+    /// let grid = GridBuf::new_filled(3, 3, 1);
+    /// assert!(grid.try_view(Rect::from_ltwh(1, 1, 3, 2)).is_err());
+    /// ```
+    fn try_view(self, bounds: Rect) -> Result<Viewed<Self>, GridError>
+    where
+        Self: Sized + ExactSizeGrid,
+    {
+        if bounds.right() > self.width() || bounds.bottom() > self.height() {
+            return Err(GridError::OutOfBounds {
+                pos: Pos::new(bounds.right(), bounds.bottom()),
+            });
+        }
+        Ok(self.view(bounds))
+    }
+
+    /// Creates a view of the grid over the intersection of the bounds and the grid itself.
+    ///
+    /// Unlike [`view`](GridConvertExt::view), a view built this way never reports a size larger
+    /// than the source grid.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::new_filled(3, 3, 1);
+    /// let view = grid.view_clipped(Rect::from_ltwh(0, 0, 5, 5));
+    /// assert_eq!(view.get(Pos::new(2, 2)), Some(&1));
+    /// assert_eq!(view.get(Pos::new(3, 0)), None);
+    /// ```
+    fn view_clipped(self, bounds: Rect) -> Viewed<Self>
+    where
+        Self: Sized,
+    {
+        let bounds = self.trim_rect(bounds);
+        self.view(bounds)
+    }
+
     /// Creates a scaled version of the grid.
     ///
     /// The `scale` factor determines how many cells in the original grid correspond to one cell
@@ -217,6 +420,68 @@ pub trait GridConvertExt: GridRead {
         crate::buf::GridBuf::from_buffer(elem, self.width())
     }
 
+    /// Collects the elements of the grid into a new buffer with a chosen width.
+    ///
+    /// Elements are paired in traversal order, so a `6x2` source collects into a `4x3` (or any
+    /// other width that divides the element count) by re-wrapping rows. This method is only
+    /// available when the `buffer` feature is enabled.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`GridError`] if the element count is not a multiple of
+    /// the chosen width.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::prelude::*;
+    ///
+    /// let grid = GridBuf::<_, _, RowMajor>::from_iter_with_width(6, 1..=12).unwrap();
+    /// let rewrapped = grid.copied().flatten_with_width::<Vec<_>, RowMajor>(4).unwrap();
+    /// assert_eq!(rewrapped.get(Pos::new(3, 0)), Some(&4));
+    /// assert_eq!(rewrapped.get(Pos::new(0, 1)), Some(&5));
+    /// ```
+    #[cfg(feature = "buffer")]
+    fn flatten_with_width<'a, B, L>(
+        &'a self,
+        width: usize,
+    ) -> Result<crate::buf::GridBuf<Self::Element<'a>, B, L>, crate::core::GridError>
+    where
+        B: FromIterator<Self::Element<'a>> + AsRef<[Self::Element<'a>]>,
+        L: layout::Linear,
+        Self: Sized,
+        Self: ExactSizeGrid,
+        Self::Element<'a>: Copy,
+    {
+        use crate::core::Rect;
+
+        let iter = self.iter_rect(Rect::from_ltwh(0, 0, self.width(), self.height()));
+        crate::buf::GridBuf::try_from_buffer(iter.collect::<B>(), width)
+    }
+
+    /// Applies an out-of-bounds policy to every read and write of this grid.
+    ///
+    /// Subsystems that want clamped sampling, toroidal wrap-around, or a sentinel value at the
+    /// edges choose once here instead of reimplementing the edge handling ad hoc.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use grixy::{prelude::*, transform::BoundsPolicy};
+    ///
+    /// let grid = GridBuf::new_filled(2, 2, 1u8).with_bounds_policy(BoundsPolicy::Wrap);
+    /// assert_eq!(grid.get(Pos::new(5, 5)), Some(&1));
+    /// ```
+    fn with_bounds_policy<T>(self, policy: BoundsPolicy<T>) -> Bounded<T, Self>
+    where
+        Self: Sized,
+    {
+        Bounded {
+            source: self,
+            policy,
+        }
+    }
+
     /// Creates a blended version of this grid, applying a blend function when setting elements.
     ///
     /// This is useful for operations like blending colors or combining values.
@@ -330,6 +595,98 @@ mod tests {
         assert_eq!(view.get(Pos::new(2, 2)), None);
     }
 
+    #[test]
+    fn grid_try_view_validates_bounds() {
+        let grid = GridBuf::<u8, _, _>::new(3, 3);
+        assert!(grid.try_view(Rect::from_ltwh(0, 0, 3, 3)).is_ok());
+
+        let grid = GridBuf::<u8, _, _>::new(3, 3);
+        assert_eq!(
+            grid.try_view(Rect::from_ltwh(1, 0, 3, 3)).err(),
+            Some(crate::core::GridError::OutOfBounds {
+                pos: Pos::new(4, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn grid_try_view_reads_translate_to_source() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3);
+        let view = grid.try_view(Rect::from_ltwh(1, 1, 2, 2)).unwrap();
+
+        assert_eq!(view.get(Pos::new(0, 0)), Some(&5));
+        assert_eq!(view.get(Pos::new(1, 1)), Some(&9));
+        assert_eq!(view.get(Pos::new(2, 0)), None);
+        assert_eq!(view.get(Pos::new(0, 2)), None);
+
+        let cells: Vec<_> = view.iter_rect(Rect::from_ltwh(0, 0, 2, 2)).collect();
+        assert_eq!(cells, [&5, &6, &8, &9]);
+    }
+
+    #[test]
+    fn grid_view_clipped_reads_translate_to_source() {
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3);
+        let view = grid.view_clipped(Rect::from_ltwh(1, 1, 9, 9));
+
+        assert_eq!(view.get(Pos::new(0, 0)), Some(&5));
+        assert_eq!(view.get(Pos::new(1, 1)), Some(&9));
+        assert_eq!(view.get(Pos::new(2, 2)), None);
+
+        let cells: Vec<_> = view.iter_rect(Rect::from_ltwh(0, 0, 9, 9)).collect();
+        assert_eq!(cells, [&5, &6, &8, &9]);
+    }
+
+    #[test]
+    fn grid_view_unchecked_reads_translate_to_source() {
+        use crate::ops::unchecked::GridReadUnchecked as _;
+
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3);
+        let view = grid.try_view(Rect::from_ltwh(1, 1, 2, 2)).unwrap();
+        assert_eq!(unsafe { view.get_unchecked(Pos::new(0, 0)) }, &5);
+
+        let cells: Vec<_> =
+            unsafe { view.iter_rect_unchecked(Rect::from_ltwh(0, 0, 2, 1)) }.collect();
+        assert_eq!(cells, [&5, &6]);
+    }
+
+    #[test]
+    fn grid_copied_and_mapped_unchecked_forward() {
+        use crate::ops::unchecked::GridReadUnchecked as _;
+
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let copied = grid.copied();
+        assert_eq!(unsafe { copied.get_unchecked(Pos::new(1, 1)) }, 4);
+
+        let mapped = copied.map(|x| x * 10);
+        assert_eq!(unsafe { mapped.get_unchecked(Pos::new(0, 1)) }, 30);
+        let cells: Vec<_> =
+            unsafe { mapped.iter_rect_unchecked(Rect::from_ltwh(0, 0, 2, 1)) }.collect();
+        assert_eq!(cells, [10, 20]);
+    }
+
+    #[test]
+    fn grid_scaled_unchecked_divides_position() {
+        use crate::ops::unchecked::GridReadUnchecked as _;
+
+        let grid = GridBuf::<_, _, RowMajor>::from_buffer(vec![1, 2, 3, 4], 2);
+        let scaled = grid.scale(2);
+        assert_eq!(unsafe { scaled.get_unchecked(Pos::new(3, 3)) }, &4);
+
+        let cells: Vec<_> =
+            unsafe { scaled.iter_rect_unchecked(Rect::from_ltwh(0, 0, 4, 1)) }.collect();
+        assert_eq!(cells, [&1, &1, &2, &2]);
+    }
+
+    #[test]
+    fn grid_view_clipped_intersects() {
+        let grid = GridBuf::new_filled(3, 3, 1);
+        let view = grid.view_clipped(Rect::from_ltwh(0, 0, 9, 9));
+        let (size, _) = view.size_hint();
+        assert_eq!(size.width(), 3);
+        assert_eq!(size.height(), 3);
+        assert_eq!(view.get(Pos::new(3, 0)), None);
+    }
+
     #[test]
     fn grid_view_iter_rect() {
         let grid = GridBuf::new_filled(3, 3, 1);
@@ -427,6 +784,22 @@ mod tests {
         assert_eq!(chained.get(Pos::new(1, 1)), Some(2));
     }
 
+    #[test]
+    fn grid_flatten_with_width() {
+        let grid = GridBuf::<_, _, crate::ops::layout::RowMajor>::from_iter_with_width(4, 1..=8)
+            .unwrap()
+            .copied();
+        let rewrapped = grid
+            .flatten_with_width::<Vec<_>, crate::ops::layout::RowMajor>(2)
+            .unwrap();
+        assert_eq!(rewrapped.get(Pos::new(1, 1)), Some(&4));
+        assert_eq!(rewrapped.get(Pos::new(0, 3)), Some(&7));
+        assert!(
+            grid.flatten_with_width::<Vec<_>, crate::ops::layout::RowMajor>(3)
+                .is_err()
+        );
+    }
+
     #[test]
     fn grid_arc() {
         use alloc::sync::Arc;
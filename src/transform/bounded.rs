@@ -0,0 +1,173 @@
+use crate::{
+    core::{GridError, Pos, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridWrite},
+};
+
+/// How a grid treats positions outside its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundsPolicy<T> {
+    /// Out-of-bounds reads return `None` and writes fail, as the underlying grid would.
+    Error,
+
+    /// Positions are clamped to the nearest edge cell.
+    Clamp,
+
+    /// Positions wrap around the grid, modulo its dimensions.
+    Wrap,
+
+    /// Out-of-bounds reads return this value; writes are silently dropped.
+    Default(T),
+}
+
+/// Applies a [`BoundsPolicy`] to every read and write of a grid.
+///
+/// See [`GridConvertExt::with_bounds_policy`][] for usage.
+///
+/// [`GridConvertExt::with_bounds_policy`]: crate::transform::GridConvertExt::with_bounds_policy
+pub struct Bounded<T, G> {
+    pub(super) source: G,
+    pub(super) policy: BoundsPolicy<T>,
+}
+
+impl<T, G> Bounded<T, G>
+where
+    G: ExactSizeGrid,
+{
+    /// Resolves a position according to the policy, or `None` if it stays out of bounds.
+    fn resolve(&self, pos: Pos) -> Option<Pos> {
+        let (width, height) = (self.source.width(), self.source.height());
+        match self.policy {
+            BoundsPolicy::Error | BoundsPolicy::Default(_) => Some(pos),
+            BoundsPolicy::Clamp => {
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                Some(Pos::new(pos.x.min(width - 1), pos.y.min(height - 1)))
+            }
+            BoundsPolicy::Wrap => {
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                Some(Pos::new(pos.x % width, pos.y % height))
+            }
+        }
+    }
+}
+
+impl<T, G> GridRead for Bounded<T, G>
+where
+    for<'a> G: GridRead<Element<'a> = &'a T> + 'a,
+    G: ExactSizeGrid,
+{
+    type Element<'b>
+        = &'b T
+    where
+        Self: 'b;
+
+    type Layout = <G as GridRead>::Layout;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        let resolved = self.resolve(pos)?;
+        let elem = self.source.get(resolved);
+        if let BoundsPolicy::Default(value) = &self.policy {
+            return elem.or(Some(value));
+        }
+        elem
+    }
+}
+
+impl<T, G> GridWrite for Bounded<T, G>
+where
+    G: GridWrite<Element = T> + ExactSizeGrid,
+{
+    type Element = T;
+    type Layout = <G as GridWrite>::Layout;
+
+    fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), GridError> {
+        let Some(resolved) = self.resolve(pos) else {
+            return Err(GridError::OutOfBounds { pos });
+        };
+        match self.source.set(resolved, value) {
+            Err(GridError::OutOfBounds { .. })
+                if matches!(self.policy, BoundsPolicy::Default(_)) =>
+            {
+                Ok(())
+            }
+            result => result,
+        }
+    }
+}
+
+impl<T, G> GridBase for Bounded<T, G>
+where
+    G: GridBase,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        self.source.size_hint()
+    }
+}
+
+impl<T, G> ExactSizeGrid for Bounded<T, G>
+where
+    G: ExactSizeGrid,
+{
+    fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    fn height(&self) -> usize {
+        self.source.height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{buf::GridBuf, core::Rect, transform::GridConvertExt as _};
+    use alloc::vec;
+
+    fn fixture() -> GridBuf<u8, alloc::vec::Vec<u8>, crate::ops::layout::RowMajor> {
+        GridBuf::from_buffer(vec![1, 2, 3, 4], 2)
+    }
+
+    #[test]
+    fn error_policy_matches_underlying_grid() {
+        let grid = fixture().with_bounds_policy(BoundsPolicy::Error);
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&4));
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+    }
+
+    #[test]
+    fn clamp_policy_returns_edge_cells() {
+        let mut grid = fixture().with_bounds_policy(BoundsPolicy::Clamp);
+        assert_eq!(grid.get(Pos::new(9, 0)), Some(&2));
+        assert_eq!(grid.get(Pos::new(0, 9)), Some(&3));
+        grid.set(Pos::new(9, 9), 7).unwrap();
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&7));
+    }
+
+    #[test]
+    fn wrap_policy_is_modular() {
+        let grid = fixture().with_bounds_policy(BoundsPolicy::Wrap);
+        assert_eq!(grid.get(Pos::new(2, 0)), Some(&1));
+        assert_eq!(grid.get(Pos::new(3, 3)), Some(&4));
+    }
+
+    #[test]
+    fn default_policy_substitutes_and_drops() {
+        let mut grid = fixture().with_bounds_policy(BoundsPolicy::Default(0));
+        assert_eq!(grid.get(Pos::new(5, 5)), Some(&0));
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+        grid.set(Pos::new(5, 5), 9).unwrap();
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+    }
+
+    #[test]
+    fn iter_rect_uses_policy_get() {
+        let grid = fixture().with_bounds_policy(BoundsPolicy::Wrap);
+        let elements: alloc::vec::Vec<_> = grid.iter_rect(Rect::from_ltwh(0, 0, 2, 2)).collect();
+        assert_eq!(elements, vec![&1, &2, &3, &4]);
+    }
+}
@@ -0,0 +1,164 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{
+    core::{GridError, Pos, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridWrite},
+};
+
+/// A handle to a snapshot of a [`Checkpointed`] grid.
+///
+/// Tokens are only meaningful for the wrapper that issued them; rolling back consumes the
+/// token, along with any snapshots taken after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token(usize);
+
+/// Adds snapshot/rollback checkpoints to a grid.
+///
+/// Instead of cloning the whole grid per snapshot, the wrapper journals the value each write
+/// overwrites; [`rollback`](Checkpointed::rollback) replays the journal in reverse to restore
+/// the state at the matching [`snapshot`](Checkpointed::snapshot). This makes save/restore
+/// cost proportional to the writes in between, which suits speculative simulation such as AI
+/// lookahead; see [`GridConvertExt::checkpointed`][] for usage.
+///
+/// [`GridConvertExt::checkpointed`]: crate::transform::GridConvertExt::checkpointed
+pub struct Checkpointed<T, G> {
+    pub(super) source: G,
+    pub(super) journal: Vec<(Pos, T)>,
+}
+
+impl<T, G> Checkpointed<T, G> {
+    /// Takes a snapshot of the grid's current state, returning a token to roll back to.
+    ///
+    /// Snapshots nest: rolling back to an earlier token undoes everything after it, including
+    /// later snapshots.
+    pub fn snapshot(&self) -> Token {
+        Token(self.journal.len())
+    }
+
+    /// Restores the grid to the state captured by `token`.
+    pub fn rollback(&mut self, token: Token)
+    where
+        G: GridWrite<Element = T>,
+    {
+        while self.journal.len() > token.0 {
+            let Some((pos, old)) = self.journal.pop() else {
+                break;
+            };
+            let _ = self.source.set(pos, old);
+        }
+    }
+
+    /// Discards the journal, keeping the grid's current state.
+    ///
+    /// Outstanding tokens become meaningless after this.
+    pub fn commit(&mut self) {
+        self.journal.clear();
+    }
+
+    /// Consumes the wrapper, returning the wrapped grid in its current state.
+    pub fn into_inner(self) -> G {
+        self.source
+    }
+}
+
+impl<T, G> GridBase for Checkpointed<T, G>
+where
+    G: GridBase,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        self.source.size_hint()
+    }
+}
+
+impl<T, G> ExactSizeGrid for Checkpointed<T, G>
+where
+    G: ExactSizeGrid,
+{
+    fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    fn height(&self) -> usize {
+        self.source.height()
+    }
+}
+
+impl<T, G> GridRead for Checkpointed<T, G>
+where
+    for<'a> G: GridRead<Element<'a> = &'a T> + 'a,
+{
+    type Element<'b>
+        = &'b T
+    where
+        Self: 'b;
+
+    type Layout = <G as GridRead>::Layout;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        self.source.get(pos)
+    }
+}
+
+impl<T, G> GridWrite for Checkpointed<T, G>
+where
+    for<'a> G: GridRead<Element<'a> = &'a T> + 'a,
+    G: GridWrite<Element = T>,
+    T: Clone,
+{
+    type Element = T;
+    type Layout = <G as GridWrite>::Layout;
+
+    fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), GridError> {
+        let old = self
+            .source
+            .get(pos)
+            .cloned()
+            .ok_or(GridError::OutOfBounds { pos })?;
+        self.source.set(pos, value)?;
+        self.journal.push((pos, old));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buf::GridBuf, transform::GridConvertExt as _};
+
+    #[test]
+    fn rollback_restores_snapshot_state() {
+        let mut grid = GridBuf::<u8, _, _>::new(3, 3).checkpointed();
+        grid.set(Pos::new(0, 0), 1).unwrap();
+        let token = grid.snapshot();
+        grid.set(Pos::new(0, 0), 9).unwrap();
+        grid.set(Pos::new(2, 2), 9).unwrap();
+        grid.rollback(token);
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+        assert_eq!(grid.get(Pos::new(2, 2)), Some(&0));
+    }
+
+    #[test]
+    fn snapshots_nest() {
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2).checkpointed();
+        let outer = grid.snapshot();
+        grid.set(Pos::new(0, 0), 1).unwrap();
+        let inner = grid.snapshot();
+        grid.set(Pos::new(0, 0), 2).unwrap();
+        grid.rollback(inner);
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&1));
+        grid.rollback(outer);
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&0));
+    }
+
+    #[test]
+    fn commit_keeps_current_state() {
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2).checkpointed();
+        let token = grid.snapshot();
+        grid.set(Pos::new(1, 1), 5).unwrap();
+        grid.commit();
+        grid.rollback(token);
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&5));
+    }
+}
@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use crate::{
     core::Pos,
-    ops::{ExactSizeGrid, GridBase, GridRead},
+    ops::{ExactSizeGrid, GridBase, GridRead, unchecked::GridReadUnchecked},
 };
 
 /// Copies elements from another grid that returns copyable references.
@@ -36,6 +36,35 @@ where
     }
 }
 
+/// Unchecked reads forward to the source's unchecked path, copying each element, so the
+/// source's aligned `iter_rect` fast paths are preserved through the adapter.
+impl<T, G> GridReadUnchecked for Copied<T, G>
+where
+    T: Copy,
+    for<'a> G: GridReadUnchecked<Element<'a> = &'a T> + 'a,
+{
+    type Element<'b>
+        = T
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
+        // SAFETY: The adapter has the same dimensions as its source, so the caller's contract
+        // carries over directly.
+        unsafe { *self.source.get_unchecked(pos) }
+    }
+
+    unsafe fn iter_rect_unchecked(
+        &self,
+        bounds: crate::prelude::Rect,
+    ) -> impl Iterator<Item = Self::Element<'_>> {
+        // SAFETY: As with `get_unchecked`, the caller's contract carries over directly.
+        unsafe { self.source.iter_rect_unchecked(bounds) }.copied()
+    }
+}
+
 impl<T, G> GridBase for Copied<T, G>
 where
     G: GridBase,
@@ -57,3 +86,24 @@ where
         self.source.height()
     }
 }
+
+impl<T, G> PartialEq for Copied<T, G>
+where
+    G: PartialEq,
+{
+    /// Two copying adapters are equal when their source grids are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl<T, G> Eq for Copied<T, G> where G: Eq {}
+
+impl<T, G> core::hash::Hash for Copied<T, G>
+where
+    G: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+    }
+}
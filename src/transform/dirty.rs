@@ -0,0 +1,171 @@
+use crate::{
+    core::{GridError, Pos, Rect, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridWrite},
+};
+
+/// Tracks the bounding rectangle of all cells modified through it.
+///
+/// Partial-redraw renderers can consume the accumulated rectangle with
+/// [`take_dirty`](DirtyTracked::take_dirty) and repaint only that region; see
+/// [`GridConvertExt::track_dirty`][] for usage.
+///
+/// [`GridConvertExt::track_dirty`]: crate::transform::GridConvertExt::track_dirty
+pub struct DirtyTracked<G> {
+    pub(super) source: G,
+    pub(super) dirty: Option<Rect>,
+}
+
+impl<G> DirtyTracked<G> {
+    /// Returns the bounding rectangle of cells modified since the last
+    /// [`take_dirty`](DirtyTracked::take_dirty), or `None` if nothing was written.
+    pub fn dirty(&self) -> Option<Rect> {
+        self.dirty
+    }
+
+    /// Returns and clears the accumulated dirty rectangle.
+    pub fn take_dirty(&mut self) -> Option<Rect> {
+        self.dirty.take()
+    }
+
+    /// Consumes the wrapper, returning the wrapped grid.
+    pub fn into_inner(self) -> G {
+        self.source
+    }
+
+    fn mark_rect(&mut self, rect: Rect) {
+        if rect.width() == 0 || rect.height() == 0 {
+            return;
+        }
+        self.dirty = Some(match self.dirty {
+            None => rect,
+            Some(dirty) => {
+                let left = dirty.top_left().x.min(rect.top_left().x);
+                let top = dirty.top_left().y.min(rect.top_left().y);
+                let right = dirty.right().max(rect.right());
+                let bottom = dirty.bottom().max(rect.bottom());
+                Rect::from_ltwh(left, top, right - left, bottom - top)
+            }
+        });
+    }
+}
+
+impl<G> GridBase for DirtyTracked<G>
+where
+    G: GridBase,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        self.source.size_hint()
+    }
+}
+
+impl<G> ExactSizeGrid for DirtyTracked<G>
+where
+    G: ExactSizeGrid,
+{
+    fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    fn height(&self) -> usize {
+        self.source.height()
+    }
+}
+
+impl<G> GridRead for DirtyTracked<G>
+where
+    G: GridRead,
+{
+    type Element<'b>
+        = G::Element<'b>
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        self.source.get(pos)
+    }
+
+    fn iter_rect(&self, bounds: Rect) -> impl Iterator<Item = Self::Element<'_>> {
+        self.source.iter_rect(bounds)
+    }
+}
+
+impl<G> GridWrite for DirtyTracked<G>
+where
+    G: GridWrite,
+{
+    type Element = G::Element;
+    type Layout = G::Layout;
+
+    fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), GridError> {
+        self.source.set(pos, value)?;
+        self.mark_rect(Rect::from_ltwh(pos.x, pos.y, 1, 1));
+        Ok(())
+    }
+
+    fn fill_rect(&mut self, bounds: Rect, f: impl FnMut(Pos) -> Self::Element) {
+        self.source.fill_rect(bounds, f);
+        let trimmed = self.source.trim_rect(bounds);
+        self.mark_rect(trimmed);
+    }
+
+    fn fill_rect_iter(&mut self, dst: Rect, iter: impl IntoIterator<Item = Self::Element>) {
+        self.source.fill_rect_iter(dst, iter);
+        let trimmed = self.source.trim_rect(dst);
+        self.mark_rect(trimmed);
+    }
+
+    fn fill_rect_solid(&mut self, dst: Rect, value: Self::Element)
+    where
+        Self::Element: Copy,
+    {
+        self.source.fill_rect_solid(dst, value);
+        let trimmed = self.source.trim_rect(dst);
+        self.mark_rect(trimmed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{buf::GridBuf, transform::GridConvertExt as _};
+
+    #[test]
+    fn no_writes_is_clean() {
+        let grid = GridBuf::<u8, _, _>::new(4, 4).track_dirty();
+        assert_eq!(grid.dirty(), None);
+    }
+
+    #[test]
+    fn set_accumulates_bounding_rect() {
+        let mut grid = GridBuf::<u8, _, _>::new(4, 4).track_dirty();
+        grid.set(Pos::new(1, 1), 7).unwrap();
+        grid.set(Pos::new(3, 2), 7).unwrap();
+        assert_eq!(grid.dirty(), Some(Rect::from_ltwh(1, 1, 3, 2)));
+    }
+
+    #[test]
+    fn failed_writes_do_not_mark() {
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2).track_dirty();
+        assert!(grid.set(Pos::new(5, 5), 7).is_err());
+        assert_eq!(grid.dirty(), None);
+    }
+
+    #[test]
+    fn take_dirty_resets_tracking() {
+        let mut grid = GridBuf::<u8, _, _>::new(4, 4).track_dirty();
+        grid.fill_rect_solid(Rect::from_ltwh(0, 0, 2, 2), 9);
+        assert_eq!(grid.take_dirty(), Some(Rect::from_ltwh(0, 0, 2, 2)));
+        assert_eq!(grid.dirty(), None);
+    }
+
+    #[test]
+    fn fill_rect_marks_trimmed_bounds() {
+        let mut grid = GridBuf::<u8, _, _>::new(3, 3).track_dirty();
+        grid.fill_rect_solid(Rect::from_ltwh(1, 1, 9, 9), 5);
+        assert_eq!(grid.take_dirty(), Some(Rect::from_ltwh(1, 1, 2, 2)));
+    }
+}
@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use crate::{
     core::{Pos, Size},
-    ops::{ExactSizeGrid, GridBase, GridRead},
+    ops::{ExactSizeGrid, GridBase, GridRead, unchecked::GridReadUnchecked},
 };
 
 /// Transforms elements.
@@ -38,6 +38,35 @@ where
     }
 }
 
+/// Unchecked reads forward to the source's unchecked path, applying the mapping function, so
+/// the source's aligned `iter_rect` fast paths are preserved through the adapter.
+impl<F, G, T> GridReadUnchecked for Mapped<F, G, T>
+where
+    F: Fn(G::Element<'_>) -> T,
+    G: GridReadUnchecked,
+{
+    type Element<'b>
+        = T
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
+        // SAFETY: The adapter has the same dimensions as its source, so the caller's contract
+        // carries over directly.
+        (self.map_fn)(unsafe { self.source.get_unchecked(pos) })
+    }
+
+    unsafe fn iter_rect_unchecked(
+        &self,
+        bounds: crate::prelude::Rect,
+    ) -> impl Iterator<Item = Self::Element<'_>> {
+        // SAFETY: As with `get_unchecked`, the caller's contract carries over directly.
+        unsafe { self.source.iter_rect_unchecked(bounds) }.map(&self.map_fn)
+    }
+}
+
 impl<F, G, T> GridRead for Mapped<F, G, T>
 where
     F: Fn(G::Element<'_>) -> T,
@@ -0,0 +1,121 @@
+use crate::{
+    core::{GridError, Pos, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridWrite},
+};
+
+/// Calls an observer closure on every successful write to a grid.
+///
+/// The observer receives the position, the element being replaced, and a reference to the new
+/// value, enabling change logging, autosave triggers, or reactive UI updates; see
+/// [`GridConvertExt::observe`][] for usage.
+///
+/// [`GridConvertExt::observe`]: crate::transform::GridConvertExt::observe
+pub struct Observed<G, F> {
+    pub(super) source: G,
+    pub(super) observer: F,
+}
+
+impl<G, F> Observed<G, F> {
+    /// Consumes the wrapper, returning the wrapped grid.
+    pub fn into_inner(self) -> G {
+        self.source
+    }
+}
+
+impl<G, F> GridBase for Observed<G, F>
+where
+    G: GridBase,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        self.source.size_hint()
+    }
+}
+
+impl<G, F> ExactSizeGrid for Observed<G, F>
+where
+    G: ExactSizeGrid,
+{
+    fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    fn height(&self) -> usize {
+        self.source.height()
+    }
+}
+
+impl<G, F> GridRead for Observed<G, F>
+where
+    G: GridRead,
+{
+    type Element<'b>
+        = G::Element<'b>
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        self.source.get(pos)
+    }
+
+    fn iter_rect(&self, bounds: crate::core::Rect) -> impl Iterator<Item = Self::Element<'_>> {
+        self.source.iter_rect(bounds)
+    }
+}
+
+impl<G, F> GridWrite for Observed<G, F>
+where
+    G: GridRead + GridWrite,
+    F: FnMut(Pos, <G as GridRead>::Element<'_>, &<G as GridWrite>::Element),
+{
+    type Element = <G as GridWrite>::Element;
+    type Layout = <G as GridWrite>::Layout;
+
+    fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), GridError> {
+        let old = self.source.get(pos).ok_or(GridError::OutOfBounds { pos })?;
+        (self.observer)(pos, old, &value);
+        self.source.set(pos, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{buf::GridBuf, core::Rect, transform::GridConvertExt as _};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn observer_sees_old_and_new_values() {
+        let mut log = Vec::new();
+        let mut grid = GridBuf::<u8, _, _>::new_filled(2, 2, 1)
+            .observe(|pos, old: &u8, new: &u8| log.push((pos, *old, *new)));
+        grid.set(Pos::new(1, 0), 9).unwrap();
+        grid.set(Pos::new(1, 0), 3).unwrap();
+        drop(grid);
+        assert_eq!(log, [(Pos::new(1, 0), 1, 9), (Pos::new(1, 0), 9, 3)]);
+    }
+
+    #[test]
+    fn failed_writes_are_not_observed() {
+        let mut calls = 0;
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2).observe(|_, _: &u8, _: &u8| calls += 1);
+        assert!(grid.set(Pos::new(5, 5), 7).is_err());
+        drop(grid);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn composes_with_other_write_adapters() {
+        let mut calls = 0;
+        let mut grid = GridBuf::<u8, _, _>::new(4, 4)
+            .observe(|_, _: &u8, _: &u8| calls += 1)
+            .track_dirty();
+        grid.set(Pos::new(2, 2), 7).unwrap();
+        assert_eq!(grid.dirty(), Some(Rect::from_ltwh(2, 2, 1, 1)));
+        drop(grid);
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    core::{GridError, Pos, SignedPos, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridWrite},
+};
+
+/// Addresses a grid by world coordinates relative to a (possibly negative) origin.
+///
+/// The origin is the world position of the grid's top-left cell; `get`/`set` subtract it before
+/// touching the underlying grid. Chunked worlds wrap each chunk once instead of repeating the
+/// subtraction (and its sign handling) at every call site.
+///
+/// ## Examples
+///
+/// ```rust
+/// use grixy::{core::SignedPos, prelude::*, transform::OffsetGrid};
+///
+/// let chunk = GridBuf::new_filled(16, 16, 0u8);
+/// let mut chunk = OffsetGrid::new(chunk, SignedPos::new(-16, -16));
+///
+/// chunk.set(SignedPos::new(-1, -1), 7).unwrap();
+/// assert_eq!(chunk.get(SignedPos::new(-1, -1)), Some(&7));
+/// assert_eq!(chunk.get(SignedPos::new(0, 0)), None); // Outside this chunk
+/// ```
+pub struct OffsetGrid<G> {
+    source: G,
+    origin: SignedPos,
+}
+
+impl<G> OffsetGrid<G> {
+    /// Creates a wrapper placing the grid's top-left cell at `origin` in world coordinates.
+    pub const fn new(source: G, origin: SignedPos) -> Self {
+        Self { source, origin }
+    }
+
+    /// Returns the world position of the grid's top-left cell.
+    #[must_use]
+    pub const fn origin(&self) -> SignedPos {
+        self.origin
+    }
+
+    /// Consumes the wrapper, returning the underlying grid.
+    pub fn into_inner(self) -> G {
+        self.source
+    }
+
+    /// Translates a world position to the underlying grid's coordinates.
+    ///
+    /// Returns `None` if the position is left of or above the origin; positions beyond the
+    /// grid's extent are left to the underlying grid's own bounds checks.
+    fn to_local(&self, pos: SignedPos) -> Option<Pos> {
+        let x = usize::try_from(pos.x.checked_sub(self.origin.x)?).ok()?;
+        let y = usize::try_from(pos.y.checked_sub(self.origin.y)?).ok()?;
+        Some(Pos::new(x, y))
+    }
+}
+
+impl<G> OffsetGrid<G>
+where
+    G: GridRead,
+{
+    /// Returns a reference to the element at a world position.
+    ///
+    /// If the position is outside the grid, it returns `None`.
+    pub fn get(&self, pos: SignedPos) -> Option<G::Element<'_>> {
+        self.source.get(self.to_local(pos)?)
+    }
+}
+
+impl<G> OffsetGrid<G>
+where
+    G: GridWrite,
+{
+    /// Sets the element at a world position.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the position is outside the grid; positions left of or above the
+    /// origin are reported saturated to `usize::MAX`.
+    pub fn set(&mut self, pos: SignedPos, value: G::Element) -> Result<(), GridError> {
+        match self.to_local(pos) {
+            Some(pos) => self.source.set(pos, value),
+            None => Err(GridError::OutOfBounds {
+                pos: Pos::new(usize::MAX, usize::MAX),
+            }),
+        }
+    }
+}
+
+impl<G> GridBase for OffsetGrid<G>
+where
+    G: GridBase,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        self.source.size_hint()
+    }
+}
+
+impl<G> ExactSizeGrid for OffsetGrid<G>
+where
+    G: ExactSizeGrid,
+{
+    fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    fn height(&self) -> usize {
+        self.source.height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::buf::GridBuf;
+
+    #[test]
+    fn translates_negative_world_coordinates() {
+        let mut grid = OffsetGrid::new(GridBuf::new_filled(4, 4, 0u8), SignedPos::new(-2, -2));
+        grid.set(SignedPos::new(-2, -2), 1).unwrap();
+        grid.set(SignedPos::new(1, 1), 2).unwrap();
+
+        assert_eq!(grid.get(SignedPos::new(-2, -2)), Some(&1));
+        assert_eq!(grid.get(SignedPos::new(1, 1)), Some(&2));
+        let inner = grid.into_inner();
+        assert_eq!(inner.get(crate::core::Pos::new(0, 0)), Some(&1));
+        assert_eq!(inner.get(crate::core::Pos::new(3, 3)), Some(&2));
+    }
+
+    #[test]
+    fn rejects_positions_outside_the_chunk() {
+        let mut grid = OffsetGrid::new(GridBuf::new_filled(2, 2, 0u8), SignedPos::new(-2, -2));
+        assert_eq!(grid.get(SignedPos::new(0, 0)), None);
+        assert_eq!(grid.get(SignedPos::new(-3, -2)), None);
+        assert!(grid.set(SignedPos::new(-3, -2), 9).is_err());
+    }
+
+    #[test]
+    fn positive_origin() {
+        let grid = OffsetGrid::new(GridBuf::new_filled(2, 2, 5u8), SignedPos::new(10, 10));
+        assert_eq!(grid.get(SignedPos::new(11, 10)), Some(&5));
+        assert_eq!(grid.get(SignedPos::new(9, 10)), None);
+    }
+}
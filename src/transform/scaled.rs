@@ -1,6 +1,8 @@
 use crate::{
     core::{Pos, Size},
-    ops::{ExactSizeGrid, GridBase, GridRead},
+    ops::{
+        ExactSizeGrid, GridBase, GridRead, layout::Traversal as _, unchecked::GridReadUnchecked,
+    },
 };
 
 /// Scales the grid elements using a nearest-neighbor approach.
@@ -52,3 +54,57 @@ where
         self.source.get(pos / self.scale)
     }
 }
+
+/// Unchecked reads divide the position by the scale factor and forward to the source's
+/// unchecked path, so scaling composes with other adapters without re-checking bounds.
+///
+/// Because each source cell repeats `scale * scale` times, there is no aligned slice to expose;
+/// rect iteration visits scaled positions in the layout's traversal order.
+impl<G> GridReadUnchecked for Scaled<G>
+where
+    G: GridReadUnchecked,
+{
+    type Element<'b>
+        = G::Element<'b>
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
+        // SAFETY: The caller guarantees `pos` is valid for the scaled grid, so the divided
+        // position is valid for the source.
+        unsafe { self.source.get_unchecked(pos / self.scale) }
+    }
+
+    unsafe fn iter_rect_unchecked(
+        &self,
+        bounds: crate::core::Rect,
+    ) -> impl Iterator<Item = Self::Element<'_>> {
+        // SAFETY: As with `get_unchecked`, every position in `bounds` divides into a valid
+        // source position.
+        Self::Layout::iter_pos(bounds).map(move |pos| unsafe { self.get_unchecked(pos) })
+    }
+}
+
+impl<G> PartialEq for Scaled<G>
+where
+    G: PartialEq,
+{
+    /// Two scaling adapters are equal when their source grids and scale factors are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.source == other.source
+    }
+}
+
+impl<G> Eq for Scaled<G> where G: Eq {}
+
+impl<G> core::hash::Hash for Scaled<G>
+where
+    G: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.scale.hash(state);
+        self.source.hash(state);
+    }
+}
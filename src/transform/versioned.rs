@@ -0,0 +1,203 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{
+    core::{GridError, Pos, Rect, Size},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridWrite},
+};
+
+/// Stamps every cell with the version of the last write that touched it.
+///
+/// A monotonically increasing version counter advances on each successful write, and
+/// [`changed_since`](Versioned::changed_since) iterates the cells stamped after a given
+/// version. Network replication can record [`version`](Versioned::version) at each tick and
+/// later send only the cells modified since the last acknowledged one; see
+/// [`GridConvertExt::versioned`][] for usage.
+///
+/// [`GridConvertExt::versioned`]: crate::transform::GridConvertExt::versioned
+pub struct Versioned<G> {
+    pub(super) source: G,
+    pub(super) generations: Vec<u64>,
+    pub(super) version: u64,
+}
+
+impl<G> Versioned<G>
+where
+    G: ExactSizeGrid,
+{
+    /// Returns the current version, i.e. that of the most recent write.
+    ///
+    /// A fresh wrapper starts at version `0`, with every cell stamped `0`.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns the positions of all cells written after version `since`.
+    ///
+    /// Positions are yielded in row-major order.
+    pub fn changed_since(&self, since: u64) -> impl Iterator<Item = Pos> + '_ {
+        let width = self.source.width();
+        self.generations
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &generation)| generation > since)
+            .map(move |(index, _)| Pos::new(index % width, index / width))
+    }
+
+    /// Consumes the wrapper, returning the wrapped grid.
+    pub fn into_inner(self) -> G {
+        self.source
+    }
+
+    fn stamp(&mut self, pos: Pos) {
+        let index = pos.y * self.source.width() + pos.x;
+        if let Some(generation) = self.generations.get_mut(index) {
+            *generation = self.version;
+        }
+    }
+
+    fn stamp_rect(&mut self, bounds: Rect) {
+        if bounds.width() == 0 || bounds.height() == 0 {
+            return;
+        }
+        self.version += 1;
+        for y in bounds.top_left().y..bounds.bottom() {
+            for x in bounds.top_left().x..bounds.right() {
+                self.stamp(Pos::new(x, y));
+            }
+        }
+    }
+}
+
+impl<G> GridBase for Versioned<G>
+where
+    G: GridBase,
+{
+    fn size_hint(&self) -> (Size, Option<Size>) {
+        self.source.size_hint()
+    }
+}
+
+impl<G> ExactSizeGrid for Versioned<G>
+where
+    G: ExactSizeGrid,
+{
+    fn width(&self) -> usize {
+        self.source.width()
+    }
+
+    fn height(&self) -> usize {
+        self.source.height()
+    }
+}
+
+impl<G> GridRead for Versioned<G>
+where
+    G: GridRead + ExactSizeGrid,
+{
+    type Element<'b>
+        = G::Element<'b>
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
+        self.source.get(pos)
+    }
+
+    fn iter_rect(&self, bounds: Rect) -> impl Iterator<Item = Self::Element<'_>> {
+        self.source.iter_rect(bounds)
+    }
+}
+
+impl<G> GridWrite for Versioned<G>
+where
+    G: GridWrite + ExactSizeGrid,
+{
+    type Element = G::Element;
+    type Layout = <G as GridWrite>::Layout;
+
+    fn set(&mut self, pos: Pos, value: Self::Element) -> Result<(), GridError> {
+        self.source.set(pos, value)?;
+        self.version += 1;
+        self.stamp(pos);
+        Ok(())
+    }
+
+    fn fill_rect(&mut self, bounds: Rect, f: impl FnMut(Pos) -> Self::Element) {
+        self.source.fill_rect(bounds, f);
+        let trimmed = self.source.trim_rect(bounds);
+        self.stamp_rect(trimmed);
+    }
+
+    fn fill_rect_iter(&mut self, dst: Rect, iter: impl IntoIterator<Item = Self::Element>) {
+        self.source.fill_rect_iter(dst, iter);
+        let trimmed = self.source.trim_rect(dst);
+        self.stamp_rect(trimmed);
+    }
+
+    fn fill_rect_solid(&mut self, dst: Rect, value: Self::Element)
+    where
+        Self::Element: Copy,
+    {
+        self.source.fill_rect_solid(dst, value);
+        let trimmed = self.source.trim_rect(dst);
+        self.stamp_rect(trimmed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::{buf::GridBuf, transform::GridConvertExt as _};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn fresh_wrapper_has_no_changes() {
+        let grid = GridBuf::<u8, _, _>::new(3, 3).versioned();
+        assert_eq!(grid.version(), 0);
+        assert_eq!(grid.changed_since(0).count(), 0);
+    }
+
+    #[test]
+    fn changed_since_yields_cells_written_after_version() {
+        let mut grid = GridBuf::<u8, _, _>::new(3, 3).versioned();
+        grid.set(Pos::new(0, 0), 1).unwrap();
+        let tick = grid.version();
+        grid.set(Pos::new(2, 1), 2).unwrap();
+        grid.set(Pos::new(1, 2), 3).unwrap();
+        let changed: Vec<_> = grid.changed_since(tick).collect();
+        assert_eq!(changed, [Pos::new(2, 1), Pos::new(1, 2)]);
+    }
+
+    #[test]
+    fn rewriting_a_cell_restamps_it() {
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2).versioned();
+        grid.set(Pos::new(0, 0), 1).unwrap();
+        let tick = grid.version();
+        grid.set(Pos::new(0, 0), 2).unwrap();
+        let changed: Vec<_> = grid.changed_since(tick).collect();
+        assert_eq!(changed, [Pos::new(0, 0)]);
+    }
+
+    #[test]
+    fn fill_rect_stamps_trimmed_bounds_once() {
+        let mut grid = GridBuf::<u8, _, _>::new(3, 3).versioned();
+        let tick = grid.version();
+        grid.fill_rect_solid(Rect::from_ltwh(1, 1, 9, 9), 5);
+        assert_eq!(grid.version(), tick + 1);
+        assert_eq!(grid.changed_since(tick).count(), 4);
+    }
+
+    #[test]
+    fn failed_writes_do_not_stamp() {
+        let mut grid = GridBuf::<u8, _, _>::new(2, 2).versioned();
+        assert!(grid.set(Pos::new(5, 5), 7).is_err());
+        assert_eq!(grid.version(), 0);
+        assert_eq!(grid.changed_since(0).count(), 0);
+    }
+}
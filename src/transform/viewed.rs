@@ -1,6 +1,6 @@
 use crate::{
     core::{Pos, Rect, Size},
-    ops::{ExactSizeGrid, GridBase, GridRead},
+    ops::{ExactSizeGrid, GridBase, GridRead, GridReadMut, unchecked::GridReadUnchecked},
 };
 
 /// Views a sub-grid, allowing access to a specific rectangular area of the grid.
@@ -36,6 +36,14 @@ where
     }
 }
 
+/// Positions are view-local: `(0, 0)` addresses the top-left of the view, and reads translate
+/// by the view's origin before reaching the source.
+///
+/// This impl is explicit rather than derived from the [`TrustedSizeGrid`] blanket impl because
+/// a view must be readable whenever its source is [`GridRead`], including sources with no
+/// unchecked path; an explicit impl and the blanket impl cannot coexist coherently.
+///
+/// [`TrustedSizeGrid`]: crate::ops::unchecked::TrustedSizeGrid
 impl<G> GridRead for Viewed<G>
 where
     G: GridRead,
@@ -48,15 +56,79 @@ where
     type Layout = G::Layout;
 
     fn get(&self, pos: Pos) -> Option<Self::Element<'_>> {
-        let pos = pos - self.bounds.top_left();
-        if !self.bounds.contains_pos(pos) {
+        if pos.x >= self.bounds.width() || pos.y >= self.bounds.height() {
             return None;
         }
-        self.source.get(pos)
+        self.source.get(pos + self.bounds.top_left())
     }
 
     fn iter_rect(&self, bounds: Rect) -> impl Iterator<Item = Self::Element<'_>> {
-        let bounds = bounds - self.bounds.top_left();
+        let bounds = self.trim_rect(bounds) + self.bounds.top_left();
         self.source.iter_rect(bounds)
     }
 }
+
+/// Unchecked reads translate view-local positions and forward to the source's unchecked path,
+/// so a caller performs a single bounds check and the source's aligned `iter_rect` fast paths
+/// are preserved through the view.
+///
+/// ## Safety
+///
+/// In addition to the per-method contracts, the view's bounds must lie fully within the source
+/// grid (as guaranteed by [`GridConvertExt::try_view`][]); otherwise a position that is valid
+/// for the view maps outside the source.
+///
+/// [`GridConvertExt::try_view`]: crate::transform::GridConvertExt::try_view
+impl<G> GridReadUnchecked for Viewed<G>
+where
+    G: GridReadUnchecked,
+{
+    type Element<'b>
+        = G::Element<'b>
+    where
+        Self: 'b;
+
+    type Layout = G::Layout;
+
+    unsafe fn get_unchecked(&self, pos: Pos) -> Self::Element<'_> {
+        let origin = self.bounds.top_left();
+        // SAFETY: The caller guarantees `pos` is valid for the view and that the view's bounds
+        // lie within the source, so the translated position is valid for the source.
+        unsafe {
+            self.source
+                .get_unchecked(Pos::new(pos.x + origin.x, pos.y + origin.y))
+        }
+    }
+
+    unsafe fn iter_rect_unchecked(&self, bounds: Rect) -> impl Iterator<Item = Self::Element<'_>> {
+        let origin = self.bounds.top_left();
+        let bounds = Rect::from_ltwh(
+            bounds.top_left().x + origin.x,
+            bounds.top_left().y + origin.y,
+            bounds.width(),
+            bounds.height(),
+        );
+        // SAFETY: As with `get_unchecked`, every translated position is valid for the source.
+        unsafe { self.source.iter_rect_unchecked(bounds) }
+    }
+}
+
+impl<G> GridReadMut for Viewed<G>
+where
+    G: GridReadMut,
+{
+    type Element = G::Element;
+    type Layout = G::Layout;
+
+    fn get_mut(&mut self, pos: Pos) -> Option<&mut Self::Element> {
+        if pos.x >= self.bounds.width() || pos.y >= self.bounds.height() {
+            return None;
+        }
+        self.source.get_mut(pos + self.bounds.top_left())
+    }
+
+    fn iter_rect_mut(&mut self, bounds: Rect) -> impl Iterator<Item = &mut Self::Element> {
+        let bounds = self.trim_rect(bounds) + self.bounds.top_left();
+        self.source.iter_rect_mut(bounds)
+    }
+}
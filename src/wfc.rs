@@ -0,0 +1,341 @@
+//! Wave Function Collapse over 2D grids.
+//!
+//! Generates a new grid of tile indices satisfying pairwise adjacency [`Rules`], which can be
+//! built by hand or learned from a sample grid. Generation is seeded and fully deterministic:
+//! the same rules, size, and seed always produce the same output.
+//!
+//! Up to 64 distinct tiles are supported, since candidate sets are stored as `u64` bitmasks.
+//! The solver uses constraint propagation without backtracking, so over-constrained rules can
+//! fail; [`collapse`] returns `None` in that case, and trying another seed is the usual remedy.
+//!
+//! ## Examples
+//!
+//! Generating a checkerboard from hand-written rules:
+//!
+//! ```rust
+//! use grixy::{core::Pos, ops::GridRead as _, wfc::{Dir, Rules, collapse}};
+//!
+//! let mut rules = Rules::new(2);
+//! for dir in [Dir::Up, Dir::Left, Dir::Right, Dir::Down] {
+//!     rules.allow(0, dir, 1);
+//! }
+//!
+//! let grid = collapse(&rules, 4, 4, 42).unwrap();
+//! let a = *grid.get(Pos::new(0, 0)).unwrap();
+//! assert_eq!(*grid.get(Pos::new(1, 0)).unwrap(), 1 - a);
+//! assert_eq!(*grid.get(Pos::new(1, 1)).unwrap(), a);
+//! ```
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    buf::GridBuf,
+    core::Pos,
+    ops::{ExactSizeGrid, GridRead, layout::RowMajor},
+};
+
+/// A cardinal neighbor direction, as used by [`Rules::allow`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dir {
+    /// Towards negative `y`.
+    Up,
+    /// Towards negative `x`.
+    Left,
+    /// Towards positive `x`.
+    Right,
+    /// Towards positive `y`.
+    Down,
+}
+
+impl Dir {
+    const ALL: [Self; 4] = [Self::Up, Self::Left, Self::Right, Self::Down];
+
+    const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+        }
+    }
+
+    const fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+        }
+    }
+}
+
+/// Pairwise tile adjacency constraints for [`collapse`].
+#[derive(Clone, Debug)]
+pub struct Rules {
+    allowed: Vec<[u64; 4]>,
+}
+
+impl Rules {
+    /// Creates empty rules for `tiles` distinct tiles, where nothing is allowed yet.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `tiles` exceeds `64`.
+    #[must_use]
+    pub fn new(tiles: usize) -> Self {
+        assert!(tiles <= 64, "At most 64 tiles are supported");
+        Self {
+            allowed: vec![[0; 4]; tiles],
+        }
+    }
+
+    /// Returns the number of distinct tiles the rules cover.
+    #[must_use]
+    pub fn tiles(&self) -> usize {
+        self.allowed.len()
+    }
+
+    /// Allows tile `b` to appear in direction `dir` of tile `a`.
+    ///
+    /// The reverse pairing is implied, so `a` is also allowed on the opposite side of `b`.
+    pub fn allow(&mut self, a: usize, dir: Dir, b: usize) {
+        self.allowed[a][dir as usize] |= 1 << b;
+        self.allowed[b][dir.opposite() as usize] |= 1 << a;
+    }
+
+    /// Learns rules from a sample grid, returning them with the tile palette.
+    ///
+    /// Every pair of horizontally or vertically adjacent cells in the sample becomes an allowed
+    /// adjacency. The palette maps the tile indices used by [`collapse`] back to sample
+    /// elements, in first-seen (row-major) order.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the sample contains more than `64` distinct elements.
+    pub fn from_sample<'a, G>(grid: &'a G) -> (Self, Vec<G::Element<'a>>)
+    where
+        G: GridRead + ExactSizeGrid,
+        G::Element<'a>: PartialEq,
+    {
+        let mut palette: Vec<G::Element<'a>> = Vec::new();
+        let index_of = |palette: &mut Vec<G::Element<'a>>, elem: G::Element<'a>| {
+            palette
+                .iter()
+                .position(|seen| *seen == elem)
+                .unwrap_or_else(|| {
+                    palette.push(elem);
+                    palette.len() - 1
+                })
+        };
+
+        let mut pairs: Vec<(usize, Dir, usize)> = Vec::new();
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let Some(here) = grid.get(Pos::new(x, y)) else {
+                    continue;
+                };
+                let a = index_of(&mut palette, here);
+                for dir in [Dir::Right, Dir::Down] {
+                    let (dx, dy) = dir.offset();
+                    let Some(nx) = x.checked_add_signed(dx) else {
+                        continue;
+                    };
+                    let Some(ny) = y.checked_add_signed(dy) else {
+                        continue;
+                    };
+                    if let Some(there) = grid.get(Pos::new(nx, ny)) {
+                        let b = index_of(&mut palette, there);
+                        pairs.push((a, dir, b));
+                    }
+                }
+            }
+        }
+
+        let mut rules = Self::new(palette.len());
+        for (a, dir, b) in pairs {
+            rules.allow(a, dir, b);
+        }
+        (rules, palette)
+    }
+}
+
+/// Generates a grid of tile indices satisfying `rules`, seeded deterministically.
+///
+/// Cells start with every tile as a candidate; the solver repeatedly collapses a cell with the
+/// fewest remaining candidates to a seeded-random choice and propagates the constraints.
+/// Returns `None` if the rules are contradictory for this seed (some cell is left with no
+/// candidate); there is no backtracking, so retrying with a different seed may still succeed.
+#[must_use]
+pub fn collapse(
+    rules: &Rules,
+    width: usize,
+    height: usize,
+    seed: u64,
+) -> Option<GridBuf<usize, Vec<usize>, RowMajor>> {
+    let tiles = rules.tiles();
+    let full = if tiles == 64 {
+        u64::MAX
+    } else {
+        (1u64 << tiles) - 1
+    };
+    let mut wave = vec![full; width * height];
+    if tiles == 0 && !wave.is_empty() {
+        return None;
+    }
+
+    // Prune tiles that have no support anywhere before collapsing anything.
+    for idx in 0..wave.len() {
+        if !propagate(rules, &mut wave, width, idx) {
+            return None;
+        }
+    }
+
+    let lowest_entropy = |wave: &[u64]| {
+        wave.iter()
+            .enumerate()
+            .filter(|(_, mask)| mask.count_ones() > 1)
+            .min_by_key(|(_, mask)| mask.count_ones())
+            .map(|(idx, _)| idx)
+    };
+
+    let mut state = seed;
+    while let Some(idx) = lowest_entropy(&wave) {
+        let pick = next_random(&mut state) % u64::from(wave[idx].count_ones());
+        let mut mask = wave[idx];
+        for _ in 0..pick {
+            mask &= mask - 1;
+        }
+        wave[idx] = 1 << mask.trailing_zeros();
+        if !propagate(rules, &mut wave, width, idx) {
+            return None;
+        }
+    }
+
+    let cells = wave
+        .iter()
+        .map(|mask| mask.trailing_zeros() as usize)
+        .collect();
+    Some(GridBuf::from_buffer(cells, width))
+}
+
+/// Restores arc consistency after the candidates of `wave[start]` shrank.
+fn propagate(rules: &Rules, wave: &mut [u64], width: usize, start: usize) -> bool {
+    let mut stack = vec![start];
+    while let Some(idx) = stack.pop() {
+        let (x, y) = (idx % width, idx / width);
+        for dir in Dir::ALL {
+            let (dx, dy) = dir.offset();
+            let Some(nx) = x.checked_add_signed(dx) else {
+                continue;
+            };
+            let Some(ny) = y.checked_add_signed(dy) else {
+                continue;
+            };
+            if nx >= width {
+                continue;
+            }
+            let nidx = ny * width + nx;
+            let Some(&neighbor) = wave.get(nidx) else {
+                continue;
+            };
+
+            let mut support = 0u64;
+            let mut mask = wave[idx];
+            while mask != 0 {
+                let tile = mask.trailing_zeros() as usize;
+                support |= rules.allowed[tile][dir as usize];
+                mask &= mask - 1;
+            }
+
+            let narrowed = neighbor & support;
+            if narrowed == 0 {
+                return false;
+            }
+            if narrowed != neighbor {
+                wave[nidx] = narrowed;
+                stack.push(nidx);
+            }
+        }
+    }
+    true
+}
+
+/// Advances a `splitmix64` generator, returning the next value.
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test::NaiveGrid, transform::GridConvertExt as _};
+
+    use super::*;
+
+    fn checkerboard_rules() -> Rules {
+        let mut rules = Rules::new(2);
+        for dir in Dir::ALL {
+            rules.allow(0, dir, 1);
+        }
+        rules
+    }
+
+    #[test]
+    fn collapse_satisfies_rules() {
+        let grid = collapse(&checkerboard_rules(), 4, 4, 7).unwrap();
+        for y in 0..4 {
+            for x in 0..4 {
+                let here = *grid.get(Pos::new(x, y)).unwrap();
+                if x > 0 {
+                    assert_ne!(here, *grid.get(Pos::new(x - 1, y)).unwrap());
+                }
+                if y > 0 {
+                    assert_ne!(here, *grid.get(Pos::new(x, y - 1)).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn collapse_is_deterministic_per_seed() {
+        let rules = checkerboard_rules();
+        let a = collapse(&rules, 4, 4, 1).unwrap();
+        let b = collapse(&rules, 4, 4, 1).unwrap();
+        assert_eq!(a.into_inner().0, b.into_inner().0);
+    }
+
+    #[test]
+    fn contradictory_rules_return_none() {
+        // A single tile with no allowed neighbors cannot fill more than one cell.
+        let rules = Rules::new(1);
+        assert!(collapse(&rules, 2, 1, 0).is_none());
+    }
+
+    #[test]
+    fn learns_rules_from_sample() {
+        #[rustfmt::skip]
+        let sample = NaiveGrid::with_cells(3, 3, alloc::vec![
+            'a', 'b', 'a',
+            'b', 'a', 'b',
+            'a', 'b', 'a',
+        ]);
+        let sample = sample.copied();
+        let (rules, palette) = Rules::from_sample(&sample);
+
+        assert_eq!(palette, ['a', 'b']);
+        let grid = collapse(&rules, 4, 4, 3).unwrap();
+        for y in 0..4 {
+            for x in 1..4 {
+                assert_ne!(
+                    grid.get(Pos::new(x, y)).unwrap(),
+                    grid.get(Pos::new(x - 1, y)).unwrap()
+                );
+            }
+        }
+    }
+}